@@ -1,5 +1,6 @@
 /* eslint-disable newline-per-chained-call */
 import pl from "@polars";
+import * as arrow from "apache-arrow";
 import { Stream } from "stream";
 import fs from "fs";
 describe("dataframe", () => {
@@ -1357,6 +1358,11 @@ describe("dataframe", () => {
   });
 });
 describe("join", () => {
+  test("how:invalid", () => {
+    const df = pl.DataFrame({ a: [1, 2] });
+    const fn = () => df.join(df, { on: "a", how: "sideways" as any });
+    expect(fn).toThrow(/how must be one of/);
+  });
   test("on", () => {
     const df = pl.DataFrame({
       foo: [1, 2, 3],
@@ -1637,7 +1643,7 @@ describe("io", () => {
     const expected = "1X6|2X2|9X8|";
     expect(actual).toEqual(expected);
   });
-  test("writeCSV:stream", (done) => {
+  test("writeCSV:stream", async () => {
     const df = pl.DataFrame([
       pl.Series("foo", [1, 2, 3], pl.UInt32),
       pl.Series("bar", ["a", "b", "c"]),
@@ -1649,10 +1655,9 @@ describe("io", () => {
         callback(null);
       },
     });
-    df.writeCSV(writeStream);
+    await df.writeCSV(writeStream);
     const newDF = pl.readCSV(body);
     expect(newDF).toFrameEqual(df);
-    done();
   });
   test("writeCSV:path", (done) => {
     const df = pl.DataFrame([
@@ -1665,6 +1670,99 @@ describe("io", () => {
     fs.rmSync("./test.csv");
     done();
   });
+  test("writeCSV:stream:error", async () => {
+    const df = pl.DataFrame({ foo: [1, 2, 3] });
+    const writeStream = new Stream.Writable({
+      write() {
+        throw new Error("write failed");
+      },
+    });
+    await expect(df.writeCSV(writeStream)).rejects.toThrow("write failed");
+  });
+  test("writeCSV:stream:backpressure", async () => {
+    const df = pl.DataFrame({
+      foo: Array.from({ length: 10000 }, (_, i) => i),
+      bar: Array.from({ length: 10000 }, (_, i) => `bar-${i}`),
+    });
+    const expected = df.writeCSV();
+    const chunks: Buffer[] = [];
+    let maxBuffered = 0;
+    const writeStream = new Stream.Writable({
+      highWaterMark: 16,
+      write(chunk, _encoding, callback) {
+        maxBuffered = Math.max(maxBuffered, writeStream.writableLength);
+        chunks.push(chunk);
+        setImmediate(callback);
+      },
+    });
+    await df.writeCSV(writeStream);
+    // chunks are only handed over once the previous ones have drained
+    expect(maxBuffered).toBeLessThan(expected.length);
+    expect(Buffer.concat(chunks)).toEqual(expected);
+  });
+  test("writeCSVAsync:stream", async () => {
+    const df = pl.DataFrame({
+      foo: Array.from({ length: 10000 }, (_, i) => i),
+      bar: Array.from({ length: 10000 }, (_, i) => `bar-${i}`),
+    });
+    const chunks: Buffer[] = [];
+    let sawBackpressure = false;
+    const writeStream = new Stream.Writable({
+      highWaterMark: 16,
+      write(chunk, _encoding, callback) {
+        sawBackpressure ||= writeStream.writableNeedDrain;
+        chunks.push(chunk);
+        setImmediate(callback);
+      },
+    });
+    await df.writeCSVAsync(writeStream);
+    expect(sawBackpressure).toBe(true);
+    const newDF = pl.readCSV(Buffer.concat(chunks));
+    expect(newDF).toFrameEqual(df);
+  });
+  test("writeCSVAsync:path", async () => {
+    const df = pl.DataFrame([
+      pl.Series("foo", [1, 2, 3], pl.UInt32),
+      pl.Series("bar", ["a", "b", "c"]),
+    ]);
+    await df.writeCSVAsync("./test_async.csv");
+    const newDF = pl.readCSV("./test_async.csv");
+    expect(newDF).toFrameEqual(df);
+    fs.rmSync("./test_async.csv");
+  });
+  test("writeCSVAsync:error", async () => {
+    const df = pl.DataFrame({ foo: [1, 2, 3] });
+    const writeStream = new Stream.Writable({
+      write(_chunk, _encoding, callback) {
+        callback(new Error("disk full"));
+      },
+    });
+    writeStream.on("error", () => {});
+    await expect(df.writeCSVAsync(writeStream)).rejects.toThrow("disk full");
+  });
+  test("writeCSVAsync:callback throws", async () => {
+    const df = pl.DataFrame({ foo: [1, 2, 3] });
+    const write = () => {
+      throw new Error("callback failed");
+    };
+    await expect(df._df.writeCsv(write, {})).rejects.toThrow("callback failed");
+  });
+  test("writeCSVAsync:path:error", async () => {
+    const df = pl.DataFrame({ foo: [1, 2, 3] });
+    await expect(
+      df.writeCSVAsync("./missing_dir/test_async.csv"),
+    ).rejects.toThrow();
+  });
+  test("writeParquetAsync", async () => {
+    const df = pl.DataFrame([
+      pl.Series("foo", [1, 2, 3], pl.UInt32),
+      pl.Series("bar", ["a", "b", "c"]),
+    ]);
+    await df.writeParquetAsync("./test_async.parquet");
+    const newDF = pl.readParquet("./test_async.parquet");
+    expect(newDF).toFrameEqual(df);
+    fs.rmSync("./test_async.parquet");
+  });
   test("JSON.stringify", () => {
     const df = pl.DataFrame({
       foo: [1],
@@ -1715,6 +1813,129 @@ describe("io", () => {
     const actual = df.toRecords();
     expect(JSON.stringify(actual)).toEqual(JSON.stringify(expected));
   });
+  test("toRecords:binary", () => {
+    const df = pl.DataFrame({
+      bin: [Buffer.from("foo"), null, Buffer.from([0, 255])],
+    });
+    expect(df.getColumn("bin").dtype).toEqual(pl.Binary);
+    expect(df.toRecords()).toEqual([
+      { bin: Buffer.from("foo") },
+      { bin: null },
+      { bin: Buffer.from([0, 255]) },
+    ]);
+    expect(df.row(2)).toEqual([Buffer.from([0, 255])]);
+  });
+  test("readRecords:binary", () => {
+    const rows = [{ bin: Buffer.from("a") }, { bin: Buffer.from("b") }];
+    const df = pl.readRecords(rows);
+    expect(df.schema).toEqual({ bin: pl.Binary });
+    expect(df.toRecords()).toEqual(rows);
+  });
+  test("readRecords:decimal", () => {
+    const rows = [{ price: "19.99" }, { price: 5n }, { price: null }];
+    const df = pl.DataFrame(rows, { schema: { price: pl.Decimal(10, 2) } });
+    expect(df.schema).toEqual({ price: pl.Decimal(10, 2) });
+    expect(df.toRecords()).toEqual([
+      { price: "19.99" },
+      { price: "5.00" },
+      { price: null },
+    ]);
+    const total = df.select(
+      pl.col("price").sum().add(pl.lit({ value: 1n, scale: 2 })),
+    );
+    expect(total.getColumn("price").toArray()).toEqual(["25.00"]);
+  });
+  test("readRecords:nested", () => {
+    const rows = [
+      { id: 1, tags: [{ name: "a" }], meta: { scores: [1, 2] } },
+      { id: 2, tags: [], meta: { scores: [], owner: "x" } },
+      { id: 3, tags: [{ name: "b", weight: 0.5 }], meta: null },
+    ];
+    const df = pl.readRecords(rows);
+    expect(df.schema).toEqual({
+      id: pl.Float64,
+      tags: pl.List(pl.Struct({ name: pl.Utf8, weight: pl.Float64 })),
+      meta: pl.Struct({ scores: pl.List(pl.Float64), owner: pl.Utf8 }),
+    });
+    expect(df.toRecords()).toEqual([
+      {
+        id: 1,
+        tags: [{ name: "a", weight: null }],
+        meta: { scores: [1, 2], owner: null },
+      },
+      { id: 2, tags: [], meta: { scores: [], owner: "x" } },
+      {
+        id: 3,
+        tags: [{ name: "b", weight: 0.5 }],
+        meta: { scores: null, owner: null },
+      },
+    ]);
+  });
+  test("readRecords:strict", () => {
+    const rows = [{ a: new Date(0) }, { a: { b: 1 } }];
+    const schema = { a: pl.Datetime("ms") };
+    const df = pl.readRecords(rows, { schema });
+    expect(df.getColumn("a").nullCount()).toEqual(1);
+    expect(() => pl.readRecords(rows, { schema, strict: true })).toThrow(
+      "cannot coerce JS Object to datetime[ms]",
+    );
+  });
+  test("readRecords:strict:rows", () => {
+    const rows: any[] = [{ a: 1 }, null, { a: 3 }];
+    const df = pl.readRecords(rows);
+    expect(df.getColumn("a").toArray()).toEqual([1, null, 3]);
+    expect(() => pl.readRecords(rows, { strict: true })).toThrow(
+      "row 1 is not an object",
+    );
+    const fns = [{ a: 1, f: () => 1 }];
+    expect(pl.readRecords(fns).getColumn("f").nullCount()).toEqual(1);
+    expect(() => pl.readRecords(fns, { strict: true })).toThrow(
+      "cannot infer a dtype from a JS Function",
+    );
+  });
+  test("readRecords:circular", () => {
+    const a: any = { b: 1 };
+    a.self = a;
+    const rows = [{ id: 1, a }];
+    expect(pl.readRecords(rows).getColumn("a").nullCount()).toEqual(1);
+    expect(() => pl.readRecords(rows, { strict: true })).toThrow(
+      "is the value circular?",
+    );
+  });
+  test("readRecords:enum", () => {
+    const schema = {
+      size: pl.Enum(["S", "M", "L"]),
+      color: pl.Categorical.withOrdering("lexical"),
+    };
+    const rows = [
+      { size: "L", color: "red" },
+      { size: "S", color: "blue" },
+    ];
+    const df = pl.DataFrame(rows, { schema });
+    expect(df.schema).toEqual(schema);
+    expect(df.toRecords()).toEqual(rows);
+    expect(df.sort("size").getColumn("size").toArray()).toEqual(["S", "L"]);
+  });
+  test("binary:parquet", () => {
+    const df = pl.DataFrame(
+      { bin: [new Uint8Array([1, 2]), null] },
+      { schema: { bin: pl.Binary } },
+    );
+    const actual = pl.readParquet(df.writeParquet());
+    expect(actual.schema).toEqual({ bin: pl.Binary });
+    expect(actual.getColumn("bin").toArray()).toEqual([
+      Buffer.from([1, 2]),
+      null,
+    ]);
+  });
+  test("toRecords:duration", () => {
+    const df = pl.DataFrame(
+      { d: [1000n, 2] },
+      { schema: { d: pl.Duration("us") } },
+    );
+    expect(df.schema).toEqual({ d: pl.Duration("us") });
+    expect(df.toRecords()).toEqual([{ d: 1000n }, { d: 2000n }]);
+  });
   test("toObject", () => {
     const expected = {
       foo: [1],
@@ -1734,7 +1955,7 @@ describe("io", () => {
       .concat("\n");
     expect(actual).toEqual(expected);
   });
-  test("writeJSON:stream", (done) => {
+  test("writeJSON:stream", async () => {
     const df = pl.DataFrame([
       pl.Series("foo", [1, 2, 3], pl.UInt32),
       pl.Series("bar", ["a", "b", "c"]),
@@ -1747,10 +1968,9 @@ describe("io", () => {
         callback(null);
       },
     });
-    df.writeJSON(writeStream, { format: "json" });
+    await df.writeJSON(writeStream, { format: "json" });
     const newDF = pl.readJSON(body).select("foo", "bar");
     expect(newDF).toFrameEqual(df);
-    done();
   });
   test("writeJSON:path", (done) => {
     const df = pl.DataFrame([
@@ -1773,6 +1993,128 @@ describe("io", () => {
       .toString();
     expect(actual).toEqual(expected);
   });
+  test("toArrow", () => {
+    const df = pl.DataFrame({
+      int: pl.Series([1, null, 3], pl.Int32),
+      float: [1.5, 2.5, null],
+      str: ["a", null, "c"],
+      bool: [true, false, null],
+      date: pl.Series("", [new Date(0), null, new Date(0)], pl.Date),
+      datetime: [new Date(1), new Date(2), null],
+      list: [[1, 2], [], null],
+    }).withColumns(pl.struct(["int", "str"]).alias("struct"));
+    const table = df.toArrow();
+    expect(table.schema.fields.map((f) => f.name)).toEqual(df.columns);
+    expect(table.batches).toHaveLength(1);
+    const values = table.batches[0].data.children[1].values;
+    expect(values).toBeInstanceOf(Float64Array);
+    expect(Array.from(values as Float64Array).slice(0, 2)).toEqual([1.5, 2.5]);
+    expect(pl.DataFrame.fromArrow(table)).toFrameEqual(df);
+  });
+  test("toArrow:chunked", () => {
+    const df = pl.concat(
+      [pl.DataFrame({ a: [1, 2] }), pl.DataFrame({ a: [3] })],
+      { rechunk: false },
+    );
+    const table = df.toArrow();
+    expect(table.batches.map((b) => b.length)).toEqual([2, 1]);
+    expect(pl.DataFrame.fromArrow(table)).toFrameEqual(df);
+  });
+  test("fromArrow:apache-arrow", () => {
+    // rebuilds the plain types of `toArrow` as apache-arrow `DataType`s
+    const toField = (field: any): arrow.Field =>
+      new arrow.Field(field.name, toType(field.type), field.nullable);
+    const toType = (type: any): arrow.DataType => {
+      switch (type.typeId) {
+        case arrow.Type.Bool:
+          return new arrow.Bool();
+        case arrow.Type.Int:
+          return new arrow.Int(type.isSigned, type.bitWidth);
+        case arrow.Type.Float:
+          return new arrow.Float(type.precision);
+        case arrow.Type.Utf8:
+          return new arrow.Utf8();
+        case arrow.Type.LargeUtf8:
+          return new arrow.LargeUtf8();
+        case arrow.Type.List:
+          return new arrow.List(toField(type.children[0]));
+        case arrow.Type.Struct:
+          return new arrow.Struct(type.children.map(toField));
+        default:
+          throw new Error(`unexpected arrow type ${type.typeId}`);
+      }
+    };
+    const toData = (data: any): arrow.Data<any> => {
+      const children = data.children.map(toData);
+      return arrow.makeData({
+        type: toType(data.type),
+        offset: data.offset,
+        length: data.length,
+        nullCount: data.nullCount,
+        nullBitmap: data.nullBitmap,
+        valueOffsets: data.valueOffsets,
+        data: data.values,
+        child: children[0],
+        children,
+      } as any);
+    };
+
+    const source = arrow.tableFromArrays({
+      int: Int32Array.of(1, 2, 3),
+      float: Float64Array.of(1.5, 2.5, 3.5),
+    });
+    const df = pl.DataFrame.fromArrow(source as any).withColumns(
+      pl.Series("str", ["a", null, "c"]),
+      pl.Series("list", [[1], [], [2, 3]], pl.List(pl.Int32)),
+    );
+    expect(df.getColumn("int").dtype).toEqual(pl.Int32);
+    expect(df.getColumn("float").toArray()).toEqual([1.5, 2.5, 3.5]);
+
+    const { schema, batches } = df.toArrow();
+    const arrowSchema = new arrow.Schema(schema.fields.map(toField));
+    const table = new arrow.Table(
+      batches.map(
+        (batch) => new arrow.RecordBatch(arrowSchema, toData(batch.data)),
+      ),
+    );
+    expect(table.numRows).toBe(3);
+    expect(table.getChild("int")?.toArray()).toEqual(Int32Array.of(1, 2, 3));
+    expect(table.getChild("str")?.toArray()).toEqual(["a", null, "c"]);
+    expect(table.getChild("list")?.get(2)?.toArray()).toEqual(
+      Int32Array.of(2, 3),
+    );
+    expect(pl.DataFrame.fromArrow(table as any)).toFrameEqual(df);
+  });
+  test("fromArrow:recordBatch", () => {
+    const int = { typeId: 2, bitWidth: 32, isSigned: true };
+    const batch = {
+      data: {
+        type: {
+          typeId: 13,
+          children: [{ name: "a", type: int, nullable: true }],
+        },
+        length: 3,
+        offset: 0,
+        nullCount: 0,
+        children: [
+          {
+            type: int,
+            length: 3,
+            offset: 1,
+            nullCount: 1,
+            nullBitmap: Uint8Array.of(0b1011),
+            values: Int32Array.of(10, 20, 30),
+            children: [],
+          },
+        ],
+      },
+    };
+    const actual = pl.DataFrame.fromArrow(batch);
+    const expected = pl.DataFrame({
+      a: pl.Series("a", [10, null, 30], pl.Int32),
+    });
+    expect(actual).toFrameEqual(expected);
+  });
   test("toSeries", () => {
     const s = pl.Series([1, 2, 3]);
     const actual = s.clone().toFrame().toSeries();
@@ -2583,3 +2925,65 @@ describe("additional", () => {
     expect(actual.shape).toEqual({ height: 174_241, width: 3 });
   });
 });
+describe("errors", () => {
+  test("column not found", () => {
+    const df = pl.DataFrame({ a: [1, 2] });
+    try {
+      df.select("x");
+      throw new Error("expected select to throw");
+    } catch (err: any) {
+      expect(err).toBeInstanceOf(pl.PolarsError);
+      expect(err.code).toEqual("ColumnNotFound");
+      expect(err.column).toEqual("x");
+      expect(err.message).not.toMatch(/^ColumnNotFound/);
+    }
+  });
+  test("shape mismatch", () => {
+    const df = pl.DataFrame({ a: [1, 2] });
+    try {
+      df.hstack([pl.Series("b", [1, 2, 3])]);
+      throw new Error("expected hstack to throw");
+    } catch (err: any) {
+      expect(err).toBeInstanceOf(pl.PolarsError);
+      expect(err.code).toEqual("ShapeMismatch");
+      expect(err.column).toBeUndefined();
+    }
+  });
+  test("async rejection", async () => {
+    const lf = pl.DataFrame({ a: [1, 2] }).lazy().select("x");
+    await expect(lf.collect()).rejects.toMatchObject({
+      name: "PolarsError",
+      code: "ColumnNotFound",
+    });
+  });
+  test("invalid argument", () => {
+    const df = pl.DataFrame({ a: [1, 2] });
+    try {
+      df.writeCSV({ sep: "" });
+      throw new Error("expected writeCSV to throw");
+    } catch (err: any) {
+      expect(err).toBeInstanceOf(pl.PolarsError);
+      expect(err.code).toEqual("InvalidArgument");
+      expect(err.message).toEqual("sep must not be empty");
+    }
+  });
+  test("async read rejection", async () => {
+    const csvpath = `${__dirname}/examples/datasets/does-not-exist.csv`;
+    await expect(pl.readCSVAsync(csvpath)).rejects.toMatchObject({
+      name: "PolarsError",
+      code: "IO",
+    });
+  });
+  test("stream rejection", async () => {
+    const readStream = new Stream.Readable({ read() {} });
+    readStream.push(`${JSON.stringify({ a: 1 })}\n`);
+    readStream.push("not parseable json\n");
+    readStream.push(null);
+    await expect(
+      pl.readJSONStream(readStream, { format: "lines" }),
+    ).rejects.toMatchObject({
+      name: "PolarsError",
+      code: expect.any(String),
+    });
+  });
+});
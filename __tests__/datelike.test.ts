@@ -113,4 +113,53 @@ describe("datelike", () => {
 
     expect(out).toFrameEqual(expected);
   });
+  test("time zone aware datetime", () => {
+    const dtype = pl.Datetime("ms", "Europe/Amsterdam");
+    const s = pl.Series("ts", [new Date(Date.UTC(2024, 0, 1))], dtype);
+    expect(s.dtype).toStrictEqual(dtype);
+    expect(s.toArray()).toEqual([new Date(Date.UTC(2024, 0, 1))]);
+  });
+  test("datetime time units to js dates", () => {
+    const date = new Date(Date.UTC(2024, 0, 1, 12, 30, 15, 250));
+    const s = pl.Series("ts", [date]);
+    expect(s.cast(pl.Datetime("us")).toArray()).toEqual([date]);
+    expect(s.cast(pl.Datetime("ns")).toArray()).toEqual([date]);
+    const df = pl.DataFrame({ ts: s.cast(pl.Datetime("ns")) });
+    expect(df.toRecords()).toEqual([{ ts: date }]);
+  });
+  test("convertTimeZone", () => {
+    const df = pl.DataFrame({ ts: [new Date(Date.UTC(2024, 0, 1))] });
+    const actual = df.select(
+      pl
+        .col("ts")
+        .date.replaceTimeZone("UTC")
+        .date.convertTimeZone("Europe/Amsterdam"),
+    );
+    expect(actual.getColumn("ts").dtype).toStrictEqual(
+      pl.Datetime("ms", "Europe/Amsterdam"),
+    );
+    expect(actual.getColumn("ts").toArray()).toEqual([
+      new Date(Date.UTC(2024, 0, 1)),
+    ]);
+  });
+  test("replaceTimeZone", () => {
+    const s = pl
+      .Series("ts", [new Date(Date.UTC(2024, 0, 1))])
+      .date.replaceTimeZone("Europe/Amsterdam");
+    expect(s.dtype).toStrictEqual(pl.Datetime("ms", "Europe/Amsterdam"));
+    expect(s.toArray()).toEqual([new Date(Date.UTC(2023, 11, 31, 23))]);
+    const naive = s.date.convertTimeZone("UTC").date.replaceTimeZone(null);
+    expect(naive.dtype).toStrictEqual(pl.Datetime("ms"));
+  });
+  test("replaceTimeZone nonExistent", () => {
+    const s = pl
+      .Series("ts", ["2024-03-31 02:30:00"])
+      .str.strptime(pl.Datetime("ms"), "%F %T");
+    const fn = () => s.date.replaceTimeZone("Europe/Amsterdam");
+    expect(fn).toThrow();
+    const actual = s.date.replaceTimeZone("Europe/Amsterdam", {
+      nonExistent: "null",
+    });
+    expect(actual.toArray()).toEqual([null]);
+  });
 });
@@ -2,6 +2,8 @@ import pl from "@polars";
 import path from "path";
 import { Stream } from "stream";
 import fs from "fs";
+import os from "os";
+import { type ChildProcess, spawn } from "child_process";
 // eslint-disable-next-line no-undef
 const csvpath = path.resolve(__dirname, "./examples/datasets/foods1.csv");
 // eslint-disable-next-line no-undef
@@ -15,6 +17,8 @@ const avropath = path.resolve(__dirname, "./examples/foods.avro");
 // eslint-disable-next-line no-undef
 const ipcpath = path.resolve(__dirname, "./examples/foods.ipc");
 // eslint-disable-next-line no-undef
+const ipcStreamPath = path.resolve(__dirname, "./examples/foods.arrows");
+// eslint-disable-next-line no-undef
 const jsonpath = path.resolve(__dirname, "./examples/foods.json");
 // eslint-disable-next-line no-undef
 const singlejsonpath = path.resolve(__dirname, "./examples/single_foods.json");
@@ -23,6 +27,13 @@ describe("read:csv", () => {
     const df = pl.readCSV(csvpath);
     expect(df.shape).toEqual({ height: 27, width: 4 });
   });
+  it("can read from a csv file asynchronously", async () => {
+    const df = await pl.readCSVAsync(csvpath);
+    expect(df).toFrameEqual(pl.readCSV(csvpath));
+  });
+  it("rejects when an async csv read fails", async () => {
+    await expect(pl.readCSVAsync("./missing.csv")).rejects.toBeDefined();
+  });
   it("can read from a csv file with inferSchemaLength = 0 option", () => {
     const df = pl.readCSV(csvpath, { inferSchemaLength: 0 });
     const expected = `shape: (1, 4)
@@ -157,6 +168,10 @@ describe("read:json", () => {
     const df = pl.readJSON(jsonpath, { batchSize: 10, inferSchemaLength: 100 });
     expect(df.shape).toEqual({ height: 27, width: 4 });
   });
+  it("can read from a json file asynchronously", async () => {
+    const df = await pl.readJSONAsync(jsonpath);
+    expect(df.shape).toEqual({ height: 27, width: 4 });
+  });
   it("can read from a json buffer", () => {
     const json = [
       JSON.stringify({ bar: "1", foo: 1 }),
@@ -168,6 +183,13 @@ describe("read:json", () => {
     expect(df.writeJSON({ format: "lines" }).toString().slice(0, 30)).toEqual(
       json.slice(0, 30),
     );
+  });  it("can read from a json lines buffer asynchronously", async () => {
+    const json = [
+      JSON.stringify({ bar: "1", foo: 1 }),
+      JSON.stringify({ bar: "1", foo: 2 }),
+    ].join("\n");
+    const df = await pl.readJSONAsync(Buffer.from(json), { format: "lines" });
+    expect(df.shape).toEqual({ height: 2, width: 2 });
   });
 });
 
@@ -235,6 +257,177 @@ describe("scan", () => {
   });
 });
 
+describe("scan:cloud", () => {
+  // the scans block the js thread, so the file server runs in its own process
+  let server: ChildProcess;
+  let url: string;
+  beforeAll(async () => {
+    const serve = `
+      const fs = require("fs");
+      const path = require("path");
+      const root = ${JSON.stringify(path.dirname(path.dirname(csvpath)))};
+      const server = require("http").createServer((req, res) => {
+        const file = path.join(root, req.url);
+        if (!fs.existsSync(file)) {
+          res.statusCode = 404;
+          return res.end();
+        }
+        // objects are downloaded in ranges
+        const body = fs.readFileSync(file);
+        const range = /bytes=(\d+)-(\d+)/.exec(req.headers.range ?? "");
+        if (!range) {
+          res.setHeader("Content-Length", body.length);
+          return res.end(req.method === "HEAD" ? undefined : body);
+        }
+        const [start, end] = [Number(range[1]), Number(range[2])];
+        res.statusCode = 206;
+        res.setHeader("Content-Range", \`bytes \${start}-\${end}/\${body.length}\`);
+        res.end(body.subarray(start, end + 1));
+      });
+      server.listen(0, () => console.log(server.address().port));
+    `;
+    server = spawn(process.execPath, ["-e", serve]);
+    const port = await new Promise((resolve) =>
+      server.stdout?.once("data", (data) => resolve(data.toString().trim())),
+    );
+    url = `http://localhost:${port}`;
+  });
+  afterAll(() => {
+    server.kill();
+  });
+
+  test("scanCSV", () => {
+    const df = pl
+      .scanCSV(`${url}/datasets/foods1.csv`, { retries: 0 })
+      .collectSync();
+    expect(df).toFrameEqual(pl.readCSV(csvpath));
+  });
+  test("scanJson", () => {
+    const df = pl.scanJson(`${url}/single_foods.json`).collectSync();
+    expect(df.shape).toEqual({ height: 1, width: 4 });
+  });
+  test("scanCSV:missing", () => {
+    expect(() =>
+      pl.scanCSV(`${url}/datasets/missing.csv`).collectSync(),
+    ).toThrow();
+  });
+  test("scanCSV:removes downloads", () => {
+    const prefix = `nodejs-polars-${process.pid}-`;
+    const downloads = () =>
+      fs.readdirSync(os.tmpdir()).filter((f) => f.startsWith(prefix));
+    const lf = pl.scanCSV(`${url}/datasets/foods1.csv`);
+    expect(downloads()).toHaveLength(0);
+    expect(lf.collectSync().height).toEqual(27);
+    expect(downloads()).toHaveLength(0);
+  });
+});
+
+// Runs against an S3-compatible store, e.g. MinIO, with a bucket that allows anonymous uploads
+const s3Endpoint = process.env.POLARS_TEST_S3_ENDPOINT;
+const describeS3 = s3Endpoint ? describe : describe.skip;
+describeS3("scan:s3", () => {
+  const bucket = process.env.POLARS_TEST_S3_BUCKET ?? "polars";
+  const cloudOptions = {
+    aws_endpoint_url: s3Endpoint as string,
+    aws_access_key_id: process.env.POLARS_TEST_S3_ACCESS_KEY ?? "minioadmin",
+    aws_secret_access_key: process.env.POLARS_TEST_S3_SECRET_KEY ?? "minioadmin",
+    aws_region: "us-east-1",
+    aws_allow_http: "true",
+  };
+  const put = async (key: string, body: Buffer) => {
+    const res = await fetch(`${s3Endpoint}/${bucket}/${key}`, {
+      method: "PUT",
+      body,
+    });
+    expect(res.ok).toBe(true);
+  };
+  const foods = pl.readCSV(csvpath);
+  beforeAll(async () => {
+    await put("csv/foods1.csv", fs.readFileSync(csvpath));
+    await put("csv/foods2.csv", fs.readFileSync(csvpath));
+    await put("foods.ndjson", foods.writeJSON({ format: "lines" }));
+    await put("foods.ipc", foods.writeIPC());
+  });
+
+  test("scanCSV", () => {
+    const df = pl
+      .scanCSV(`s3://${bucket}/csv/foods1.csv`, { cloudOptions })
+      .collectSync();
+    expect(df).toFrameEqual(foods);
+  });
+  test("scanCSV:glob", () => {
+    const df = pl
+      .scanCSV(`s3://${bucket}/csv/*.csv`, { cloudOptions })
+      .collectSync();
+    expect(df).toFrameEqual(pl.concat([foods, foods]));
+  });
+  test("scanCSV:pushdown", () => {
+    const df = pl
+      .scanCSV(`s3://${bucket}/csv/*.csv`, {
+        cloudOptions,
+        rowCount: { name: "idx", offset: 0 },
+      })
+      .filter(pl.col("category").eq(pl.lit("fruit")))
+      .select("idx", "calories")
+      .head(8)
+      .collectSync();
+    const expected = pl
+      .concat([foods, foods])
+      .withRowCount("idx")
+      .filter(pl.col("category").eq(pl.lit("fruit")))
+      .select("idx", "calories")
+      .head(8);
+    expect(df).toFrameEqual(expected);
+  });
+  test("scanCSV:nRows before predicate", () => {
+    const df = pl
+      .scanCSV(`s3://${bucket}/csv/foods1.csv`, { cloudOptions, nRows: 10 })
+      .filter(pl.col("category").eq(pl.lit("fruit")))
+      .collectSync();
+    const expected = foods
+      .head(10)
+      .filter(pl.col("category").eq(pl.lit("fruit")));
+    expect(df).toFrameEqual(expected);
+  });
+  test("scanCSV:glob with ?", () => {
+    const df = pl
+      .scanCSV(`s3://${bucket}/csv/foods?.csv`, { cloudOptions })
+      .collectSync();
+    expect(df).toFrameEqual(pl.concat([foods, foods]));
+  });
+  test("scanCSV:schema is not downloaded", () => {
+    const lf = pl.scanCSV(`s3://${bucket}/csv/missing.csv`, {
+      cloudOptions,
+      schema: { a: pl.Int64 },
+      retries: 0,
+    });
+    expect(lf.columns).toEqual(["a"]);
+    expect(() => lf.collectSync()).toThrow();
+  });
+  test("scanCSV:missing credentials", () => {
+    expect(() =>
+      pl
+        .scanCSV(`s3://${bucket}/csv/foods1.csv`, {
+          cloudOptions: { ...cloudOptions, aws_secret_access_key: "wrong" },
+          retries: 0,
+        })
+        .collectSync(),
+    ).toThrow();
+  });
+  test("scanJson", () => {
+    const df = pl
+      .scanJson(`s3://${bucket}/foods.ndjson`, { cloudOptions })
+      .collectSync();
+    expect(df).toFrameEqual(foods);
+  });
+  test("scanIPC", () => {
+    const df = pl
+      .scanIPC(`s3://${bucket}/foods.ipc`, { cloudOptions })
+      .collectSync();
+    expect(df).toFrameEqual(foods);
+  });
+});
+
 describe("parquet", () => {
   beforeEach(() => {
     pl.readCSV(csvpath).writeParquet(parquetpath);
@@ -252,6 +445,15 @@ describe("parquet", () => {
     const df = pl.readParquet(buff);
     expect(df.shape).toEqual({ height: 27, width: 4 });
   });
+  test("readAsync", async () => {
+    const df = await pl.readParquetAsync(parquetpath);
+    expect(df.shape).toEqual({ height: 27, width: 4 });
+  });
+  test("readAsync:buffer", async () => {
+    const buff = fs.readFileSync(parquetpath);
+    const df = await pl.readParquetAsync(buff, { numRows: 4 });
+    expect(df.shape).toEqual({ height: 4, width: 4 });
+  });
 
   test("read:compressed", () => {
     const csvDF = pl.readCSV(csvpath);
@@ -275,6 +477,27 @@ describe("parquet", () => {
     expect(df.shape).toEqual({ height: 4, width: 4 });
   });
 
+  test("scan:hivePartitioning", () => {
+    // eslint-disable-next-line no-undef
+    const root = path.resolve(__dirname, "./examples/hive");
+    for (const year of [2023, 2024]) {
+      const dir = path.join(root, `year=${year}`);
+      fs.mkdirSync(dir, { recursive: true });
+      pl.DataFrame({ a: [1, 2] }).writeParquet(path.join(dir, "data.parquet"));
+    }
+    const df = pl
+      .scanParquet(`${root}/**/*.parquet`, { hivePartitioning: true })
+      .filter(pl.col("year").eq(2024))
+      .collectSync();
+    expect(df.columns).toEqual(["a", "year"]);
+    expect(df.getColumn("year").toArray()).toEqual([2024, 2024]);
+    const typed = pl
+      .scanParquet(`${root}/**/*.parquet`, { hiveSchema: { year: pl.Int32 } })
+      .collectSync();
+    expect(typed.getColumn("year").dtype).toStrictEqual(pl.Int32);
+    fs.rmSync(root, { recursive: true });
+  });
+
   test("writeParquet with decimals", async () => {
     const df = pl.DataFrame([
       pl.Series("decimal", [1n, 2n, 3n], pl.Decimal()),
@@ -300,6 +523,10 @@ describe("ipc", () => {
     const df = pl.readIPC(ipcpath);
     expect(df.shape).toEqual({ height: 27, width: 4 });
   });
+  test("readAsync", async () => {
+    const df = await pl.readIPCAsync(ipcpath);
+    expect(df.shape).toEqual({ height: 27, width: 4 });
+  });
   test("read/write:buffer", () => {
     const buff = pl.readCSV(csvpath).writeIPC();
     const df = pl.readIPC(buff);
@@ -356,6 +583,10 @@ describe("avro", () => {
     const df = pl.readAvro(avropath);
     expect(df.shape).toEqual({ height: 27, width: 4 });
   });
+  test("readAsync", async () => {
+    const df = await pl.readAvroAsync(avropath);
+    expect(df.shape).toEqual({ height: 27, width: 4 });
+  });
   test("read:buffer", () => {
     const buff = fs.readFileSync(avropath);
     const df = pl.readAvro(buff);
@@ -456,4 +687,83 @@ describe("stream", () => {
       pl.readJSONStream(readStream, { format: "lines" }),
     ).rejects.toBeDefined();
   });
+  test("readCSV:source throws", async () => {
+    const source = {
+      [Symbol.asyncIterator]: () => ({
+        next(): Promise<IteratorResult<Buffer>> {
+          throw new Error("source failed");
+        },
+      }),
+    };
+    await expect(pl.readCSVStream(source)).rejects.toThrow("source failed");
+  });
+  test("readCSV:asyncIterable", async () => {
+    async function* chunks() {
+      yield Buffer.from("a,b\n1,");
+      yield Buffer.from("2\n2,2\n3");
+      yield ",2\n";
+    }
+    const expected = pl.DataFrame({
+      a: pl.Series("a", [1, 2, 3], pl.Int64),
+      b: pl.Series("b", [2, 2, 2], pl.Int64),
+    });
+    const df = await pl.readCSVStream(chunks());
+    expect(df).toFrameEqual(expected);
+  });
+  test("readCSV:stream error", async () => {
+    async function* chunks() {
+      yield Buffer.from("a,b\n1,2\n");
+      throw new Error("connection reset");
+    }
+    await expect(pl.readCSVStream(chunks())).rejects.toThrow(
+      "connection reset",
+    );
+  });
+  test("readCSV:stream endRows", async () => {
+    let pulled = 0;
+    async function* chunks() {
+      yield "a,b\n";
+      // never ends, so only the rows asked for can be read
+      for (;;) {
+        pulled++;
+        yield `${pulled},2\n`;
+      }
+    }
+    const df = await pl.readCSVStream(chunks(), { endRows: 2 });
+    expect(df).toFrameEqual(
+      pl.DataFrame({
+        a: pl.Series("a", [1, 2], pl.Int64),
+        b: pl.Series("b", [2, 2], pl.Int64),
+      }),
+    );
+    expect(pulled).toBeLessThan(10);
+  });
+  test("readJSON:lines:batches", async () => {
+    const rows = Array.from({ length: 25 }, (_, a) => ({ a, b: `b-${a}` }));
+    async function* chunks() {
+      for (const row of rows) {
+        yield `${JSON.stringify(row)}\n`;
+      }
+    }
+    const actual = await pl.readJSONStream(chunks(), {
+      format: "lines",
+      batchSize: 4,
+      inferSchemaLength: 10,
+    });
+    const expected = pl.DataFrame({
+      a: pl.Series("a", rows.map((r) => r.a), pl.Int64),
+      b: rows.map((r) => r.b),
+    });
+    expect(actual).toFrameEqual(expected);
+  });
+  test("readIPCStream", async () => {
+    const body = fs.readFileSync(ipcStreamPath);
+    async function* chunks() {
+      for (let i = 0; i < body.length; i += 16) {
+        yield body.subarray(i, i + 16);
+      }
+    }
+    const actual = await pl.readIPCStream(chunks());
+    expect(actual).toFrameEqual(pl.readCSV(csvpath));
+  });
 });
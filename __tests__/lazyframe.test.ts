@@ -1,5 +1,8 @@
 import pl from "@polars";
 import fs from "fs";
+import os from "os";
+import path from "path";
+import { execFileSync } from "child_process";
 
 describe("lazyframe", () => {
   test("columns", () => {
@@ -12,6 +15,26 @@ describe("lazyframe", () => {
     const actual = df.columns;
     expect(actual).toEqual(["foo", "bar"]);
   });
+  test("schema", () => {
+    const lf = pl
+      .DataFrame({
+        foo: [1, 2],
+        bar: ["a", "b"],
+      })
+      .lazy()
+      .withColumns(
+        pl.col("foo").cast(pl.Int32),
+        pl.col("bar").str.lengths().alias("len"),
+      );
+    expect(lf.schema).toEqual({
+      foo: pl.Int32,
+      bar: pl.String,
+      len: pl.UInt32,
+    });
+    expect(lf.dtypes).toEqual([pl.Int32, pl.String, pl.UInt32]);
+    expect(lf.width).toEqual(3);
+    expect(() => lf.select("missing").schema).toThrow(pl.PolarsError);
+  });
   test("collectSync", () => {
     const expected = pl.DataFrame({
       foo: [1, 2],
@@ -28,6 +51,123 @@ describe("lazyframe", () => {
     const actual = await expected.lazy().collect();
     expect(actual).toFrameEqual(expected);
   });
+  test("collect:streaming", async () => {
+    const lf = pl
+      .DataFrame({
+        key: ["a", "b", "a"],
+        value: [1, 2, 3],
+      })
+      .lazy()
+      .groupBy("key")
+      .agg(pl.col("value").sum());
+    const expected = pl.DataFrame({ key: ["a", "b"], value: [4, 2] });
+    let actual = await lf.collect({ streaming: true });
+    expect(actual.sort("key")).toFrameEqual(expected);
+    actual = lf.collectSync({ streaming: true, commSubexprElim: false });
+    expect(actual.sort("key")).toFrameEqual(expected);
+    expect(lf.describeOptimizedPlan({ streaming: true })).toContain(
+      "STREAMING",
+    );
+    expect(lf.describeOptimizedPlan()).not.toContain("STREAMING");
+  });
+  test("collect:signal", async () => {
+    const lf = pl.DataFrame({ foo: [1, 2, 3] }).lazy();
+    const controller = new AbortController();
+    const df = await lf.collect({ signal: controller.signal });
+    expect(df).toFrameEqual(pl.DataFrame({ foo: [1, 2, 3] }));
+    const fetched = await lf.fetch(1, { signal: controller.signal });
+    expect(fetched.height).toBe(1);
+    controller.abort();
+    await expect(
+      lf.collect({ signal: controller.signal }),
+    ).rejects.toHaveProperty("name", "AbortError");
+    await expect(
+      lf.fetch(1, { signal: controller.signal }),
+    ).rejects.toHaveProperty("name", "AbortError");
+  });
+  test("fetch:signal", async () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "polars-fetch-"));
+    const file = path.join(dir, "fetch.csv");
+    pl.DataFrame({ foo: [1, 2, 3, 4] }).writeCSV(file);
+    const lf = pl.scanCSV(file).select(pl.col("foo").sum());
+    const controller = new AbortController();
+    const fetched = await lf.fetch(2, { signal: controller.signal });
+    expect(fetched).toFrameEqual(await lf.fetch(2));
+    expect(fetched.getColumn("foo").toArray()).toEqual([3]);
+    fs.rmSync(dir, { recursive: true });
+  });
+  test("collect:abort", async () => {
+    const values = Array.from({ length: 2000 }, (_, i) => i);
+    const lf = pl
+      .DataFrame({ foo: values })
+      .lazy()
+      .join(pl.DataFrame({ bar: values }).lazy(), { how: "cross" })
+      .select(pl.col("foo").plus(pl.col("bar")).sum());
+    const controller = new AbortController();
+    const result = lf.collect({ signal: controller.signal });
+    controller.abort();
+    await expect(result).rejects.toHaveProperty("name", "AbortError");
+  });
+  test("profile", async () => {
+    const expected = pl.DataFrame({ foo: [1, 2, 3] });
+    const lf = expected.lazy().filter(pl.col("foo").gt(0));
+    const [df, timings] = await lf.profile();
+    expect(df).toFrameEqual(expected);
+    expect(timings.columns).toEqual(["node", "start", "end"]);
+    expect(timings.height).toBeGreaterThan(0);
+    const [syncDf, syncTimings] = lf.profileSync({ streaming: false });
+    expect(syncDf).toFrameEqual(expected);
+    expect(syncTimings.getColumn("node").toArray()).toContain("optimization");
+  });
+  test("collectBatches", async () => {
+    const expected = pl.DataFrame({
+      foo: [1, 2, 3],
+      bar: ["a", "b", "c"],
+    });
+    const batches: pl.DataFrame[] = [];
+    for await (const batch of expected.lazy().collectBatches()) {
+      batches.push(batch);
+    }
+    const actual = pl.concat(batches);
+    expect(actual.sort("foo")).toFrameEqual(expected);
+  });
+  test("collectBatches:break", async () => {
+    const df = pl.DataFrame({
+      foo: [1, 2, 3],
+    });
+    let seen = 0;
+    for await (const batch of df.lazy().collectBatches({ bufferSize: 2 })) {
+      seen += batch.height;
+      break;
+    }
+    expect(seen).toBeGreaterThan(0);
+  });
+  test("collectBatches:multiple", async () => {
+    const lf = pl.concat([
+      pl.DataFrame({ foo: [1, 2] }).lazy(),
+      pl.DataFrame({ foo: [3, 4] }).lazy(),
+      pl.DataFrame({ foo: [5, 6] }).lazy(),
+    ]);
+    const batches: pl.DataFrame[] = [];
+    for await (const batch of lf.collectBatches({ bufferSize: 1 })) {
+      batches.push(batch);
+    }
+    expect(batches.length).toBeGreaterThan(1);
+    const actual = pl.concat(batches).sort("foo");
+    expect(actual.getColumn("foo").toArray()).toEqual([1, 2, 3, 4, 5, 6]);
+  });
+  test("collectBatches:backpressure", async () => {
+    const lf = pl.concat(
+      Array.from({ length: 8 }, (_, i) => pl.DataFrame({ foo: [i] }).lazy()),
+    );
+    const batches = lf.collectBatches({ bufferSize: 1 });
+    const first = await batches.next();
+    expect(first.done).toBe(false);
+    // the producer is blocked on the full buffer; returning must not hang
+    await batches.return(undefined);
+    expect((await batches.next()).done).toBe(true);
+    expect(lf.collectSync().height).toEqual(8);
+  });
   test("describeOptimizedPlan", () => {
     const df = pl
       .DataFrame({
@@ -641,6 +781,55 @@ describe("lazyframe", () => {
       });
       expect(actual).toFrameEqualIgnoringOrder(expected);
     });
+    test("validate", () => {
+      const df = pl.DataFrame({ id: [1, 2], a: ["x", "y"] }).lazy();
+      const other = pl.DataFrame({ id: [1, 1], b: [10, 11] }).lazy();
+      const actual = df
+        .join(other, { on: "id", validate: "1:m" })
+        .collectSync();
+      expect(actual.height).toEqual(2);
+      expect(() =>
+        df.join(other, { on: "id", validate: "1:1" }).collectSync(),
+      ).toThrow(pl.PolarsError);
+      expect(() =>
+        df.join(other, { on: "id", validate: "1:x" as any }),
+      ).toThrow();
+    });
+    test("joinNulls", () => {
+      const df = pl.DataFrame({ id: [1, null], a: ["x", "y"] }).lazy();
+      const other = pl.DataFrame({ id: [1, null], b: [10, 11] }).lazy();
+      let actual = df.join(other, { on: "id" }).collectSync();
+      expect(actual.getColumn("b").toArray()).toEqual([10]);
+      actual = df.join(other, { on: "id", joinNulls: true }).collectSync();
+      expect(actual.sort("b").getColumn("b").toArray()).toEqual([10, 11]);
+    });
+    test("coalesce", () => {
+      const df = pl.DataFrame({ ham: ["a", "b"], foo: [1, 2] }).lazy();
+      const other = pl.DataFrame({ ham: ["a", "d"], bar: [3, 4] }).lazy();
+      let actual = df
+        .join(other, { on: "ham", how: "left", coalesce: false })
+        .collectSync();
+      expect(actual.columns).toEqual(["ham", "foo", "hamright", "bar"]);
+      actual = df
+        .join(other, { on: "ham", how: "outer", coalesce: true })
+        .collectSync();
+      expect(actual.columns).toEqual(["ham", "foo", "bar"]);
+      expect(actual.getColumn("ham").sort().toArray()).toEqual(["a", "b", "d"]);
+    });
+    test("asof:nearest", () => {
+      const df = pl.DataFrame({ a: [1, 5, 10] }).lazy();
+      const other = pl.DataFrame({ a: [2, 6, 20], b: [2, 6, 20] }).lazy();
+      const actual = df
+        .joinAsof(other, { on: "a", strategy: "nearest" })
+        .collectSync();
+      expect(actual.getColumn("b").toArray()).toEqual([2, 6, 6]);
+      expect(() =>
+        df.joinAsof(other, { on: "a", strategy: "sideways" as any }),
+      ).toThrow();
+      expect(() => df.joinAsof(other, { on: "a", byLeft: "a" })).toThrow(
+        pl.PolarsError,
+      );
+    });
   });
   test("last", () => {
     const actual = pl
@@ -1339,4 +1528,103 @@ describe("lazyframe", () => {
     expect(newDF.sort("foo")).toFrameEqual(actualDf);
     fs.rmSync("./test.parquet");
   });
+  test("sinkIPC:path", async () => {
+    const ldf = pl
+      .DataFrame([
+        pl.Series("foo", [1, 2, 3], pl.Int64),
+        pl.Series("bar", ["a", "b", "c"]),
+      ])
+      .lazy();
+    ldf.sinkIPC("./test.ipc");
+    const newDF: pl.DataFrame = pl.readIPC("./test.ipc");
+    const actualDf: pl.DataFrame = await ldf.collect();
+    expect(newDF.sort("foo")).toFrameEqual(actualDf);
+    fs.rmSync("./test.ipc");
+  });
+  test("sinkIPC:compression:zstd", async () => {
+    const ldf = pl
+      .DataFrame([
+        pl.Series("foo", [1, 2, 3], pl.Int64),
+        pl.Series("bar", ["a", "b", "c"]),
+      ])
+      .lazy();
+    ldf.sinkIPC("./test.ipc", { compression: "zstd" });
+    const newDF: pl.DataFrame = pl.readIPC("./test.ipc");
+    const actualDf: pl.DataFrame = await ldf.collect();
+    expect(newDF.sort("foo")).toFrameEqual(actualDf);
+    fs.rmSync("./test.ipc");
+  });
+  test("sinkNdJson:path", async () => {
+    const ldf = pl
+      .DataFrame([
+        pl.Series("foo", [1, 2, 3], pl.Int64),
+        pl.Series("bar", ["a", "b", "c"]),
+      ])
+      .lazy();
+    ldf.sinkNdJson("./test.ndjson");
+    const newDF: pl.DataFrame = pl.readJSON("./test.ndjson", {
+      format: "lines",
+    });
+    const actualDf: pl.DataFrame = await ldf.collect();
+    expect(newDF.sort("foo")).toFrameEqual(actualDf);
+    fs.rmSync("./test.ndjson");
+  });
+  test("sinkCSV:error", () => {
+    const ldf = pl.DataFrame({ foo: [1, 2, 3] }).lazy();
+    expect(() => ldf.sinkCSV("./missing/dir/test.csv")).toThrow();
+  });
+  test("sinkParquetAsync", async () => {
+    const ldf = pl
+      .DataFrame([
+        pl.Series("foo", [1, 2, 3], pl.Int64),
+        pl.Series("bar", ["a", "b", "c"]),
+      ])
+      .lazy();
+    await ldf.sinkParquetAsync("./test.parquet");
+    const newDF: pl.DataFrame = pl.readParquet("./test.parquet");
+    const actualDf: pl.DataFrame = await ldf.collect();
+    expect(newDF.sort("foo")).toFrameEqual(actualDf);
+    fs.rmSync("./test.parquet");
+  });
+  test("sinkCSVAsync:signal", async () => {
+    const ldf = pl.DataFrame({ foo: [1, 2, 3] }).lazy();
+    const controller = new AbortController();
+    controller.abort();
+    await expect(
+      ldf.sinkCSVAsync("./test.csv", { signal: controller.signal }),
+    ).rejects.toHaveProperty("name", "AbortError");
+    expect(fs.existsSync("./test.csv")).toBe(false);
+  });
+  // named pipes are not available on windows
+  const fifoTest = process.platform === "win32" ? test.skip : test;
+  fifoTest("sinkCSVAsync:abort", async () => {
+    const values = Array.from({ length: 2000 }, (_, i) => i);
+    const ldf = pl
+      .DataFrame({ foo: values })
+      .lazy()
+      .join(pl.DataFrame({ bar: values }).lazy(), { how: "cross" });
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "polars-abort-"));
+    const fifo = path.join(dir, "out.csv");
+    // the sink blocks on the full pipe until the test reads from it
+    execFileSync("mkfifo", [fifo]);
+    const controller = new AbortController();
+    const result = ldf.sinkCSVAsync(fifo, { signal: controller.signal });
+    // resolves once the sink has opened the pipe, so the query is running
+    const reader = await fs.promises.open(fifo, "r");
+    controller.abort();
+    const buf = Buffer.alloc(1 << 16);
+    let bytesRead: number;
+    do {
+      ({ bytesRead } = await reader.read(buf, 0, buf.length));
+    } while (bytesRead > 0);
+    await reader.close();
+    await expect(result).rejects.toHaveProperty("name", "AbortError");
+    fs.rmSync(dir, { recursive: true });
+  });
+  test("sinkCSVAsync:error", async () => {
+    const ldf = pl.DataFrame({ foo: [1, 2, 3] }).lazy();
+    await expect(
+      ldf.sinkCSVAsync("./missing/dir/test.csv"),
+    ).rejects.toBeDefined();
+  });
 });
@@ -24,6 +24,43 @@ describe("from lists", () => {
     expect(actual).toEqual(expected);
   });
 });
+describe("int64 as bigint", () => {
+  afterEach(() => {
+    pl.Config.unsetInt64AsBigInt();
+  });
+  test("signed bigints", () => {
+    const s = pl.Series("a", [-1n, 2n], pl.Int64);
+    expect(s.dtype).toStrictEqual(pl.Int64);
+    expect(s.toArray()).toEqual([-1, 2]);
+    expect(pl.Series("a", [-1n, 2n]).dtype).toStrictEqual(pl.Int64);
+    expect(pl.Series("a", [1n, -2n]).dtype).toStrictEqual(pl.Int64);
+    expect(pl.Series("a", [null, 1n, 2n, -3n]).toArray()).toEqual([
+      null,
+      1,
+      2,
+      -3,
+    ]);
+  });
+  test("toArray", () => {
+    const ids = [1234567890123456789n, -1234567890123456789n, null];
+    pl.Config.setInt64AsBigInt();
+    const s = pl.Series("ids", ids, pl.Int64);
+    expect(s.toArray()).toEqual(ids);
+    expect(s.getIndex(0)).toEqual(1234567890123456789n);
+  });
+  test("rows and records", () => {
+    pl.Config.setInt64AsBigInt();
+    const df = pl.DataFrame({
+      id: pl.Series("id", [9007199254740993n], pl.Int64),
+    });
+    expect(df.toRecords()).toEqual([{ id: 9007199254740993n }]);
+    expect(df.rows()).toEqual([[9007199254740993n]]);
+    expect(df.row(0)).toEqual([9007199254740993n]);
+  });
+  test("out of range", () => {
+    expect(() => pl.Series("a", [2n ** 64n], pl.Int64)).toThrow();
+  });
+});
 describe("typedArrays", () => {
   test("int8", () => {
     const int8Array = new Int8Array([1, 2, 3]);
@@ -191,13 +228,34 @@ describe("typedArrays", () => {
     const expectedDtype = pl.Decimal(10, 2);
     const actual = pl.Series("", expected, expectedDtype);
     expect(actual.dtype).toEqual(expectedDtype);
-    try {
-      actual.toArray();
-    } catch (e: any) {
-      expect(e.message).toContain(
-        "Decimal is not a supported type in javascript, please convert to string or number before collecting to js",
-      );
-    }
+    expect(actual.toArray()).toEqual(["1.00", "2.00", "3.00"]);
+  });
+  test("decimal:from strings", () => {
+    const dtype = pl.Decimal(38, 3);
+    const values = [
+      "-0.005",
+      null,
+      "12345678901234567890.5",
+      { value: -15n, scale: 1 },
+    ];
+    const actual = pl.Series("", values, dtype);
+    expect(actual.dtype).toEqual(dtype);
+    expect(actual.toArray()).toEqual([
+      "-0.005",
+      null,
+      "12345678901234567890.500",
+      "-1.500",
+    ]);
+    const fn = () => pl.Series("", ["1.2345"], dtype);
+    expect(fn).toThrow();
+  });
+  test("decimal:extendConstant", () => {
+    const dtype = pl.Decimal(10, 2);
+    const actual = pl
+      .Series("", ["1.50"], dtype)
+      .extendConstant({ value: 125n, scale: 2 }, 2);
+    expect(actual.dtype).toEqual(dtype);
+    expect(actual.toArray()).toEqual(["1.50", "1.25", "1.25"]);
   });
 
   test("fixed list", () => {
@@ -211,6 +269,11 @@ describe("typedArrays", () => {
     const actualValues = actual.toArray();
     expect(actualValues).toEqual(expected);
   });
+  test("fixed list:dtype roundtrip", () => {
+    const dtype = pl.FixedSizeList(pl.Int64, 2);
+    const df = pl.DataFrame([pl.Series("a", [[1, 2]], dtype)]);
+    expect(df.schema.a).toEqual(dtype);
+  });
 });
 describe("series", () => {
   const chance = new Chance();
@@ -246,6 +309,36 @@ describe("series", () => {
     });
   });
 });
+describe("arrow", () => {
+  test("toArrow", () => {
+    const s = pl.Series("a", [1, null, 3], pl.Int64);
+    const vector = s.toArrow();
+    expect(vector.name).toEqual("a");
+    expect(vector.type).toMatchObject({
+      typeId: 2,
+      bitWidth: 64,
+      isSigned: true,
+    });
+    const [data] = vector.data;
+    expect(data.nullCount).toEqual(1);
+    const values = data.values as BigInt64Array;
+    expect(values).toBeInstanceOf(BigInt64Array);
+    expect(values[0]).toEqual(1n);
+    expect(values[2]).toEqual(3n);
+  });
+  test("fromArrow:sliced", () => {
+    const s = pl.Series("a", ["foo", null, "bar", "baz"]).slice(1, 3);
+    const actual = pl.Series.fromArrow("a", s.toArrow());
+    expect(actual).toSeriesEqual(s);
+  });
+  test("fromArrow:empty", () => {
+    const actual = pl.Series.fromArrow("a", {
+      type: { typeId: 3, precision: 2 },
+      data: [],
+    });
+    expect(actual).toSeriesEqual(pl.Series("a", [], pl.Float64));
+  });
+});
 describe("series", () => {
   const numSeries = () => pl.Series("foo", [1, 2, 3], pl.Int32);
   const fltSeries = () => pl.Series("float", [1, 2, 3], pl.Float64);
@@ -634,6 +727,21 @@ describe("series", () => {
     const mask = pl.Series([true]);
     expect(() => pl.Series([1, 2, 3]).set(mask, 99)).toThrow();
   });
+  it("scatter: throws error for unsupported dtypes", () => {
+    const s = pl.Series("a", [Buffer.from("a"), Buffer.from("b")]);
+    expect(() => s.scatter([0], Buffer.from("c"))).toThrow(
+      "scatter is not supported for dtype: binary",
+    );
+  });
+  it("cast: throws error for invalid dtypes", () => {
+    const s = pl.Series("a", [1, 2, 3]);
+    expect(() => s.cast(pl.Datetime("days" as any))).toThrow(
+      "time unit must be one of {'ns', 'us', 'ms'}, got days",
+    );
+    expect(() => s.cast({ variant: "Complex" } as any)).toThrow(
+      "DataType Complex is not supported here",
+    );
+  });
   it.each`
     name            | fn                                                  | errorType
     ${"isFinite"}   | ${pl.Series(["foo"]).isFinite}                      | ${TypeError}
@@ -689,6 +797,128 @@ describe("series", () => {
       new Uint8Array([1, 2, 3, 5]),
     );
   });
+  test("binary", () => {
+    const s = pl.Series("bin", [Buffer.from("foo"), null]);
+    expect(s.dtype).toEqual(pl.Binary);
+    expect(s.getIndex(0)).toEqual(Buffer.from("foo"));
+    expect(s.toArray()).toEqual([Buffer.from("foo"), null]);
+    const typed = pl.Series("bin", [Uint8Array.of(1, 2)], pl.Binary);
+    expect(typed.dtype).toEqual(pl.Binary);
+    expect(typed.toArray()).toEqual([Buffer.from([1, 2])]);
+  });
+  test("duration", () => {
+    const s = pl.Series("d", [1, null, 2500], pl.Duration("ms"));
+    expect(s.dtype).toEqual(pl.Duration("ms"));
+    expect(s.toArray()).toEqual([1_000_000n, null, 2_500_000_000n]);
+    const roundTrip = pl.Series("d", s.toArray(), pl.Duration("ms"));
+    expect(roundTrip).toSeriesEqual(s);
+    const diff = pl
+      .DataFrame({ a: [new Date(1000)], b: [new Date(0)] })
+      .select(pl.col("a").sub(pl.col("b")))
+      .getColumn("a");
+    expect(diff.dtype).toEqual(pl.Duration("ms"));
+    expect(diff.getIndex(0)).toEqual(1_000_000_000n);
+    expect(() => pl.Series("d", [1_500n], pl.Duration("us"))).toThrow(
+      "not a whole number",
+    );
+  });
+  test("time", () => {
+    const s = pl.Series("t", [3_600_000_000_000n, null], pl.Time);
+    expect(s.dtype).toEqual(pl.Time);
+    expect(s.toArray()).toEqual([3_600_000_000_000n, null]);
+  });
+  test("object", () => {
+    class Payload {
+      constructor(public id: number) {}
+    }
+    const values = [new Payload(1), null, new Payload(3)];
+    const s = pl.Series("payload", values, pl.Object);
+    expect(s.dtype).toEqual(pl.Object);
+    const actual = s.toArray();
+    expect(actual[0]).toBe(values[0]);
+    expect(actual[1]).toBeNull();
+    expect(actual[2]).toBe(values[2]);
+    expect(s.filter(s.isNotNull()).toArray()).toEqual([values[0], values[2]]);
+  });
+  test("object:frame", () => {
+    const a = { fn: () => 1 };
+    const b = new Map([["k", "v"]]);
+    const df = pl.DataFrame([
+      pl.Series("key", [2, 1]),
+      pl.Series("payload", [a, b], pl.Object),
+    ]);
+    const sorted = df.sort("key").toRecords();
+    expect(sorted[0].payload).toBe(b);
+    expect(sorted[1].payload).toBe(a);
+    const joined = df
+      .join(pl.DataFrame({ key: [1, 2], other: ["x", "y"] }), { on: "key" })
+      .sort("key")
+      .toRecords();
+    expect(joined.map((r) => r.payload)).toEqual([b, a]);
+    expect(joined[0].payload).toBe(b);
+  });
+  test("enum", () => {
+    const dtype = pl.Enum(["small", "medium", "large"]);
+    const s = pl.Series("size", ["large", null, "small"], dtype);
+    expect(s.dtype).toEqual(dtype);
+    expect(s.dtype.equals(pl.Enum(["small", "large"]))).toBe(false);
+    expect(s.toArray()).toEqual(["large", null, "small"]);
+    expect(s.sort().toArray()).toEqual([null, "small", "large"]);
+    const fn = () => pl.Series("size", ["huge"], dtype);
+    expect(fn).toThrow();
+    const casted = pl.Series("size", ["medium"]).cast(dtype);
+    expect(casted.dtype).toEqual(dtype);
+  });
+  test("categorical ordering", () => {
+    const s = pl.Series("c", ["b", "a", "b"], pl.Categorical);
+    expect(s.dtype).toEqual(pl.Categorical);
+    const lexical = pl.Categorical.withOrdering("lexical");
+    expect(s.dtype.equals(lexical)).toBe(false);
+    const actual = s.cast(lexical);
+    expect(actual.dtype).toEqual(lexical);
+    expect(actual.sort().toArray()).toEqual(["a", "b", "b"]);
+  });
+  test("toTypedArrayWithValidity", () => {
+    const s = pl.Series("a", [1, null, 3, null, 5], pl.Int32);
+    const { values, validity } = s.toTypedArrayWithValidity();
+    expect(values).toBeInstanceOf(Int32Array);
+    expect([values[0], values[2], values[4]]).toEqual([1, 3, 5]);
+    expect(validity).toEqual(Uint8Array.of(0b10101));
+    expect(s.dropNulls().toTypedArrayWithValidity()).toStrictEqual({
+      values: Int32Array.of(1, 3, 5),
+    });
+  });
+  test("toTypedArray:copies", () => {
+    const s = pl.Series("a", [1, 2, 3], pl.Int32);
+    const values = s.toTypedArray();
+    values[0] = 10;
+    expect(s.toArray()).toEqual([1, 2, 3]);
+  });
+  test("toTypedArrayWithValidity:repeated", () => {
+    const s = pl.Series("a", [1, null, 3], pl.Int32);
+    const first = s.toTypedArrayWithValidity();
+    const second = s.clone().toTypedArrayWithValidity();
+    expect(second.validity).toEqual(first.validity);
+    expect([second.values[0], second.values[2]]).toEqual([1, 3]);
+  });
+  test("toTypedArrayWithValidity:sliced", () => {
+    const s = pl.Series("a", [null, 1, null, 3], pl.UInt8).slice(1, 3);
+    const { values, validity } = s.toTypedArrayWithValidity();
+    expect(values.length).toEqual(3);
+    expect(validity).toEqual(Uint8Array.of(0b101));
+  });
+  test("toTypedArrayWithValidity:temporal", () => {
+    const s = pl.Series("a", [new Date(1), new Date(2)]);
+    const { values } = s.toTypedArrayWithValidity();
+    expect(values).toEqual(BigInt64Array.of(1n, 2n));
+    const dates = pl.Series([0, 1], pl.Int32).cast(pl.Date);
+    expect(dates.toTypedArray()).toEqual(Int32Array.of(0, 1));
+  });
+  test("toTypedArrayWithValidity:error", () => {
+    const s = pl.Series("a", ["a", "b"]);
+    expect(() => s.toTypedArrayWithValidity()).toThrow(/TypedArray/);
+    expect(() => s.toTypedArray()).toThrow(/TypedArray/);
+  });
   test("toDummies", () => {
     const s = pl.Series("a", [1, 2, 3]);
     let actual = s.toDummies();
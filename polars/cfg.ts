@@ -18,6 +18,16 @@ export interface Config {
   setGlobalStringCache(): Config;
   /** Turn off the global string cache */
   unsetGlobalStringCache(): Config;
+  /**
+   * Return Int64 values as BigInts, e.g. from `toArray`, `toRecords` and `row`.
+   * By default they are returned as numbers, which lose precision above 2^53.
+   *
+   * The setting is global to the process, not to a DataFrame or a call:
+   * it applies to every `polars` user in it, including other libraries.
+   */
+  setInt64AsBigInt(): Config;
+  /** Return Int64 values as numbers */
+  unsetInt64AsBigInt(): Config;
 }
 
 /**
@@ -57,6 +67,16 @@ export const Config: Config = {
   unsetGlobalStringCache() {
     pli.toggleStringCache(false);
 
+    return this;
+  },
+  setInt64AsBigInt() {
+    pli.toggleInt64AsBigint(true);
+
+    return this;
+  },
+  unsetInt64AsBigInt() {
+    pli.toggleInt64AsBigint(false);
+
     return this;
   },
 };
@@ -11,7 +11,10 @@ import { concat } from "./functions";
 import { Expr } from "./lazy/expr";
 import { _Series, Series } from "./series";
 import { Stream, Writable } from "stream";
+import { createWriteStream } from "fs";
 import type {
+  ArrowRecordBatch,
+  ArrowTable,
   FillNullStrategy,
   JoinOptions,
   WriteAvroOptions,
@@ -56,7 +59,8 @@ interface WriteMethods {
    *
    * If no options are specified, it will return a new string containing the contents
    * ___
-   * @param dest file or stream to write to
+   * @param dest file or stream to write to. A stream is written to like with {@link writeCSVAsync},
+   *   and a Promise is returned that settles once the stream has accepted everything.
    * @param options.includeBom - Whether to include UTF-8 BOM in the CSV output.
    * @param options.lineTerminator - String used to end each row.
    * @param options.includeHeader - Whether or not to include header in the CSV output.
@@ -95,21 +99,32 @@ interface WriteMethods {
    * foo,bar,ham
    * 1,6,a
    *
-   * // using a write stream
+   * // using a write stream, which is written to off-thread like with `writeCSVAsync`
    * > const writeStream = new Stream.Writable({
    * ...   write(chunk, encoding, callback) {
    * ...     console.log("writeStream: %O', chunk.toString());
    * ...     callback(null);
    * ...   }
    * ... });
-   * > df.head(1).writeCSV(writeStream, {includeHeader: false});
+   * > await df.head(1).writeCSV(writeStream, {includeHeader: false});
    * writeStream: '1,6,a'
    * ```
    * @category IO
    */
   writeCSV(): Buffer;
   writeCSV(options: WriteCsvOptions): Buffer;
-  writeCSV(dest: string | Writable, options?: WriteCsvOptions): void;
+  writeCSV(dest: string, options?: WriteCsvOptions): void;
+  writeCSV(dest: Writable, options?: WriteCsvOptions): Promise<void>;
+  /**
+   * Behaves the same as {@link writeCSV}, but serializes on a worker thread, waits for
+   * the stream to drain when it signals backpressure, and returns a Promise that
+   * rejects if the stream errors. When given a file path, the file is closed once written.
+   * @category IO
+   */
+  writeCSVAsync(
+    dest: string | Writable,
+    options?: WriteCsvOptions,
+  ): Promise<void>;
   /**
    * Write Dataframe to JSON string, file, or write stream
    * @param destination file or write stream
@@ -137,9 +152,21 @@ interface WriteMethods {
    */
   writeJSON(options?: { format: "lines" | "json" }): Buffer;
   writeJSON(
-    destination: string | Writable,
+    destination: string,
     options?: { format: "lines" | "json" },
   ): void;
+  writeJSON(
+    destination: Writable,
+    options?: { format: "lines" | "json" },
+  ): Promise<void>;
+  /**
+   * Behaves the same as {@link writeJSON}, but writes on a worker thread and returns a Promise
+   * @category IO
+   */
+  writeJSONAsync(
+    destination: string | Writable,
+    options?: { format: "lines" | "json" },
+  ): Promise<void>;
   /**
    * Write to Arrow IPC binary stream, or a feather file.
    * @param file File path to which the file should be written.
@@ -147,7 +174,16 @@ interface WriteMethods {
    * @category IO
    */
   writeIPC(options?: WriteIPCOptions): Buffer;
-  writeIPC(destination: string | Writable, options?: WriteIPCOptions): void;
+  writeIPC(destination: string, options?: WriteIPCOptions): void;
+  writeIPC(destination: Writable, options?: WriteIPCOptions): Promise<void>;
+  /**
+   * Behaves the same as {@link writeIPC}, but writes on a worker thread and returns a Promise
+   * @category IO
+   */
+  writeIPCAsync(
+    destination: string | Writable,
+    options?: WriteIPCOptions,
+  ): Promise<void>;
 
   /**
    * Write the DataFrame disk in parquet format.
@@ -156,10 +192,19 @@ interface WriteMethods {
    * @category IO
    */
   writeParquet(options?: WriteParquetOptions): Buffer;
+  writeParquet(destination: string, options?: WriteParquetOptions): void;
   writeParquet(
+    destination: Writable,
+    options?: WriteParquetOptions,
+  ): Promise<void>;
+  /**
+   * Behaves the same as {@link writeParquet}, but writes on a worker thread and returns a Promise
+   * @category IO
+   */
+  writeParquetAsync(
     destination: string | Writable,
     options?: WriteParquetOptions,
-  ): void;
+  ): Promise<void>;
 
   /**
    * Write the DataFrame disk in avro format.
@@ -168,7 +213,16 @@ interface WriteMethods {
    * @category IO
    */
   writeAvro(options?: WriteAvroOptions): Buffer;
-  writeAvro(destination: string | Writable, options?: WriteAvroOptions): void;
+  writeAvro(destination: string, options?: WriteAvroOptions): void;
+  writeAvro(destination: Writable, options?: WriteAvroOptions): Promise<void>;
+  /**
+   * Behaves the same as {@link writeAvro}, but writes on a worker thread and returns a Promise
+   * @category IO
+   */
+  writeAvroAsync(
+    destination: string | Writable,
+    options?: WriteAvroOptions,
+  ): Promise<void>;
 }
 
 /**
@@ -759,6 +813,9 @@ export interface DataFrame
    *  - A "forward" search selects the first row in the right DataFrame whose
    *    'on' key is greater than or equal to the left's key.
    *
+   *  - A "nearest" search selects the row in the right DataFrame whose
+   *    'on' key is nearest to the left's key.
+   *
    * The default is "backward".
    *
    * @param other DataFrame to join with.
@@ -767,7 +824,7 @@ export interface DataFrame
    * @param options.on Join column of both DataFrames. If set, `leftOn` and `rightOn` should be undefined.
    * @param options.byLeft join on these columns before doing asof join
    * @param options.byRight join on these columns before doing asof join
-   * @param options.strategy One of 'forward', 'backward'
+   * @param options.strategy One of 'forward', 'backward', 'nearest'
    * @param options.suffix Suffix to append to columns with a duplicate name.
    * @param options.tolerance
    *   Numeric tolerance. By setting this the join will only be done if the near keys are within this distance.
@@ -840,7 +897,7 @@ export interface DataFrame
       byLeft?: string | string[];
       byRight?: string | string[];
       by?: string | string[];
-      strategy?: "backward" | "forward";
+      strategy?: "backward" | "forward" | "nearest";
       suffix?: string;
       tolerance?: number | string;
       allowParallel?: boolean;
@@ -1449,6 +1506,25 @@ export interface DataFrame
    * @category IO
    */
   toRecords(): Record<string, any>[];
+  /**
+   * Converts dataframe object into an apache-arrow `Table`-like object, with a record batch per chunk.
+   *
+   * Primitive buffers are shared with the dataframe rather than copied.
+   * Categorical columns are exported as strings.
+   *
+   * The `type` of each field and array is a plain object keyed by apache-arrow's `Type` enum,
+   * not an apache-arrow `DataType`. To build an apache-arrow `Table`, rebuild the types
+   * (e.g. `{ typeId: Type.Int, bitWidth: 32, isSigned: true }` as `new Int32()`) and pass
+   * each array to `makeData`, with `values` as `data` and the first of `children` as `child`.
+   * @example
+   * ```
+   * > const { schema, batches } = df.toArrow();
+   * > schema.fields.map((f) => [f.name, f.type.typeId])
+   * [ [ 'foo', 3 ], [ 'bar', 20 ] ]
+   * ```
+   * @category IO
+   */
+  toArrow(): ArrowTable;
 
   /**
    * compat with `JSON.stringify`
@@ -1853,6 +1929,7 @@ function mapPolarsTypeToJSONSchema(colType: DataType): string {
     Datetime: "string",
     Utf8: "string",
     Categorical: "string",
+    Enum: "string",
     List: "array",
     Struct: "object",
   };
@@ -1861,6 +1938,77 @@ function mapPolarsTypeToJSONSchema(colType: DataType): string {
   return typeMapping[dataType] || "string";
 }
 
+/**
+ * Runs a native writer against an in-memory stream and returns what it wrote.
+ */
+function writeToBuffers(write: (stream: Writable) => void): Buffer[] {
+  const buffers: Buffer[] = [];
+  const writeStream = new Stream.Writable({
+    write(chunk, _encoding, callback) {
+      buffers.push(chunk);
+      callback(null);
+    },
+  });
+  write(writeStream);
+  writeStream.end("");
+  return buffers;
+}
+
+/**
+ * Hands `write` a `(chunk, done)` callback that feeds a writable stream, calling
+ * `done` only once the stream can take more data.
+ * A file path is opened as a write stream, and closed once everything is written.
+ */
+async function writeAsync(
+  dest: string | Writable,
+  write: (
+    cb: (chunk: Buffer, done: (err?: Error | null) => void) => void,
+  ) => Promise<void>,
+): Promise<void> {
+  const stream = typeof dest === "string" ? createWriteStream(dest) : dest;
+  if (typeof dest === "string") {
+    // errors on a stream we own are surfaced through `stream.errored`
+    stream.on("error", () => {});
+  }
+  try {
+    await write((chunk, done) => {
+      if (stream.errored || stream.destroyed) {
+        return done(stream.errored ?? new Error("write stream was destroyed"));
+      }
+      const onDrain = () => {
+        stream.off("error", onError);
+        done();
+      };
+      const onError = (err: Error) => {
+        stream.off("drain", onDrain);
+        done(err);
+      };
+      try {
+        if (stream.write(chunk)) {
+          return done();
+        }
+        stream.once("drain", onDrain);
+        stream.once("error", onError);
+      } catch (err) {
+        done(err as Error);
+      }
+    });
+  } catch (err) {
+    // close the file we opened
+    if (typeof dest === "string") {
+      stream.destroy();
+    }
+    throw err;
+  }
+  if (typeof dest === "string") {
+    await new Promise<void>((resolve, reject) =>
+      stream.end((err?: Error | null) => (err ? reject(err) : resolve())),
+    );
+  } else if (stream.errored) {
+    throw stream.errored;
+  }
+}
+
 /**
  * @ignore
  */
@@ -2368,24 +2516,25 @@ export const _DataFrame = (_df: any): DataFrame => {
       return this.writeCSV(...args);
     },
     writeCSV(dest?, options = {}) {
-      if (dest instanceof Writable || typeof dest === "string") {
+      if (typeof dest === "string") {
         return _df.writeCsv(dest, options) as any;
       }
-      const buffers: Buffer[] = [];
-      const writeStream = new Stream.Writable({
-        write(chunk, _encoding, callback) {
-          buffers.push(chunk);
-          callback(null);
-        },
-      });
-      _df.writeCsv(writeStream as any, dest ?? options);
-      writeStream.end("");
-
-      return Buffer.concat(buffers);
+      if (dest instanceof Writable) {
+        return this.writeCSVAsync(dest, options);
+      }
+      return Buffer.concat(
+        writeToBuffers((stream) => _df.writeCsv(stream, dest ?? options)),
+      );
+    },
+    writeCSVAsync(dest, options = {}) {
+      return writeAsync(dest, (cb) => _df.writeCsv(cb, options));
     },
     toRecords() {
       return _df.toObjects();
     },
+    toArrow() {
+      return _df.toArrow();
+    },
     toJSON(...args: any[]) {
       // this is passed by `JSON.stringify` when calling `toJSON()`
       if (args[0] === "") {
@@ -2434,78 +2583,76 @@ export const _DataFrame = (_df: any): DataFrame => {
       }, {});
     },
     writeJSON(dest?, options = { format: "lines" }) {
-      if (dest instanceof Writable || typeof dest === "string") {
+      if (typeof dest === "string") {
         return _df.writeJson(dest, options) as any;
       }
-      const buffers: Buffer[] = [];
-      const writeStream = new Stream.Writable({
-        write(chunk, _encoding, callback) {
-          buffers.push(chunk);
-          callback(null);
-        },
-      });
-
-      _df.writeJson(writeStream, { ...options, ...dest });
-      writeStream.end("");
-
-      return Buffer.concat(buffers);
+      if (dest instanceof Writable) {
+        return this.writeJSONAsync(dest, options);
+      }
+      return Buffer.concat(
+        writeToBuffers((stream) =>
+          _df.writeJson(stream, { ...options, ...dest }),
+        ),
+      );
+    },
+    writeJSONAsync(dest, options = { format: "lines" }) {
+      return writeAsync(dest, (cb) => _df.writeJson(cb, options));
     },
     toParquet(dest?, options?) {
       return this.writeParquet(dest, options);
     },
     writeParquet(dest?, options = { compression: "uncompressed" }) {
-      if (dest instanceof Writable || typeof dest === "string") {
+      if (typeof dest === "string") {
         return _df.writeParquet(dest, options.compression) as any;
       }
-      const buffers: Buffer[] = [];
-      const writeStream = new Stream.Writable({
-        write(chunk, _encoding, callback) {
-          buffers.push(chunk);
-          callback(null);
-        },
-      });
-
-      _df.writeParquet(writeStream, dest?.compression ?? options?.compression);
-      writeStream.end("");
-
-      return Buffer.concat(buffers);
+      if (dest instanceof Writable) {
+        return this.writeParquetAsync(dest, options);
+      }
+      return Buffer.concat(
+        writeToBuffers((stream) =>
+          _df.writeParquet(stream, dest?.compression ?? options?.compression),
+        ),
+      );
+    },
+    writeParquetAsync(dest, options = { compression: "uncompressed" }) {
+      return writeAsync(dest, (cb) =>
+        _df.writeParquet(cb, options.compression),
+      );
     },
     writeAvro(dest?, options = { compression: "uncompressed" }) {
-      if (dest instanceof Writable || typeof dest === "string") {
+      if (typeof dest === "string") {
         return _df.writeAvro(dest, options.compression) as any;
       }
-      const buffers: Buffer[] = [];
-      const writeStream = new Stream.Writable({
-        write(chunk, _encoding, callback) {
-          buffers.push(chunk);
-          callback(null);
-        },
-      });
-
-      _df.writeAvro(writeStream, dest?.compression ?? options?.compression);
-      writeStream.end("");
-
-      return Buffer.concat(buffers);
+      if (dest instanceof Writable) {
+        return this.writeAvroAsync(dest, options);
+      }
+      return Buffer.concat(
+        writeToBuffers((stream) =>
+          _df.writeAvro(stream, dest?.compression ?? options?.compression),
+        ),
+      );
+    },
+    writeAvroAsync(dest, options = { compression: "uncompressed" }) {
+      return writeAsync(dest, (cb) => _df.writeAvro(cb, options.compression));
     },
     toIPC(dest?, options?) {
       return this.writeIPC(dest, options);
     },
     writeIPC(dest?, options = { compression: "uncompressed" }) {
-      if (dest instanceof Writable || typeof dest === "string") {
+      if (typeof dest === "string") {
         return _df.writeIpc(dest, options.compression) as any;
       }
-      const buffers: Buffer[] = [];
-      const writeStream = new Stream.Writable({
-        write(chunk, _encoding, callback) {
-          buffers.push(chunk);
-          callback(null);
-        },
-      });
-
-      _df.writeIpc(writeStream, dest?.compression ?? options?.compression);
-      writeStream.end("");
-
-      return Buffer.concat(buffers);
+      if (dest instanceof Writable) {
+        return this.writeIPCAsync(dest, options);
+      }
+      return Buffer.concat(
+        writeToBuffers((stream) =>
+          _df.writeIpc(stream, dest?.compression ?? options?.compression),
+        ),
+      );
+    },
+    writeIPCAsync(dest, options = { compression: "uncompressed" }) {
+      return writeAsync(dest, (cb) => _df.writeIpc(cb, options.compression));
     },
     toSeries: (index = 0) => _Series(_df.selectAtIdx(index) as any) as any,
     toStruct(name) {
@@ -2697,6 +2844,17 @@ export interface DataFrameConstructor extends Deserialize<DataFrame> {
     },
   ): DataFrame;
   isDataFrame(arg: any): arg is DataFrame;
+  /**
+   * Create a DataFrame from an apache-arrow `Table` or `RecordBatch`, or an object of the same shape.
+   *
+   * The buffers are copied into the DataFrame.
+   * @example
+   * ```
+   * > const table = tableFromArrays({ a: Int32Array.of(1, 2, 3) });
+   * > pl.DataFrame.fromArrow(table)
+   * ```
+   */
+  fromArrow(table: ArrowTable | ArrowRecordBatch): DataFrame;
 }
 
 function DataFrameConstructor(data?, options?): DataFrame {
@@ -2767,5 +2925,16 @@ export const DataFrame: DataFrameConstructor = Object.assign(
     isDataFrame,
     deserialize: (buf, fmt) =>
       _DataFrame(pli.JsDataFrame.deserialize(buf, fmt)),
+    fromArrow: (table: ArrowTable | ArrowRecordBatch) =>
+      _DataFrame(
+        pli.JsDataFrame.fromArrow(
+          "batches" in table
+            ? table
+            : {
+                schema: table.schema ?? { fields: table.data.type.children },
+                batches: [table],
+              },
+        ),
+      ),
   },
 );
@@ -68,20 +68,43 @@ export abstract class DataType {
   public static get Date(): DataType {
     return new Date();
   }
-  /** Time of day type */
+  /**
+   * Time of day type.
+   * Values are represented in JS as BigInt nanoseconds since midnight.
+   */
   public static get Time(): DataType {
     return new Time();
   }
-  /** Type for wrapping arbitrary JS objects */
+  /**
+   * Type for wrapping arbitrary JS objects.
+   * Values are held by reference and returned unchanged, but are opaque to
+   * polars: they can be filtered, sorted by other columns and joined, but
+   * not compared or computed on.
+   */
   public static get Object(): DataType {
     return new Object_();
   }
-  /** A categorical encoding of a set of strings  */
-  public static get Categorical(): DataType {
+  /**
+   * A categorical encoding of a set of strings.
+   * Categories are ordered physically, use `.withOrdering("lexical")` to sort them lexically.
+   */
+  public static get Categorical(): Categorical {
     return new Categorical();
   }
+  /**
+   * A categorical encoding of a fixed set of strings.
+   * Values outside of `categories` are rejected.
+   * @param categories the valid categories, in order
+   */
+  public static Enum(categories: string[]): DataType {
+    return new Enum(categories);
+  }
 
-  /** Decimal type */
+  /**
+   * Decimal type.
+   * Values are represented in JS as exact decimal strings, e.g. `"12.30"`.
+   * They can be created from strings, numbers, BigInts or `{ value: bigint, scale: number }` objects.
+   */
   public static Decimal(precision?: number, scale?: number): DataType {
     return new Decimal(precision, scale);
   }
@@ -100,6 +123,18 @@ export abstract class DataType {
   ): DataType {
     return new Datetime(timeUnit, timeZone as any);
   }
+  /**
+   * Time duration type.
+   * Values are represented in JS as BigInt nanoseconds, whatever the time unit.
+   * When creating a series, numbers are taken to be in `timeUnit` instead, and
+   * BigInts that are not a whole number of `timeUnit` raise an error.
+   * @param timeUnit any of 'ms' | 'ns' | 'us'
+   */
+  public static Duration(timeUnit: TimeUnit): DataType;
+  public static Duration(timeUnit: "ms" | "ns" | "us"): DataType;
+  public static Duration(timeUnit): DataType {
+    return new Duration(timeUnit);
+  }
   /**
    * Nested list/array type
    *
@@ -135,6 +170,10 @@ export abstract class DataType {
   public static get String(): DataType {
     return new String();
   }
+  /** Variable-length binary data, represented in JS as a `Buffer` */
+  public static get Binary(): DataType {
+    return new Binary();
+  }
 
   toString() {
     if (this.inner) {
@@ -188,8 +227,50 @@ export class Object_ extends DataType {}
 export class Utf8 extends DataType {}
 // biome-ignore lint/suspicious/noShadowRestrictedNames: <explanation>
 export class String extends DataType {}
+export class Binary extends DataType {}
+
+export type CategoricalOrdering = "physical" | "lexical";
+
+export class Categorical extends DataType {
+  constructor(private ordering: CategoricalOrdering = "physical") {
+    super();
+  }
+  override get inner() {
+    return [this.ordering];
+  }
+  override equals(other: DataType): boolean {
+    if (other.variant === this.variant) {
+      return this.ordering === (other as Categorical).ordering;
+    }
+    return false;
+  }
+  /** Returns a categorical type with the given ordering */
+  withOrdering(ordering: CategoricalOrdering): Categorical {
+    return new Categorical(ordering);
+  }
+}
 
-export class Categorical extends DataType {}
+/**
+ * Enum type
+ */
+export class Enum extends DataType {
+  constructor(private categories: string[]) {
+    super();
+  }
+  override get inner() {
+    return [this.categories];
+  }
+  override equals(other: DataType): boolean {
+    if (other.variant === this.variant) {
+      const categories = (other as Enum).categories;
+      return (
+        this.categories.length === categories.length &&
+        this.categories.every((c, idx) => c === categories[idx])
+      );
+    }
+    return false;
+  }
+}
 
 export class Decimal extends DataType {
   private precision: number | null;
@@ -249,6 +330,25 @@ export class Datetime extends DataType {
   }
 }
 
+/**
+ * Duration type
+ */
+export class Duration extends DataType {
+  constructor(private timeUnit: TimeUnit) {
+    super();
+  }
+  override get inner() {
+    return [this.timeUnit];
+  }
+
+  override equals(other: DataType): boolean {
+    if (other.variant === this.variant) {
+      return this.timeUnit === (other as Duration).timeUnit;
+    }
+    return false;
+  }
+}
+
 export class List extends DataType {
   constructor(protected __inner: DataType) {
     super();
@@ -362,6 +462,7 @@ export namespace TimeUnit {
  */
 export namespace DataType {
   export type Categorical = import(".").Categorical;
+  export type Enum = import(".").Enum;
   export type Int8 = import(".").Int8;
   export type Int16 = import(".").Int16;
   export type Int32 = import(".").Int32;
@@ -375,10 +476,12 @@ export namespace DataType {
   export type Bool = import(".").Bool;
   export type Utf8 = import(".").Utf8;
   export type String = import(".").String;
+  export type Binary = import(".").Binary;
   export type List = import(".").List;
   export type FixedSizeList = import(".").FixedSizeList;
   export type Date = import(".").Date;
   export type Datetime = import(".").Datetime;
+  export type Duration = import(".").Duration;
   export type Time = import(".").Time;
   export type Object = import(".").Object_;
   export type Null = import(".").Null;
@@ -394,6 +497,9 @@ export namespace DataType {
     }
 
     let { variant, inner } = dtype;
+    if (variant === "Categorical") {
+      return new Categorical(inner[0]);
+    }
     if (variant === "Struct") {
       inner = [
         inner[0].map((fld) => Field.from(fld.name, deserialize(fld.dtype))),
@@ -52,6 +52,7 @@ export const DTYPE_TO_FFINAME = {
   Time: "Time",
   Object: "Object",
   Categorical: "Categorical",
+  Enum: "Categorical",
   Struct: "Struct",
 };
 
@@ -92,6 +93,12 @@ const POLARS_TYPE_TO_CONSTRUCTOR: Record<string, any> = {
   Datetime(name, values, strict?) {
     return pli.JsSeries.newOptI64(name, values, strict);
   },
+  Duration(name, values, _strict, dtype) {
+    return pli.JsSeries.newOptDuration(name, values, dtype);
+  },
+  Time(name, values) {
+    return pli.JsSeries.newOptTime(name, values);
+  },
   Bool(name, values, strict?) {
     return pli.JsSeries.newOptBool(name, values, strict);
   },
@@ -101,9 +108,18 @@ const POLARS_TYPE_TO_CONSTRUCTOR: Record<string, any> = {
   String(name, values, strict?) {
     return (pli.JsSeries.newOptStr as any)(name, values, strict);
   },
+  Binary(name, values, strict?) {
+    return pli.JsSeries.newOptBinary(name, values, strict);
+  },
   Categorical(name, values, strict?) {
     return (pli.JsSeries.newOptStr as any)(name, values, strict);
   },
+  Enum(name, values, strict?) {
+    return (pli.JsSeries.newOptStr as any)(name, values, strict);
+  },
+  Object(name, values) {
+    return pli.JsSeries.newObject(name, values);
+  },
   List(name, values, _strict, dtype) {
     return pli.JsSeries.newList(name, values, dtype);
   },
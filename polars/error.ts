@@ -11,3 +11,60 @@ export class NotImplemented extends Error {
 }
 
 export const todo = () => new Error("not yet implemented");
+
+/**
+ * Kind of a {@link PolarsError}, mapped from the underlying Rust error.
+ */
+export type PolarsErrorCode =
+  | "ColumnNotFound"
+  | "ComputeError"
+  | "Duplicate"
+  | "InvalidOperation"
+  | "IO"
+  | "NoData"
+  | "OutOfBounds"
+  | "SchemaFieldNotFound"
+  | "SchemaMismatch"
+  | "ShapeMismatch"
+  | "StringCacheMismatch"
+  | "StructFieldNotFound"
+  | "InvalidArgument";
+
+/**
+ * Error thrown by the native polars bindings.
+ * @example
+ * ```
+ * > try {
+ * >   df.select("foo");
+ * > } catch (e) {
+ * >   if (e instanceof pl.PolarsError && e.code === "ColumnNotFound") {
+ * >     console.log(e.column);
+ * >   }
+ * > }
+ * foo
+ * ```
+ */
+export class PolarsError extends Error {
+  name = "PolarsError";
+  /** Stable kind of the error */
+  code: PolarsErrorCode;
+  /** The column or field the error refers to, if any */
+  column?: string;
+
+  constructor(code: PolarsErrorCode, message: string, column?: string) {
+    super(message);
+    this.code = code;
+    if (column !== undefined) {
+      this.column = column;
+    }
+  }
+
+  /**
+   * The native bindings create their errors as plain `Error`s named
+   * `PolarsError`, so these are matched by name.
+   * @ignore
+   */
+  static [Symbol.hasInstance](err: any): boolean {
+    return err instanceof Error && err.name === "PolarsError";
+  }
+}
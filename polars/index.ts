@@ -17,6 +17,7 @@ export * from "./lazy";
 import * as lazy from "./lazy";
 export * from "./types";
 import * as sql from "./sql";
+import * as error from "./error";
 export type { GroupBy } from "./groupby";
 
 export namespace pl {
@@ -30,6 +31,8 @@ export namespace pl {
   export type ChainedWhen = lazy.ChainedWhen;
   export type ChainedThen = lazy.ChainedThen;
   export import Config = cfg.Config;
+  export import PolarsError = error.PolarsError;
+  export type PolarsErrorCode = error.PolarsErrorCode;
 
   export import Field = _field;
   export import repeat = func.repeat;
@@ -47,9 +50,15 @@ export namespace pl {
   export import readJSON = io.readJSON;
   export import readParquet = io.readParquet;
   export import readAvro = io.readAvro;
+  export import readCSVAsync = io.readCSVAsync;
+  export import readIPCAsync = io.readIPCAsync;
+  export import readJSONAsync = io.readJSONAsync;
+  export import readParquetAsync = io.readParquetAsync;
+  export import readAvroAsync = io.readAvroAsync;
 
   export import readCSVStream = io.readCSVStream;
   export import readJSONStream = io.readJSONStream;
+  export import readIPCStream = io.readIPCStream;
 
   // lazy
   export import col = lazy.col;
@@ -90,6 +99,7 @@ export namespace pl {
   export const version = pli.version();
 
   export type Categorical = import("./datatypes").Categorical;
+  export type Enum = import("./datatypes").Enum;
   export type Int8 = import("./datatypes").Int8;
   export type Int16 = import("./datatypes").Int16;
   export type Int32 = import("./datatypes").Int32;
@@ -103,17 +113,20 @@ export namespace pl {
   export type Bool = import("./datatypes").Bool;
   export type Utf8 = import("./datatypes").Utf8;
   export type String = import("./datatypes").String;
+  export type Binary = import("./datatypes").Binary;
   export type List = import("./datatypes").List;
   export type FixedSizeList = import("./datatypes").FixedSizeList;
   export type Date = import("./datatypes").Date;
   export type Datetime = import("./datatypes").Datetime;
   export type Time = import("./datatypes").Time;
+  export type Duration = import("./datatypes").Duration;
   export type Object = import("./datatypes").Object_;
   export type Null = import("./datatypes").Null;
   export type Struct = import("./datatypes").Struct;
   export type Decimal = import("./datatypes").Decimal;
 
   export const Categorical = DataType.Categorical;
+  export const Enum = DataType.Enum;
   export const Int8 = DataType.Int8;
   export const Int16 = DataType.Int16;
   export const Int32 = DataType.Int32;
@@ -128,12 +141,14 @@ export namespace pl {
   export const Utf8 = DataType.Utf8;
   // biome-ignore lint/suspicious/noShadowRestrictedNames: pl.String
   export const String = DataType.String;
+  export const Binary = DataType.Binary;
   export const List = DataType.List;
   export const FixedSizeList = DataType.FixedSizeList;
   // biome-ignore lint/suspicious/noShadowRestrictedNames: pl.Date
   export const Date = DataType.Date;
   export const Datetime = DataType.Datetime;
   export const Time = DataType.Time;
+  export const Duration = DataType.Duration;
   // biome-ignore lint/suspicious/noShadowRestrictedNames: pl.Object
   export const Object = DataType.Object;
   export const Null = DataType.Null;
@@ -175,6 +190,8 @@ export type Then = lazy.Then;
 export type ChainedWhen = lazy.ChainedWhen;
 export type ChainedThen = lazy.ChainedThen;
 export import Config = cfg.Config;
+export import PolarsError = error.PolarsError;
+export type PolarsErrorCode = error.PolarsErrorCode;
 export import Field = _field;
 export import repeat = func.repeat;
 export import concat = func.concat;
@@ -191,9 +208,15 @@ export import readIPC = io.readIPC;
 export import readJSON = io.readJSON;
 export import readParquet = io.readParquet;
 export import readAvro = io.readAvro;
+export import readCSVAsync = io.readCSVAsync;
+export import readIPCAsync = io.readIPCAsync;
+export import readJSONAsync = io.readJSONAsync;
+export import readParquetAsync = io.readParquetAsync;
+export import readAvroAsync = io.readAvroAsync;
 
 export import readCSVStream = io.readCSVStream;
 export import readJSONStream = io.readJSONStream;
+export import readIPCStream = io.readIPCStream;
 
 // lazy
 export import col = lazy.col;
@@ -233,6 +256,7 @@ export import when = lazy.when;
 export const version = pli.version();
 
 export type Categorical = import("./datatypes").Categorical;
+export type Enum = import("./datatypes").Enum;
 export type Int8 = import("./datatypes").Int8;
 export type Int16 = import("./datatypes").Int16;
 export type Int32 = import("./datatypes").Int32;
@@ -246,17 +270,20 @@ export type Float64 = import("./datatypes").Float64;
 export type Bool = import("./datatypes").Bool;
 export type Utf8 = import("./datatypes").Utf8;
 export type String = import("./datatypes").String;
+export type Binary = import("./datatypes").Binary;
 export type List = import("./datatypes").List;
 export type FixedSizeList = import("./datatypes").FixedSizeList;
 export type Date = import("./datatypes").Date;
 export type Datetime = import("./datatypes").Datetime;
 export type Time = import("./datatypes").Time;
+export type Duration = import("./datatypes").Duration;
 export type Object = import("./datatypes").Object_;
 export type Null = import("./datatypes").Null;
 export type Struct = import("./datatypes").Struct;
 export type Decimal = import("./datatypes").Decimal;
 
 export const Categorical = DataType.Categorical;
+export const Enum = DataType.Enum;
 export const Int8 = DataType.Int8;
 export const Int16 = DataType.Int16;
 export const Int32 = DataType.Int32;
@@ -271,12 +298,14 @@ export const Bool = DataType.Bool;
 export const Utf8 = DataType.Utf8;
 // biome-ignore lint/suspicious/noShadowRestrictedNames: pl.String
 export const String = DataType.String;
+export const Binary = DataType.Binary;
 export const List = DataType.List;
 export const FixedSizeList = DataType.FixedSizeList;
 // biome-ignore lint/suspicious/noShadowRestrictedNames: pl.Date
 export const Date = DataType.Date;
 export const Datetime = DataType.Datetime;
 export const Time = DataType.Time;
+export const Duration = DataType.Duration;
 // biome-ignore lint/suspicious/noShadowRestrictedNames: pl.Object
 export const Object = DataType.Object;
 export const Null = DataType.Null;
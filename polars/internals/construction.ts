@@ -13,6 +13,9 @@ export const jsTypeToPolarsType = (value: unknown): DataType => {
   if (Array.isArray(value)) {
     return jsTypeToPolarsType(firstNonNull(value));
   }
+  if (Buffer.isBuffer(value)) {
+    return DataType.Binary;
+  }
   if (isTypedArray(value)) {
     switch (value.constructor.name) {
       case Int8Array.name:
@@ -55,7 +58,7 @@ export const jsTypeToPolarsType = (value: unknown): DataType => {
 
   switch (typeof value) {
     case "bigint":
-      return DataType.UInt64;
+      return value < 0n ? DataType.Int64 : DataType.UInt64;
     case "number":
       return DataType.Float64;
     case "string":
@@ -92,6 +95,21 @@ const firstNonNull = (arr: any[]): any => {
   return first;
 };
 
+/** BigInts are inferred as UInt64, unless any of them is negative */
+const hasNegativeBigInt = (values: any[]): boolean =>
+  values.some((v) =>
+    Array.isArray(v) ? hasNegativeBigInt(v) : typeof v === "bigint" && v < 0n,
+  );
+
+const inferDtype = (values: any[], firstValue: any): DataType => {
+  const dtype = jsTypeToPolarsType(firstValue);
+  if (dtype.equals(DataType.UInt64) && hasNegativeBigInt(values)) {
+    return DataType.Int64;
+  }
+
+  return dtype;
+};
+
 const fromTypedArray = (name, value) => {
   switch (value.constructor.name) {
     case Int8Array.name:
@@ -139,8 +157,11 @@ export function arrayToJsSeries(
     dtype = DataType.Float64;
   }
   const firstValue = firstNonNull(values);
-  if (Array.isArray(firstValue) || isTypedArray(firstValue)) {
-    const listDtype = jsTypeToPolarsType(firstValue);
+  const isBinary =
+    dtype?.variant === "Binary" ||
+    (dtype === undefined && Buffer.isBuffer(firstValue));
+  if (!isBinary && (Array.isArray(firstValue) || isTypedArray(firstValue))) {
+    const listDtype = inferDtype(values, firstValue);
     const ctor = polarsTypeToConstructor(DataType.List(listDtype));
     const s = ctor(name, values, strict, listDtype);
     if (dtype instanceof FixedSizeList) {
@@ -151,25 +172,28 @@ export function arrayToJsSeries(
     return s;
   }
 
-  dtype = dtype ?? jsTypeToPolarsType(firstValue);
+  const isInferred = dtype === undefined;
+  dtype = dtype ?? inferDtype(values, firstValue);
   let series: Series;
   if (dtype?.variant === "Struct") {
-    const df = pli.fromRows(values, null, 1);
+    const fields: Field[] = dtype.inner;
+    const schema =
+      isInferred || !fields.length
+        ? null
+        : Object.fromEntries(fields.map((f) => [f.name, f.dtype]));
+    const df = pli.fromRows(values, schema, values.length, strict);
 
     return df.toStruct(name);
   }
 
   if (dtype?.variant === "Decimal") {
-    if (typeof firstValue !== "bigint") {
-      throw new Error("Decimal type can only be constructed from BigInt");
-    }
-    return pli.JsSeries.newAnyvalue(name, values, dtype, strict);
+    return pli.JsSeries.newOptDecimal(name, values, dtype);
   }
   if (firstValue instanceof Date) {
     series = pli.JsSeries.newOptDate(name, values, strict);
   } else {
     const ctor = polarsTypeToConstructor(dtype);
-    series = ctor(name, values, strict);
+    series = ctor(name, values, strict, dtype);
   }
 
   if (
@@ -177,6 +201,7 @@ export function arrayToJsSeries(
       "Datetime",
       "Date",
       "Categorical",
+      "Enum",
       "Int8",
       "Int16",
       "UInt8",
@@ -1,3 +1,6 @@
 import pl from "../native-polars.js";
 
+// lets the bindings throw errors carrying their `code` from this thread
+pl.initErrors();
+
 export default pl;
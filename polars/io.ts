@@ -113,29 +113,45 @@ class LineBatcher extends Stream.Transform {
 
 // helper functions
 
-function readCSVBuffer(buff, options) {
-  return _DataFrame(
-    pli.readCsv(buff, { ...readCsvDefaultOptions, ...options }),
-  );
-}
-
+/**
+ * __Read an array of objects into a DataFrame.__
+ * ___
+ * Nested arrays and objects are read as `List` and `Struct` columns.
+ * @param records - array of objects
+ * @param options.schema - schema to coerce the values to, instead of inferring it
+ * @param options.inferSchemaLength - number of records used to infer the schema
+ * @param options.strict - throw if a value cannot be coerced to its column type, instead of setting it to null
+ * @example
+ * ```
+ * > const df = pl.readRecords([{ a: 1, b: [{ c: "x" }] }, { a: 2, b: [] }]);
+ * > df.getColumn("b").dtype.equals(pl.List(pl.Struct({ c: pl.Utf8 })))
+ * true
+ * ```
+ */
 export function readRecords(
   records: Record<string, any>[],
-  options?: { schema: Record<string, DataType> },
+  options?: { schema: Record<string, DataType>; strict?: boolean },
 ): DataFrame;
 export function readRecords(
   records: Record<string, any>[],
-  options?: { inferSchemaLength?: number },
+  options?: { inferSchemaLength?: number; strict?: boolean },
 ): DataFrame;
 export function readRecords(
   records: Record<string, any>[],
   options,
 ): DataFrame {
   if (options?.schema) {
-    return _DataFrame(pli.fromRows(records, options.schema));
+    return _DataFrame(
+      pli.fromRows(records, options.schema, undefined, options.strict),
+    );
   }
   return _DataFrame(
-    pli.fromRows(records, undefined, options?.inferSchemaLength),
+    pli.fromRows(
+      records,
+      undefined,
+      options?.inferSchemaLength,
+      options?.strict,
+    ),
   );
 }
 
@@ -200,6 +216,38 @@ export function readCSV(pathOrBody, options?) {
   throw new Error("must supply either a path or body");
 }
 
+/**
+ * __Read a CSV file or string into a Dataframe without blocking the event loop.__
+ *
+ * Behaves the same as {@link readCSV}, but parses on a worker thread and returns a Promise.
+ * @example
+ * ```
+ * > const df = await pl.readCSVAsync("./file.csv", { sep: "," });
+ * ```
+ */
+export function readCSVAsync(
+  pathOrBody: string | Buffer,
+  options?: Partial<ReadCsvOptions>,
+): Promise<DataFrame>;
+export async function readCSVAsync(pathOrBody, options?) {
+  options = { ...readCsvDefaultOptions, ...options };
+  const extensions = [".tsv", ".csv"];
+
+  if (Buffer.isBuffer(pathOrBody)) {
+    return _DataFrame(await pli.readCsvAsync(pathOrBody, options));
+  }
+  if (typeof pathOrBody === "string") {
+    const inline = !isPath(pathOrBody, extensions);
+    if (inline) {
+      const buf = Buffer.from(pathOrBody, "utf-8");
+
+      return _DataFrame(await pli.readCsvAsync(buf, options));
+    }
+    return _DataFrame(await pli.readCsvAsync(pathOrBody, options));
+  }
+  throw new Error("must supply either a path or body");
+}
+
 export interface ScanCsvOptions {
   hasHeader: boolean;
   sep: string;
@@ -221,6 +269,8 @@ export interface ScanCsvOptions {
   raiseIfEmpty: boolean;
   truncateRaggedLines: boolean;
   schema: Record<string, DataType>;
+  cloudOptions: Record<string, string>;
+  retries: number;
 }
 
 const scanCsvDefaultOptions: Partial<ScanCsvOptions> = {
@@ -264,6 +314,11 @@ const scanCsvDefaultOptions: Partial<ScanCsvOptions> = {
  *     cannot be guaranteed.
  * @param options.rechunk -Make sure that all columns are contiguous in memory by aggregating the chunks into a single array.
  * @param options.lowMemory - Reduce memory usage in expense of performance.
+ * @param options.schema -Set the CSV file's schema. This only accepts datatypes that are implemented in the csv parser and expects a complete Schema.
+ *     For remote files, this avoids downloading the head of the first file to infer the schema when the scan is created.
+ * @param options.cloudOptions - Options that indicate how to connect to a cloud provider, e.g. `aws_endpoint_url` or `aws_region`.
+ *     Remote files are downloaded to temporary files while the query runs and removed once read. Glob patterns are supported.
+ * @param options.retries - Number of retries if accessing a cloud instance fails. Default - 2
  * ___
  *
  */
@@ -332,6 +387,39 @@ export function readJSON(
   }
   throw new Error("must supply either a path or body");
 }
+
+/**
+ * __Read a JSON file or string into a DataFrame without blocking the event loop.__
+ *
+ * Behaves the same as {@link readJSON}, but parses on a worker thread and returns a Promise.
+ */
+export function readJSONAsync(
+  pathOrBody: string | Buffer,
+  options?: Partial<ReadJsonOptions>,
+): Promise<DataFrame>;
+export async function readJSONAsync(
+  pathOrBody,
+  options: Partial<ReadJsonOptions> = readJsonDefaultOptions,
+) {
+  options = { ...readJsonDefaultOptions, ...options };
+  const method =
+    options.format === "lines" ? pli.readJsonLinesAsync : pli.readJsonAsync;
+  const extensions = [".ndjson", ".json", ".jsonl"];
+  if (Buffer.isBuffer(pathOrBody)) {
+    return _DataFrame(await method(pathOrBody, options));
+  }
+
+  if (typeof pathOrBody === "string") {
+    const inline = !isPath(pathOrBody, extensions);
+    if (inline) {
+      return _DataFrame(
+        await method(Buffer.from(pathOrBody, "utf-8"), options),
+      );
+    }
+    return _DataFrame(await method(pathOrBody, options));
+  }
+  throw new Error("must supply either a path or body");
+}
 interface ScanJsonOptions {
   inferSchemaLength: number | null;
   nThreads: number;
@@ -340,6 +428,9 @@ interface ScanJsonOptions {
   numRows: number;
   skipRows: number;
   rowCount: RowCount;
+  schema: Record<string, DataType>;
+  cloudOptions: Record<string, string>;
+  retries: number;
 }
 
 /**
@@ -357,6 +448,11 @@ interface ScanJsonOptions {
  * @param options.numRows  Stop reading from parquet file after reading ``numRows``.
  * @param options.skipRows -Start reading after ``skipRows`` position.
  * @param options.rowCount Add row count as column
+ * @param options.schema - Set the schema instead of inferring it. For remote files, this avoids downloading the head of the
+ *     first file when the scan is created.
+ * @param options.cloudOptions - Options that indicate how to connect to a cloud provider, e.g. `aws_endpoint_url` or `aws_region`.
+ *     Remote files are downloaded to temporary files while the query runs and removed once read. Glob patterns are supported.
+ * @param options.retries - Number of retries if accessing a cloud instance fails. Default - 2
  * @returns ({@link DataFrame})
  * @example
  * ```
@@ -436,6 +532,51 @@ export function readParquet(
   throw new Error("must supply either a path or body");
 }
 
+/**
+ * __Read into a DataFrame from a parquet file without blocking the event loop.__
+ *
+ * Behaves the same as {@link readParquet}, but reads on a worker thread and returns a Promise.
+ */
+export async function readParquetAsync(
+  pathOrBody: string | Buffer,
+  options?: Partial<ReadParquetOptions>,
+): Promise<DataFrame> {
+  const pliOptions: any = {};
+
+  if (typeof options?.columns?.[0] === "number") {
+    pliOptions.projection = options?.columns;
+  } else {
+    pliOptions.columns = options?.columns;
+  }
+
+  pliOptions.nRows = options?.numRows;
+  pliOptions.rowCount = options?.rowCount;
+  const parallel = options?.parallel ?? "auto";
+
+  if (Buffer.isBuffer(pathOrBody)) {
+    return _DataFrame(
+      await pli.readParquetAsync(pathOrBody, pliOptions, parallel),
+    );
+  }
+
+  if (typeof pathOrBody === "string") {
+    const inline = !isPath(pathOrBody, [".parquet"]);
+    if (inline) {
+      return _DataFrame(
+        await pli.readParquetAsync(
+          Buffer.from(pathOrBody),
+          pliOptions,
+          parallel,
+        ),
+      );
+    }
+    return _DataFrame(
+      await pli.readParquetAsync(pathOrBody, pliOptions, parallel),
+    );
+  }
+  throw new Error("must supply either a path or body");
+}
+
 export interface ReadAvroOptions {
   columns: string[] | Array<string> | number[];
   projection: number;
@@ -470,6 +611,32 @@ export function readAvro(pathOrBody, options = {}) {
   throw new Error("must supply either a path or body");
 }
 
+/**
+ * __Read into a DataFrame from an avro file without blocking the event loop.__
+ *
+ * Behaves the same as {@link readAvro}, but reads on a worker thread and returns a Promise.
+ */
+export function readAvroAsync(
+  pathOrBody: string | Buffer,
+  options?: Partial<ReadAvroOptions>,
+): Promise<DataFrame>;
+export async function readAvroAsync(pathOrBody, options = {}) {
+  if (Buffer.isBuffer(pathOrBody)) {
+    return _DataFrame(await pli.readAvroAsync(pathOrBody, options));
+  }
+
+  if (typeof pathOrBody === "string") {
+    const inline = !isPath(pathOrBody, [".avro"]);
+    if (inline) {
+      return _DataFrame(
+        await pli.readAvroAsync(Buffer.from(pathOrBody), options),
+      );
+    }
+    return _DataFrame(await pli.readAvroAsync(pathOrBody, options));
+  }
+  throw new Error("must supply either a path or body");
+}
+
 interface RowCount {
   name: string;
   offset: string;
@@ -483,8 +650,10 @@ interface ScanParquetOptions {
   rechunk?: boolean;
   lowMemory?: boolean;
   useStatistics?: boolean;
-  cloudOptions?: Map<string, string>;
+  cloudOptions?: Record<string, string>;
   retries?: number;
+  hivePartitioning?: boolean;
+  hiveSchema?: Record<string, DataType>;
 }
 
 /**
@@ -503,6 +672,10 @@ interface ScanParquetOptions {
         This determines the direction of parallelism. 'auto' will try to determine the optimal direction.
    @param options.useStatistics - Use statistics in the parquet to determine if pages can be skipped from reading.
    @param options.hivePartitioning - Infer statistics and schema from hive partitioned URL and use them to prune reads.
+        Paths such as `year=2024/month=05/data.parquet` add `year` and `month` columns, and predicates on those
+        columns skip non-matching directories entirely. Default - false, or true when `hiveSchema` is given.
+   @param options.hiveSchema - The column names and data types of the partition columns.
+        If not set, the data types are inferred from the directory names.
    @param options.rechunk - In case of reading multiple files via a glob pattern rechunk the final DataFrame into contiguous memory chunks.
    @param options.lowMemory - Reduce memory pressure at the expense of performance.
    @param options.cache - Cache the result after reading.
@@ -558,10 +731,38 @@ export function readIPC(pathOrBody, options = {}) {
   throw new Error("must supply either a path or body");
 }
 
+/**
+ * __Read into a DataFrame from Arrow IPC (Feather v2) file without blocking the event loop.__
+ *
+ * Behaves the same as {@link readIPC}, but reads on a worker thread and returns a Promise.
+ */
+export function readIPCAsync(
+  pathOrBody: string | Buffer,
+  options?: Partial<ReadIPCOptions>,
+): Promise<DataFrame>;
+export async function readIPCAsync(pathOrBody, options = {}) {
+  if (Buffer.isBuffer(pathOrBody)) {
+    return _DataFrame(await pli.readIpcAsync(pathOrBody, options));
+  }
+
+  if (typeof pathOrBody === "string") {
+    const inline = !isPath(pathOrBody, [".ipc"]);
+    if (inline) {
+      return _DataFrame(
+        await pli.readIpcAsync(Buffer.from(pathOrBody, "utf-8"), options),
+      );
+    }
+    return _DataFrame(await pli.readIpcAsync(pathOrBody, options));
+  }
+  throw new Error("must supply either a path or body");
+}
+
 export interface ScanIPCOptions {
   nRows: number;
   cache: boolean;
   rechunk: boolean;
+  cloudOptions: Record<string, string>;
+  retries: number;
 }
 
 /**
@@ -571,6 +772,8 @@ export interface ScanIPCOptions {
  * @param options.nRows Stop reading from IPC file after reading ``nRows``
  * @param options.cache Cache the result after reading.
  * @param options.rechunk Reallocate to contiguous memory when all chunks/ files are parsed.
+ * @param options.cloudOptions - Options that indicate how to connect to a cloud provider, e.g. `aws_endpoint_url` or `aws_region`.
+ * @param options.retries - Number of retries if accessing a cloud instance fails. Default - 2
  */
 export function scanIPC(
   path: string,
@@ -583,13 +786,13 @@ export function scanIPC(path, options = {}) {
 /**
  * __Read a stream into a Dataframe.__
  *
- * **Warning:** this is much slower than `scanCSV` or `readCSV`
- *
- * This will consume the entire stream into a single buffer and then call `readCSV`
- * Only use it when you must consume from a stream, or when performance is not a major consideration
+ * Chunks are pulled from the stream on a worker thread, so the payload is never buffered
+ * on the JS side. The CSV parser needs the whole input in memory, so the stream is read
+ * to its end unless `endRows` is set, in which case only enough lines to fill it are read.
+ * Prefer `scanCSV` or `readCSV` when the data is already on disk.
  *
  * ___
- * @param stream - readable stream containing csv data
+ * @param stream - readable stream or async iterable of Buffers containing csv data
  * @param options
  * @param options.inferSchemaLength -Maximum number of lines to read to infer schema. If set to 0, all columns will be read as pl.Utf8.
  *     If set to `null`, a full table scan will be done (slow).
@@ -646,45 +849,25 @@ export function scanIPC(path, options = {}) {
  * ```
  */
 export function readCSVStream(
-  stream: Readable,
+  stream: Readable | AsyncIterable<Buffer | string>,
   options?: Partial<ReadCsvOptions>,
 ): Promise<DataFrame>;
-export function readCSVStream(stream, options?) {
-  const batchSize = options?.batchSize ?? 10000;
-  let count = 0;
-  const end = options?.endRows ?? Number.POSITIVE_INFINITY;
-
-  return new Promise((resolve, reject) => {
-    const s = stream.pipe(new LineBatcher({ batchSize }));
-    const chunks: any[] = [];
+export async function readCSVStream(stream, options?) {
+  options = { ...readCsvDefaultOptions, ...options };
+  options.nRows ??= options.endRows;
 
-    s.on("data", (chunk) => {
-      // early abort if 'end rows' is specified
-      if (count <= end) {
-        chunks.push(chunk);
-      } else {
-        s.end();
-      }
-      count += batchSize;
-    }).on("end", () => {
-      try {
-        const buff = Buffer.concat(chunks);
-        const df = readCSVBuffer(buff, options);
-        resolve(df);
-      } catch (err) {
-        reject(err);
-      }
-    });
-  });
+  return _DataFrame(await readFromStream(stream, pli.readCsvStream, options));
 }
 /**
  * __Read a newline delimited JSON stream into a DataFrame.__
  *
- * @param stream - readable stream containing json data
+ * @param stream - readable stream or async iterable of Buffers containing json data.
+ *    Newline delimited data is pulled from the stream on a worker thread and parsed `batchSize` lines at a time.
  * @param options
  * @param options.inferSchemaLength -Maximum number of lines to read to infer schema. If set to 0, all columns will be read as pl.Utf8.
  *    If set to `null`, a full table scan will be done (slow).
- *    Note: this is done per batch
+ *    Note: with `format: "lines"` the schema is inferred from the first batch, which holds at least this many lines.
+ *    Otherwise this is done per batch.
  * @param options.batchSize - Number of lines to read into the buffer at once. Modify this to change performance.
  * @example
  * ```
@@ -713,11 +896,16 @@ export function readCSVStream(stream, options?) {
  * ```
  */
 export function readJSONStream(
-  stream: Readable,
+  stream: Readable | AsyncIterable<Buffer | string>,
   options?: Partial<ReadJsonOptions>,
 ): Promise<DataFrame>;
 export function readJSONStream(stream, options = readJsonDefaultOptions) {
   options = { ...readJsonDefaultOptions, ...options };
+  if (options.format === "lines") {
+    return readFromStream(stream, pli.readJsonLinesStream, options).then(
+      _DataFrame,
+    );
+  }
 
   return new Promise((resolve, reject) => {
     const chunks: any[] = [];
@@ -742,3 +930,56 @@ export function readJSONStream(stream, options = readJsonDefaultOptions) {
       });
   });
 }
+
+/**
+ * __Read an Arrow IPC stream into a DataFrame.__
+ *
+ * Reads the IPC *streaming* format (as written by `pyarrow.ipc.new_stream`), not IPC files.
+ * Chunks are pulled from the stream on a worker thread, and each record batch is decoded as it arrives.
+ * ___
+ * @param stream - readable stream or async iterable of Buffers containing IPC stream data
+ * @param options.columns Columns to select. Accepts a list of column names.
+ * @param options.nRows Stop reading from the stream after reading ``nRows``.
+ * @example
+ * ```
+ * > const df = await pl.readIPCStream(fs.createReadStream("./data.arrows"));
+ * ```
+ */
+export function readIPCStream(
+  stream: Readable | AsyncIterable<Buffer | string>,
+  options?: Partial<ReadIPCOptions>,
+): Promise<DataFrame>;
+export async function readIPCStream(stream, options = {}) {
+  return _DataFrame(await readFromStream(stream, pli.readIpcStream, options));
+}
+
+/**
+ * Hands `read` a `(done) => void` callback that answers each call with the next
+ * chunk of `stream`, or `null` once it is exhausted.
+ * The stream is destroyed once `read` settles, in case it stopped early.
+ */
+async function readFromStream(
+  stream: Readable | AsyncIterable<Buffer | string>,
+  read: (
+    pull: (done: (err: any, chunk?: Buffer | null) => void) => void,
+    options: any,
+  ) => Promise<any>,
+  options: any,
+) {
+  const iterator = stream[Symbol.asyncIterator]();
+  const pull = (done) => {
+    iterator.next().then(
+      ({ value, done: finished }) =>
+        done(
+          null,
+          finished ? null : Buffer.isBuffer(value) ? value : Buffer.from(value),
+        ),
+      (err) => done(err),
+    );
+  };
+  try {
+    return await read(pull, options);
+  } finally {
+    await iterator.return?.();
+  }
+}
@@ -1,4 +1,5 @@
 import { type DataFrame, _DataFrame } from "../dataframe";
+import { DataType } from "../datatypes";
 import { Expr, exprToLitOrExpr } from "./expr";
 import pli from "../internals/polars_internal";
 import {
@@ -12,10 +13,13 @@ import {
 import { _LazyGroupBy, type LazyGroupBy } from "./groupby";
 import type { Deserialize, GroupByOps, Serialize } from "../shared_traits";
 import type {
+  AbortOptions,
   LazyOptions,
   LazyJoinOptions,
   SinkCsvOptions,
   SinkParquetOptions,
+  SinkIpcOptions,
+  SinkJsonOptions,
 } from "../types";
 import type { Series } from "../series";
 
@@ -30,6 +34,20 @@ export interface LazyDataFrame extends Serialize, GroupByOps<LazyGroupBy> {
   [inspect](): string;
   [Symbol.toStringTag]: string;
   get columns(): string[];
+  /**
+   * Output schema of the query, resolved from the plan without running it.
+   * @example
+   * ```
+   * > const lf = pl.DataFrame({ foo: [1, 2], bar: ["a", "b"] }).lazy();
+   * > lf.withColumns(pl.col("foo").cast(pl.Int32)).schema;
+   * { foo: Int32, bar: String }
+   * ```
+   */
+  get schema(): Record<string, DataType>;
+  /** Data types of the output columns, see {@link LazyDataFrame.schema} */
+  get dtypes(): DataType[];
+  /** Number of output columns, see {@link LazyDataFrame.schema} */
+  get width(): number;
   /**
    * Cache the result once the execution of the physical plan hits this node.
    */
@@ -49,12 +67,64 @@ export interface LazyDataFrame extends Serialize, GroupByOps<LazyGroupBy> {
    *     Caution!
    * *  If you already have set a global string cache, set this to `false` as this will reset the
    * *  global cache when the query is finished.
+   * @param slicePushdown - Do slice pushdown optimization.
+   * @param commSubplanElim - Cache branching subplans that occur on self-joins or unions.
+   * @param commSubexprElim - Common subexpressions will be cached and reused.
+   * @param streaming - Process the query in batches to handle larger-than-memory data.
+   *     Parts of the query that the streaming engine does not support run on the default engine.
    * @param noOptimization - Turn off optimizations.
+   * @param signal - An `AbortSignal` that interrupts the query.
+   *     The Promise rejects with `signal.reason` once the query has stopped.
    * @return DataFrame
-   *
+   * @example
+   * ```
+   * > const df = await pl.scanCSV("/path/to/my_larger_than_ram_file.csv")
+   * >   .groupBy("key")
+   * >   .agg(pl.col("value").sum())
+   * >   .collect({ streaming: true });
+   * >
+   * > const controller = new AbortController();
+   * > setTimeout(() => controller.abort(), 1000);
+   * > await lf.collect({ signal: controller.signal });
+   * Uncaught AbortError: This operation was aborted
+   * ```
    */
-  collect(opts?: LazyOptions): Promise<DataFrame>;
+  collect(opts?: LazyOptions & AbortOptions): Promise<DataFrame>;
   collectSync(opts?: LazyOptions): DataFrame;
+  /**
+   * Run the query and time each node of the physical plan.
+   *
+   * Resolves to the result and a DataFrame with the `node` name, `start` and `end`
+   * of every executed node, in microseconds since the query started.
+   * @param opts - optimization options, see {@link LazyDataFrame.collect}
+   * @example
+   * ```
+   * > const [df, timings] = await lf.profile();
+   * > timings.withColumns(pl.col("end").minus(pl.col("start")).alias("took"))
+   * >   .sort("took", true);
+   * ```
+   */
+  profile(opts?: LazyOptions): Promise<[DataFrame, DataFrame]>;
+  /** Behaves the same as profile, but will perform the actions synchronously */
+  profileSync(opts?: LazyOptions): [DataFrame, DataFrame];
+  /**
+   * Run the query on the streaming engine and yield the result as a sequence of DataFrames.
+   *
+   * Batches are produced lazily: the query pauses once `bufferSize` batches are waiting to be consumed,
+   * so the whole result never has to fit in memory. Breaking out of the loop stops the query.
+   * Note: batches are yielded in the order the engine produces them, which is not guaranteed to be row order.
+   * @param opts.bufferSize - Number of batches that may be queued ahead of the consumer. Default - 1
+   * @example
+   * ```
+   * > const lf = pl.scanCsv("/path/to/my_larger_than_ram_file.csv");
+   * > for await (const batch of lf.collectBatches()) {
+   * >   console.log(batch.height);
+   * > }
+   * ```
+   */
+  collectBatches(opts?: {
+    bufferSize?: number;
+  }): AsyncIterableIterator<DataFrame>;
   /**
    * A string representation of the optimized query plan.
    */
@@ -114,9 +184,13 @@ export interface LazyDataFrame extends Serialize, GroupByOps<LazyGroupBy> {
    * @param opts.projectionPushdown - Do projection pushdown optimization.
    * @param opts.simplifyExpression - Run simplify expressions optimization.
    * @param opts.stringCache - Use a global string cache in this query.
+   * @param opts.streaming - Process the query in batches to handle larger-than-memory data.
+   * @param opts.signal - An `AbortSignal` that interrupts the query.
+   *     Unless `streaming` is set, it is only checked before the query starts
+   *     and once it has finished.
    */
   fetch(numRows?: number): Promise<DataFrame>;
-  fetch(numRows: number, opts: LazyOptions): Promise<DataFrame>;
+  fetch(numRows: number, opts: LazyOptions & AbortOptions): Promise<DataFrame>;
   /** Behaves the same as fetch, but will perform the actions synchronously */
   fetchSync(numRows?: number): DataFrame;
   fetchSync(numRows: number, opts: LazyOptions): DataFrame;
@@ -179,6 +253,10 @@ export interface LazyDataFrame extends Serialize, GroupByOps<LazyGroupBy> {
    * @param options.suffix - Suffix to append to columns with a duplicate name.
    * @param options.allowParallel - Allow the physical plan to optionally evaluate the computation of both DataFrames up to the join in parallel.
    * @param options.forceParallel - Force the physical plan to evaluate the computation of both DataFrames up to the join in parallel.
+   * @param options.validate - Check that the join keys are unique on the given sides: `"m:m"` (default), `"m:1"`, `"1:m"` or `"1:1"`.
+   *     The query fails with a `ComputeError` when they are not.
+   * @param options.joinNulls - Join on null values. By default null values never produce matches.
+   * @param options.coalesce - Merge the key columns of both sides into one. By default only inner and left joins do.
    * @see {@link LazyJoinOptions}
    * @example
    * ```
@@ -240,6 +318,9 @@ export interface LazyDataFrame extends Serialize, GroupByOps<LazyGroupBy> {
         - A "forward" search selects the first row in the right DataFrame whose
           'on' key is greater than or equal to the left's key.
 
+        - A "nearest" search selects the row in the right DataFrame whose
+          'on' key is nearest to the left's key.
+
       The default is "backward".
 
       Parameters
@@ -250,7 +331,7 @@ export interface LazyDataFrame extends Serialize, GroupByOps<LazyGroupBy> {
       @param options.on Join column of both DataFrames. If set, `leftOn` and `rightOn` should be undefined.
       @param options.byLeft join on these columns before doing asof join
       @param options.byRight join on these columns before doing asof join
-      @param options.strategy One of {'forward', 'backward'}
+      @param options.strategy One of {'forward', 'backward', 'nearest'}
       @param options.suffix Suffix to append to columns with a duplicate name.
       @param options.tolerance
         Numeric tolerance. By setting this the join will only be done if the near keys are within this distance.
@@ -324,7 +405,7 @@ export interface LazyDataFrame extends Serialize, GroupByOps<LazyGroupBy> {
       byLeft?: string | string[];
       byRight?: string | string[];
       by?: string | string[];
-      strategy?: "backward" | "forward";
+      strategy?: "backward" | "forward" | "nearest";
       suffix?: string;
       tolerance?: number | string;
       allowParallel?: boolean;
@@ -523,6 +604,14 @@ export interface LazyDataFrame extends Serialize, GroupByOps<LazyGroupBy> {
   */
 
   sinkCSV(path: string, options?: SinkCsvOptions): void;
+  /**
+   * Behaves the same as sinkCSV, but writes on a worker thread and returns a Promise.
+   * Pass `options.signal` to interrupt the write between two batches.
+   */
+  sinkCSVAsync(
+    path: string,
+    options?: SinkCsvOptions & AbortOptions,
+  ): Promise<void>;
 
   /***
    *
@@ -568,6 +657,78 @@ export interface LazyDataFrame extends Serialize, GroupByOps<LazyGroupBy> {
     >>> lf.sinkParquet("out.parquet")  # doctest: +SKIP
    */
   sinkParquet(path: string, options?: SinkParquetOptions): void;
+  /**
+   * Behaves the same as sinkParquet, but writes on a worker thread and returns a Promise.
+   * Pass `options.signal` to interrupt the write between two batches.
+   */
+  sinkParquetAsync(
+    path: string,
+    options?: SinkParquetOptions & AbortOptions,
+  ): Promise<void>;
+
+  /***
+   *
+   * Evaluate the query in streaming mode and write to an Arrow IPC (Feather v2) file.
+
+    .. warning::
+        Streaming mode is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    This allows streaming results that are larger than RAM to be written to disk.
+
+    Parameters
+    ----------
+    @param path - File path to which the file should be written.
+    @param compression : {'uncompressed', 'lz4', 'zstd'}
+        Compression method. Default -> 'uncompressed'
+    @param maintainOrder - Maintain the order in which data is processed. Default -> true
+        Setting this to `False` will  be slightly faster.
+
+    Examples
+    --------
+    >>> const lf = pl.scanCsv("/path/to/my_larger_than_ram_file.csv")
+    >>> lf.sinkIPC("out.arrow", { compression: "zstd" })
+   */
+  sinkIPC(path: string, options?: SinkIpcOptions): void;
+  /**
+   * Behaves the same as sinkIPC, but writes on a worker thread and returns a Promise.
+   * Pass `options.signal` to interrupt the write between two batches.
+   */
+  sinkIPCAsync(
+    path: string,
+    options?: SinkIpcOptions & AbortOptions,
+  ): Promise<void>;
+
+  /***
+   *
+   * Evaluate the query in streaming mode and write to a newline delimited JSON file.
+
+    .. warning::
+        Streaming mode is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    This allows streaming results that are larger than RAM to be written to disk.
+
+    Parameters
+    ----------
+    @param path - File path to which the file should be written.
+    @param maintainOrder - Maintain the order in which data is processed. Default -> true
+        Setting this to `False` will  be slightly faster.
+
+    Examples
+    --------
+    >>> const lf = pl.scanCsv("/path/to/my_larger_than_ram_file.csv")
+    >>> lf.sinkNdJson("out.ndjson")
+   */
+  sinkNdJson(path: string, options?: SinkJsonOptions): void;
+  /**
+   * Behaves the same as sinkNdJson, but writes on a worker thread and returns a Promise.
+   * Pass `options.signal` to interrupt the write between two batches.
+   */
+  sinkNdJsonAsync(
+    path: string,
+    options?: SinkJsonOptions & AbortOptions,
+  ): Promise<void>;
 }
 
 const prepareGroupbyInputs = (by) => {
@@ -592,6 +753,57 @@ const prepareGroupbyInputs = (by) => {
 };
 
 /** @ignore */
+const withLazyOptions = (_ldf: any, opts?: LazyOptions) => {
+  if (!opts) {
+    return _ldf;
+  }
+  const enabled = !opts.noOptimization;
+
+  return _ldf.optimizationToggle(
+    opts.typeCoercion,
+    enabled && opts.predicatePushdown,
+    enabled && opts.projectionPushdown,
+    opts.simplifyExpression,
+    opts.stringCache,
+    enabled && opts.slicePushdown,
+    enabled && opts.commSubplanElim,
+    enabled && opts.commSubexprElim,
+    opts.streaming,
+  );
+};
+
+/**
+ * Run a native query with a cancel token that is tripped by `signal`.
+ * A query that stopped because of the signal rejects with `signal.reason`.
+ * @ignore
+ */
+const withAbortSignal = <T>(
+  signal: AbortSignal | undefined,
+  run: (token?: any) => Promise<T>,
+): Promise<T> => {
+  if (!signal) {
+    return run();
+  }
+  if (signal.aborted) {
+    return Promise.reject(signal.reason);
+  }
+  const token = new pli.JsCancelToken();
+  const onAbort = () => token.cancel();
+  signal.addEventListener("abort", onAbort, { once: true });
+
+  return run(token).then(
+    (result) => {
+      signal.removeEventListener("abort", onAbort);
+
+      return result;
+    },
+    (err) => {
+      signal.removeEventListener("abort", onAbort);
+      throw token.cancelled ? signal.reason : err;
+    },
+  );
+};
+
 export const _LazyDataFrame = (_ldf: any): LazyDataFrame => {
   const unwrap = (method: string, ...args: any[]) => {
     return _ldf[method as any](...args);
@@ -612,11 +824,25 @@ export const _LazyDataFrame = (_ldf: any): LazyDataFrame => {
     get columns() {
       return _ldf.columns;
     },
+    get schema() {
+      const schema = _ldf.schema;
+      for (const [name, dtype] of Object.entries(schema)) {
+        schema[name] = DataType.deserialize(dtype);
+      }
+
+      return schema;
+    },
+    get dtypes() {
+      return Object.values(this.schema);
+    },
+    get width() {
+      return _ldf.width;
+    },
     describePlan() {
       return _ldf.describePlan();
     },
-    describeOptimizedPlan() {
-      return _ldf.describeOptimizedPlan();
+    describeOptimizedPlan(opts?) {
+      return withLazyOptions(_ldf, opts).describeOptimizedPlan();
     },
     cache() {
       return _LazyDataFrame(_ldf.cache());
@@ -624,11 +850,39 @@ export const _LazyDataFrame = (_ldf: any): LazyDataFrame => {
     clone() {
       return _LazyDataFrame((_ldf as any).clone());
     },
-    collectSync() {
-      return _DataFrame(_ldf.collectSync());
+    collectSync(opts?) {
+      return _DataFrame(withLazyOptions(_ldf, opts).collectSync());
+    },
+    collect(opts?) {
+      const ldf = withLazyOptions(_ldf, opts);
+
+      return withAbortSignal(opts?.signal, (token) =>
+        ldf.collect(token),
+      ).then(_DataFrame);
+    },
+    profileSync(opts?) {
+      const [df, timings] = withLazyOptions(_ldf, opts).profileSync();
+
+      return [_DataFrame(df), _DataFrame(timings)];
+    },
+    profile(opts?) {
+      return withLazyOptions(_ldf, opts)
+        .profile()
+        .then(([df, timings]) => [_DataFrame(df), _DataFrame(timings)]);
     },
-    collect() {
-      return _ldf.collect().then(_DataFrame);
+    async *collectBatches(opts?) {
+      const batches = _ldf.collectBatches(opts?.bufferSize);
+      try {
+        while (true) {
+          const batch = await batches.next();
+          if (batch === null) {
+            return;
+          }
+          yield _DataFrame(batch);
+        }
+      } finally {
+        batches.close();
+      }
     },
     drop(...cols) {
       return _LazyDataFrame(_ldf.dropColumns(cols.flat(2)));
@@ -674,40 +928,14 @@ export const _LazyDataFrame = (_ldf: any): LazyDataFrame => {
       return wrap("explode", column);
     },
     fetchSync(numRows, opts?) {
-      if (opts?.noOptimization) {
-        opts.predicatePushdown = false;
-        opts.projectionPushdown = false;
-      }
-      if (opts) {
-        _ldf = _ldf.optimizationToggle(
-          opts.typeCoercion,
-          opts.predicatePushdown,
-          opts.projectionPushdown,
-          opts.simplifyExpr,
-          opts.stringCache,
-          opts.slicePushdown,
-        );
-      }
-
-      return _DataFrame(_ldf.fetchSync(numRows));
+      return _DataFrame(withLazyOptions(_ldf, opts).fetchSync(numRows));
     },
     fetch(numRows, opts?) {
-      if (opts?.noOptimization) {
-        opts.predicatePushdown = false;
-        opts.projectionPushdown = false;
-      }
-      if (opts) {
-        _ldf = _ldf.optimizationToggle(
-          opts.typeCoercion,
-          opts.predicatePushdown,
-          opts.projectionPushdown,
-          opts.simplifyExpr,
-          opts.stringCache,
-          opts.slicePushdown,
-        );
-      }
+      const ldf = withLazyOptions(_ldf, opts);
 
-      return _ldf.fetch(numRows).then(_DataFrame);
+      return withAbortSignal(opts?.signal, (token) =>
+        ldf.fetch(numRows, token),
+      ).then(_DataFrame);
     },
     first() {
       return this.fetchSync(1);
@@ -795,7 +1023,15 @@ export const _LazyDataFrame = (_ldf: any): LazyDataFrame => {
         forceParallel: false,
         ...options,
       };
-      const { how, suffix, allowParallel, forceParallel } = options;
+      const {
+        how,
+        suffix,
+        allowParallel,
+        forceParallel,
+        validate,
+        joinNulls,
+        coalesce,
+      } = options;
       if (how === "cross") {
         return _LazyDataFrame(
           _ldf.join(
@@ -806,8 +1042,6 @@ export const _LazyDataFrame = (_ldf: any): LazyDataFrame => {
             forceParallel,
             how,
             suffix,
-            [],
-            [],
           ),
         );
       }
@@ -829,7 +1063,7 @@ export const _LazyDataFrame = (_ldf: any): LazyDataFrame => {
         rightOn = selectionToExprList(options.rightOn, false);
       }
 
-      const ldf = (_ldf.join as any)(
+      const ldf = _ldf.join(
         df._ldf,
         leftOn,
         rightOn,
@@ -837,8 +1071,9 @@ export const _LazyDataFrame = (_ldf: any): LazyDataFrame => {
         forceParallel,
         how,
         suffix,
-        [],
-        [],
+        validate,
+        joinNulls,
+        coalesce,
       );
 
       return _LazyDataFrame(ldf);
@@ -1023,10 +1258,50 @@ export const _LazyDataFrame = (_ldf: any): LazyDataFrame => {
       options.maintainOrder = options.maintainOrder ?? false;
       _ldf.sinkCsv(path, options);
     },
+    sinkCSVAsync(
+      path,
+      { signal, ...options }: SinkCsvOptions & AbortOptions = {},
+    ) {
+      options.maintainOrder = options.maintainOrder ?? false;
+      return withAbortSignal(signal, (token) =>
+        _ldf.sinkCsvAsync(path, options, token),
+      );
+    },
     sinkParquet(path: string, options: SinkParquetOptions = {}) {
       options.compression = options.compression ?? "zstd";
       _ldf.sinkParquet(path, options);
     },
+    sinkParquetAsync(
+      path: string,
+      { signal, ...options }: SinkParquetOptions & AbortOptions = {},
+    ) {
+      options.compression = options.compression ?? "zstd";
+      return withAbortSignal(signal, (token) =>
+        _ldf.sinkParquetAsync(path, options, token),
+      );
+    },
+    sinkIPC(path: string, options: SinkIpcOptions = {}) {
+      _ldf.sinkIpc(path, options);
+    },
+    sinkIPCAsync(
+      path: string,
+      { signal, ...options }: SinkIpcOptions & AbortOptions = {},
+    ) {
+      return withAbortSignal(signal, (token) =>
+        _ldf.sinkIpcAsync(path, options, token),
+      );
+    },
+    sinkNdJson(path: string, options: SinkJsonOptions = {}) {
+      _ldf.sinkNdjson(path, options);
+    },
+    sinkNdJsonAsync(
+      path: string,
+      { signal, ...options }: SinkJsonOptions & AbortOptions = {},
+    ) {
+      return withAbortSignal(signal, (token) =>
+        _ldf.sinkNdjsonAsync(path, options, token),
+      );
+    },
   };
 };
 
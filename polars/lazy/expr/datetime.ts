@@ -1,5 +1,5 @@
 import type { DateFunctions } from "../../shared_traits";
-import { type Expr, _Expr } from "../expr";
+import { type Expr, _Expr, exprToLitOrExpr } from "../expr";
 
 /**
  * DateTime functions
@@ -26,5 +26,13 @@ export const ExprDateTimeFunctions = (_expr: any): ExprDateTime => {
     week: wrapNullArgs("week"),
     weekday: wrapNullArgs("weekday"),
     year: wrapNullArgs("year"),
+    convertTimeZone: (timeZone) => wrap("convertTimeZone", timeZone),
+    replaceTimeZone: (timeZone, options = {}) =>
+      wrap(
+        "replaceTimeZone",
+        timeZone ?? null,
+        exprToLitOrExpr(options.ambiguous ?? "raise"),
+        options.nonExistent ?? "raise",
+      ),
   };
 };
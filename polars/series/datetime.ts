@@ -1,4 +1,5 @@
 import { type Series, _Series } from ".";
+import { col } from "../lazy/functions";
 import type { DateFunctions } from "../shared_traits";
 
 export type SeriesDateFunctions = DateFunctions<Series>;
@@ -10,6 +11,19 @@ export const SeriesDateFunctions = (_s): SeriesDateFunctions => {
 
   const wrapNullArgs = (method: string) => () => wrap(method);
 
+  const wrapExpr = (method, ...args: any[]): Series => {
+    const s = _Series(_s);
+
+    return s
+      .toFrame()
+      .select(
+        col(s.name)
+          .date[method](...args)
+          .as(s.name),
+      )
+      .getColumn(s.name);
+  };
+
   return {
     day: wrapNullArgs("day"),
     hour: wrapNullArgs("hour"),
@@ -23,5 +37,8 @@ export const SeriesDateFunctions = (_s): SeriesDateFunctions => {
     week: wrapNullArgs("week"),
     weekday: wrapNullArgs("weekday"),
     year: wrapNullArgs("year"),
+    convertTimeZone: (timeZone) => wrapExpr("convertTimeZone", timeZone),
+    replaceTimeZone: (timeZone, options?) =>
+      wrapExpr("replaceTimeZone", timeZone, options),
   };
 };
@@ -19,7 +19,11 @@ import type {
   EwmOps,
 } from "../shared_traits";
 import { col } from "../lazy/functions";
-import type { InterpolationMethod, RankMethod } from "../types";
+import type {
+  ArrowVector,
+  InterpolationMethod,
+  RankMethod,
+} from "../types";
 
 const inspect = Symbol.for("nodejs.util.inspect.custom");
 /**
@@ -1041,10 +1045,48 @@ export interface Series
   /**
    * Converts series to a javascript typedArray.
    *
+   * The values are copied, so the typed array can be modified without affecting the series.
+   * See {@link toTypedArrayWithValidity} to avoid the copy.
+   *
    * __Warning:__
    * This will throw an error if you have nulls, or are using non numeric data types
    */
   toTypedArray(): any;
+  /**
+   * Converts series to a javascript typedArray, along with its validity.
+   *
+   * Temporal series are converted to their physical representation, e.g. `Datetime` to a `BigInt64Array`.
+   * Values at null positions are unspecified, `validity` is an arrow style bitmap
+   * where bit `i % 8` of byte `i / 8` is set when the value at `i` is not null.
+   * It is omitted when the series has no nulls.
+   *
+   * If the series is a single chunk, `values` and `validity` are views over the memory of the series, which is
+   * shared with its clones. They must not be modified, as that would silently change every one of those series.
+   * Memory that is already viewed by an earlier call is copied, as is all of it on runtimes that do not allow
+   * external memory, such as Electron.
+   * @example
+   * ```
+   * > const { values, validity } = pl.Series([1, null, 3], pl.Int32).toTypedArrayWithValidity();
+   * > values
+   * Int32Array(3) [ 1, 0, 3 ]
+   * > validity
+   * Uint8Array(1) [ 5 ]
+   * ```
+   */
+  toTypedArrayWithValidity(): {
+    values:
+      | Int8Array
+      | Int16Array
+      | Int32Array
+      | BigInt64Array
+      | Uint8Array
+      | Uint16Array
+      | Uint32Array
+      | BigUint64Array
+      | Float32Array
+      | Float64Array;
+    validity?: Uint8Array;
+  };
 
   /**
    * Get dummy/indicator variables.
@@ -1096,6 +1138,22 @@ export interface Series
    * ```
    */
   toObject(): { name: string; datatype: string; values: any[] };
+  /**
+   * Converts the series into an apache-arrow `Vector`-like object, with a `Data` per chunk.
+   *
+   * Primitive buffers are shared with the series rather than copied.
+   * As with {@link DataFrame.toArrow}, the types are plain objects that have to be rebuilt as
+   * apache-arrow `DataType`s before the chunks are passed to `makeData`.
+   * @example
+   * ```
+   * > const { type, data } = pl.Series("a", [1, 2, 3], pl.Int32).toArrow();
+   * > type
+   * { typeId: 2, bitWidth: 32, isSigned: true }
+   * > data[0].values
+   * Int32Array(3) [ 1, 2, 3 ]
+   * ```
+   */
+  toArrow(): ArrowVector & { name: string; nullable: boolean };
   toFrame(): DataFrame;
   /** compat with `JSON.stringify */
   toJSON(): string;
@@ -1755,6 +1813,10 @@ export function _Series(_s: any): Series {
       }
       throw new Error("data contains nulls, unable to convert to TypedArray");
     },
+    toTypedArrayWithValidity() {
+      const { values, validity } = _s.toTypedArrayWithValidity();
+      return validity ? { values, validity } : { values };
+    },
     toDummies(separator = "_", dropFirst = false) {
       return _DataFrame(_s.toDummies(separator, dropFirst));
     },
@@ -1774,6 +1836,9 @@ export function _Series(_s: any): Series {
     toObject() {
       return _s.toJs();
     },
+    toArrow() {
+      return _s.toArrow();
+    },
     unique(maintainOrder?) {
       if (maintainOrder) {
         return wrap("uniqueStable");
@@ -1859,6 +1924,16 @@ export interface SeriesConstructor extends Deserialize<Series> {
    */
   of<T3>(...items: T3[]): Series;
   isSeries(arg: any): arg is Series;
+  /**
+   * Creates a Series from an apache-arrow `Vector`, or an object of the same shape.
+   *
+   * The buffers are copied into the Series.
+   * @example
+   * ```
+   * > pl.Series.fromArrow("a", vectorFromArray([1, 2, 3]))
+   * ```
+   */
+  fromArrow(name: string, vector: ArrowVector): Series;
   /**
    * @param binary used to serialize/deserialize series. This will only work with the output from series.toBinary().
    */
@@ -1902,4 +1977,6 @@ export const Series: SeriesConstructor = Object.assign(SeriesConstructor, {
   from,
   of,
   deserialize: (buf, fmt) => _Series(pli.JsSeries.deserialize(buf, fmt)),
+  fromArrow: (name: string, vector: ArrowVector) =>
+    _Series(pli.JsSeries.fromArrow(name, vector)),
 });
@@ -826,6 +826,31 @@ export interface DateFunctions<T> {
    * @returns Year as Int32
    */
   year(): T;
+  /**
+   * Convert a time zone aware Datetime to the given time zone.
+   * The underlying instants are unchanged, only their local representation.
+   * @param timeZone - IANA time zone name, e.g. `Europe/Amsterdam`.
+   * @example
+   * ```
+   * > df.select(pl.col("ts").date.convertTimeZone("Asia/Tokyo"))
+   * ```
+   */
+  convertTimeZone(timeZone: string): T;
+  /**
+   * Replace the time zone of a Datetime, keeping its local wall-clock time.
+   * Pass `null` to make the Datetime time zone naive.
+   * @param timeZone - IANA time zone name, or `null`.
+   * @param options.ambiguous - How to handle ambiguous local times: `'raise'` (default), `'earliest'`, `'latest'` or `'null'`.
+   *   May also be an expression evaluating to one of these.
+   * @param options.nonExistent - How to handle non-existent local times: `'raise'` (default) or `'null'`.
+   */
+  replaceTimeZone(
+    timeZone: string | null,
+    options?: {
+      ambiguous?: "raise" | "earliest" | "latest" | "null" | Expr;
+      nonExistent?: "raise" | "null";
+    },
+  ): T;
 }
 
 export interface StringFunctions<T> {
@@ -92,6 +92,21 @@ export interface SinkParquetOptions {
   slicePushdown?: boolean;
   noOptimization?: boolean;
 }
+/**
+ * Options for @see {@link LazyDataFrame.sinkIPC}
+ * @category Options
+ */
+export interface SinkIpcOptions {
+  compression?: "uncompressed" | "lz4" | "zstd";
+  maintainOrder?: boolean;
+}
+/**
+ * Options for @see {@link LazyDataFrame.sinkNdJson}
+ * @category Options
+ */
+export interface SinkJsonOptions {
+  maintainOrder?: boolean;
+}
 /**
  * Options for {@link DataFrame.writeJSON}
  * @category Options
@@ -190,6 +205,12 @@ export type InterpolationMethod =
  */
 export type JoinType = "left" | "inner" | "outer" | "semi" | "anti" | "cross";
 
+/**
+ * Expected cardinality of the join keys:
+ * many-to-many (no check), many-to-one, one-to-many or one-to-one.
+ */
+export type JoinValidation = "m:m" | "m:1" | "1:m" | "1:1";
+
 /** @ignore */
 export type JoinBaseOptions = {
   how?: JoinType;
@@ -216,6 +237,15 @@ export interface JoinOptions {
 export interface LazyJoinOptions extends JoinOptions {
   allowParallel?: boolean;
   forceParallel?: boolean;
+  /** Fail the query if the join keys do not have this cardinality */
+  validate?: JoinValidation;
+  /** Match null keys with each other */
+  joinNulls?: boolean;
+  /**
+   * Merge the key columns of both sides into one.
+   * By default only inner and left joins do.
+   */
+  coalesce?: boolean;
 }
 
 /**
@@ -227,9 +257,24 @@ export type LazyOptions = {
   projectionPushdown?: boolean;
   simplifyExpression?: boolean;
   stringCache?: boolean;
+  slicePushdown?: boolean;
+  commSubplanElim?: boolean;
+  commSubexprElim?: boolean;
+  streaming?: boolean;
   noOptimization?: boolean;
 };
 
+/**
+ * options for interrupting a running query @see {@link LazyDataFrame.collect}
+ */
+export interface AbortOptions {
+  /**
+   * Stop the query at its next safe point once the signal aborts.
+   * The Promise then rejects with `signal.reason`.
+   */
+  signal?: AbortSignal;
+}
+
 /**
  * options for rolling window operations
  * @category Options
@@ -264,3 +309,71 @@ export interface RollingSkewOptions {
  * ClosedWindow types
  */
 export type ClosedWindow = "None" | "Both" | "Left" | "Right";
+
+/**
+ * An arrow data type, in the shape of apache-arrow's `DataType`.
+ * `typeId` is a value of apache-arrow's `Type` enum.
+ * Being a plain object, it has to be rebuilt as an apache-arrow `DataType` before it is passed to apache-arrow.
+ */
+export interface ArrowType {
+  typeId: number;
+  bitWidth?: number;
+  isSigned?: boolean;
+  precision?: number;
+  scale?: number;
+  unit?: number;
+  timezone?: string | null;
+  listSize?: number;
+  children?: ArrowField[];
+}
+
+/**
+ * An arrow field, in the shape of apache-arrow's `Field`
+ */
+export interface ArrowField {
+  name: string;
+  type: ArrowType;
+  nullable: boolean;
+}
+
+/**
+ * A contiguous arrow array, in the shape of apache-arrow's `Data`.
+ * Its `type` is a plain object rather than an apache-arrow `DataType`, see {@link ArrowType}.
+ */
+export interface ArrowData {
+  type: ArrowType;
+  length: number;
+  offset: number;
+  nullCount: number;
+  nullBitmap?: Uint8Array;
+  valueOffsets?: Int32Array | BigInt64Array;
+  values?: ArrayBufferView;
+  children: ArrowData[];
+}
+
+/**
+ * A chunked arrow array, in the shape of apache-arrow's `Vector`
+ * @see {@link Series.toArrow}
+ */
+export interface ArrowVector {
+  type: ArrowType;
+  data: ArrowData[];
+}
+
+/**
+ * A record batch, in the shape of apache-arrow's `RecordBatch`
+ */
+export interface ArrowRecordBatch {
+  schema?: { fields: ArrowField[] };
+  length?: number;
+  data: ArrowData;
+}
+
+/**
+ * A table of record batches, in the shape of apache-arrow's `Table`
+ * @see {@link DataFrame.toArrow}
+ */
+export interface ArrowTable {
+  schema: { fields: ArrowField[] };
+  batches: ArrowRecordBatch[];
+}
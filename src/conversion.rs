@@ -1,13 +1,20 @@
 use crate::lazy::dsl::JsExpr;
+use crate::object::{object_value, register_object_type, ObjectValue, OBJECT_NAME};
 use crate::prelude::*;
 use napi::bindgen_prelude::*;
-use napi::{JsBigInt, JsBoolean, JsDate, JsNumber, JsObject, JsString, JsUnknown};
+use napi::{
+    JsBigInt, JsBoolean, JsBuffer, JsDate, JsNumber, JsObject, JsString, JsTypedArray, JsUnknown,
+    TypedArrayType,
+};
+use polars::export::arrow::array::Utf8ViewArray;
 use polars::frame::NullStrategy;
 use polars::prelude::*;
 use polars_core::series::ops::NullBehavior;
 use polars_io::RowIndex;
 use std::any::Any;
-use std::collections::HashMap;
+use std::borrow::Borrow;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
 
 use smartstring::alias::String as SmartString;
 
@@ -36,38 +43,6 @@ impl TypeName for Wrap<StringChunked> {
         ValueType::Object
     }
 }
-/// Safety.
-/// it is up to the consumer to make sure the item is valid
-pub(crate) trait ToSeries {
-    unsafe fn to_series(&self) -> Series;
-}
-
-impl ToSeries for Array {
-    unsafe fn to_series(&self) -> Series {
-        let len = self.len();
-        let mut v: Vec<AnyValue> = Vec::with_capacity(len as usize);
-        for i in 0..len {
-            let av: Wrap<AnyValue> = self.get(i).unwrap().unwrap_or(Wrap(AnyValue::Null));
-            v.push(av.0);
-        }
-        Series::new("", v)
-    }
-}
-
-impl ToSeries for JsUnknown {
-    unsafe fn to_series(&self) -> Series {
-        let obj = self.cast::<JsObject>();
-        let len = obj.get_array_length_unchecked().unwrap();
-        let mut v: Vec<AnyValue> = Vec::with_capacity(len as usize);
-        for i in 0..len {
-            let unknown: JsUnknown = obj.get_element_unchecked(i).unwrap();
-            let av = AnyValue::from_js(unknown).unwrap();
-            v.push(av);
-        }
-        Series::new("", v)
-    }
-}
-
 impl ToNapiValue for Wrap<&Series> {
     unsafe fn to_napi_value(napi_env: sys::napi_env, val: Self) -> napi::Result<sys::napi_value> {
         let s = val.0;
@@ -77,7 +52,7 @@ impl ToNapiValue for Wrap<&Series> {
 
         match dtype {
             DataType::Struct(_) => {
-                let ca = s.struct_().map_err(JsPolarsErr::from)?;
+                let ca = s.struct_().map_err(|e| JsPolarsErr::from(e).into_napi())?;
                 let df: DataFrame = ca.clone().into();
                 let (height, _) = df.shape();
                 let mut rows = env.create_array(height as u32)?;
@@ -92,6 +67,14 @@ impl ToNapiValue for Wrap<&Series> {
                 }
                 Array::to_napi_value(napi_env, rows)
             }
+            DataType::Object(..) => {
+                let mut arr = env.create_array(len as u32)?;
+                for idx in 0..len {
+                    let val = s.get(idx).map_err(|e| JsPolarsErr::from(e).into_napi())?;
+                    arr.set(idx as u32, Wrap(val))?;
+                }
+                Array::to_napi_value(napi_env, arr)
+            }
             _ => {
                 let mut arr = env.create_array(len as u32)?;
                 for (idx, val) in s.iter().enumerate() {
@@ -113,6 +96,7 @@ impl<'a> ToNapiValue for Wrap<AnyValue<'a>> {
             AnyValue::Int8(n) => i32::to_napi_value(env, n as i32),
             AnyValue::Int16(n) => i32::to_napi_value(env, n as i32),
             AnyValue::Int32(n) => i32::to_napi_value(env, n),
+            AnyValue::Int64(n) if crate::int64_as_bigint() => i64n::to_napi_value(env, i64n(n)),
             AnyValue::Int64(n) => i64::to_napi_value(env, n),
             AnyValue::UInt8(n) => u32::to_napi_value(env, n as u32),
             AnyValue::UInt16(n) => u32::to_napi_value(env, n as u32),
@@ -135,13 +119,16 @@ impl<'a> ToNapiValue for Wrap<AnyValue<'a>> {
 
                 Ok(ptr)
             }
-            AnyValue::Datetime(v, _, _) => {
+            AnyValue::Datetime(v, tu, _) => {
                 let mut js_value = std::ptr::null_mut();
-                napi::sys::napi_create_date(env, v as f64, &mut js_value);
+                check_status!(
+                    napi::sys::napi_create_date(env, datetime_to_epoch_ms(v, tu), &mut js_value),
+                    "Failed to convert rust type `AnyValue::Datetime` into napi value",
+                )?;
 
                 Ok(js_value)
             }
-            AnyValue::Categorical(idx, rev, arr) => {
+            AnyValue::Categorical(idx, rev, arr) | AnyValue::Enum(idx, rev, arr) => {
                 let s = if arr.is_null() {
                     rev.get(idx)
                 } else {
@@ -150,20 +137,27 @@ impl<'a> ToNapiValue for Wrap<AnyValue<'a>> {
                 let ptr = String::to_napi_value(env, s.to_string());
                 Ok(ptr.unwrap())
             }
-            AnyValue::Duration(v, _) => i64::to_napi_value(env, v),
-            AnyValue::Time(v) => i64::to_napi_value(env, v),
+            AnyValue::Duration(v, tu) => {
+                i128::to_napi_value(env, v as i128 * nanoseconds_per_unit(tu) as i128)
+            }
+            AnyValue::Time(v) => i128::to_napi_value(env, v as i128),
             AnyValue::List(ser) => Wrap::<&Series>::to_napi_value(env, Wrap(&ser)),
             ref av @ AnyValue::Struct(_, _, flds) => struct_dict(env, av._iter_struct_av(), flds),
             AnyValue::Array(ser, _) => Wrap::<&Series>::to_napi_value(env, Wrap(&ser)),
-            AnyValue::Enum(_, _, _) => todo!(),
-            AnyValue::Object(_) => todo!(),
-            AnyValue::ObjectOwned(_) => todo!(),
-            AnyValue::StructOwned(_) => todo!(),
-            AnyValue::Binary(_) => todo!(),
-            AnyValue::BinaryOwned(_) => todo!(),
-            AnyValue::Decimal(_, _) => {
-                Err(napi::Error::from_reason("Decimal is not a supported type in javascript, please convert to string or number before collecting to js"))
-            }
+            ref av @ (AnyValue::Object(_) | AnyValue::ObjectOwned(_)) => match object_value(av) {
+                Some(v) => <&ObjectValue>::to_napi_value(env, v),
+                None => Err(JsPolarsErr::Other(
+                    "Object values cannot be converted to JS".to_owned(),
+                )
+                .into_napi()),
+            },
+            AnyValue::StructOwned(_) => Err(JsPolarsErr::Other(
+                "owned Struct values cannot be converted to JS".to_owned(),
+            )
+            .into_napi()),
+            AnyValue::Binary(b) => Buffer::to_napi_value(env, Buffer::from(b)),
+            AnyValue::BinaryOwned(b) => Buffer::to_napi_value(env, Buffer::from(b)),
+            AnyValue::Decimal(v, scale) => String::to_napi_value(env, decimal_to_string(v, scale)),
         }
     }
 }
@@ -185,6 +179,24 @@ impl FromNapiValue for Wrap<StringChunked> {
         Ok(Wrap(builder.finish()))
     }
 }
+impl FromNapiValue for Wrap<BinaryChunked> {
+    unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> JsResult<Self> {
+        let arr = Array::from_napi_value(env, napi_val)?;
+        let len = arr.len() as usize;
+        let mut builder = BinaryChunkedBuilder::new("", len);
+        for i in 0..len {
+            match arr.get::<JsUnknown>(i as u32)? {
+                Some(val) => match bytes_from_js(&val)? {
+                    Some(bytes) => builder.append_value(bytes),
+                    None => builder.append_null(),
+                },
+                None => builder.append_null(),
+            }
+        }
+
+        Ok(Wrap(builder.finish()))
+    }
+}
 impl FromNapiValue for Wrap<BooleanChunked> {
     unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> JsResult<Self> {
         let arr = Array::from_napi_value(env, napi_val)?;
@@ -249,7 +261,24 @@ macro_rules! impl_chunked {
 impl_chunked!(Float64Type, f64);
 impl_chunked!(Int32Type, i32);
 impl_chunked!(UInt32Type, u32);
-impl_chunked!(Int64Type, i64);
+
+impl FromNapiValue for Wrap<ChunkedArray<Int64Type>> {
+    unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> JsResult<Self> {
+        let arr = Array::from_napi_value(env, napi_val)?;
+        let len = arr.len() as usize;
+        let mut builder = PrimitiveChunkedBuilder::<Int64Type>::new("", len);
+        for i in 0..len {
+            match arr.get::<JsUnknown>(i as u32)? {
+                Some(v) if v.get_type()? == ValueType::BigInt => {
+                    builder.append_value(i64::from_js(v)?)
+                }
+                Some(v) => builder.append_option(i64::from_js(v).ok()),
+                None => builder.append_null(),
+            }
+        }
+        Ok(Wrap(builder.finish()))
+    }
+}
 
 impl FromNapiValue for Wrap<ChunkedArray<UInt64Type>> {
     unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> JsResult<Self> {
@@ -259,10 +288,15 @@ impl FromNapiValue for Wrap<ChunkedArray<UInt64Type>> {
         for i in 0..len {
             match arr.get::<BigInt>(i as u32) {
                 Ok(val) => match val {
-                    Some(v) => {
-                        let (_, v, _) = v.get_u64();
-                        builder.append_value(v)
-                    }
+                    Some(v) => match v.get_u64() {
+                        (_, v, true) => builder.append_value(v),
+                        _ => {
+                            return Err(JsPolarsErr::Other(
+                                "BigInt is out of range for UInt64".to_owned(),
+                            )
+                            .into_napi())
+                        }
+                    },
                     None => builder.append_null(),
                 },
                 Err(_) => builder.append_null(),
@@ -277,7 +311,8 @@ impl FromNapiValue for Wrap<Expr> {
         let obj = Object::from_napi_value(env, napi_val)?;
         let expr: &JsExpr = obj
             .get("_expr")?
-            .expect(&format!("field {} should exist", "_expr"));
+            .ok_or_else(|| JsPolarsErr::Other("expected an Expr".to_owned()))
+            .map_err(JsPolarsErr::into_napi)?;
         Ok(Wrap(expr.inner.clone()))
     }
 }
@@ -287,7 +322,8 @@ impl FromNapiValue for Wrap<JsExpr> {
         let obj = Object::from_napi_value(env, napi_val)?;
         let expr: &JsExpr = obj
             .get("_expr")?
-            .expect(&format!("field {} should exist", "_expr"));
+            .ok_or_else(|| JsPolarsErr::Other("expected an Expr".to_owned()))
+            .map_err(JsPolarsErr::into_napi)?;
         Ok(Wrap(expr.clone()))
     }
 }
@@ -311,12 +347,39 @@ impl FromNapiValue for Wrap<QuantileInterpolOptions> {
             "higher" => QuantileInterpolOptions::Higher,
             "midpoint" => QuantileInterpolOptions::Midpoint,
             "linear" => QuantileInterpolOptions::Linear,
-            _ => return Err(napi::Error::from_reason("not supported".to_owned())),
+            _ => return Err(JsPolarsErr::Other("not supported".to_owned()).into_napi()),
         };
         Ok(Wrap(interpol))
     }
 }
 
+impl FromNapiValue for Wrap<CategoricalOrdering> {
+    unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> JsResult<Self> {
+        let ordering = String::from_napi_value(env, napi_val)?;
+        let parsed = match ordering.as_ref() {
+            "physical" => CategoricalOrdering::Physical,
+            "lexical" => CategoricalOrdering::Lexical,
+            v => {
+                return Err(JsPolarsErr::Other(format!(
+                    "ordering must be one of {{'physical', 'lexical'}}, got {v}",
+                ))
+                .into_napi())
+            }
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl ToNapiValue for Wrap<CategoricalOrdering> {
+    unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> napi::Result<sys::napi_value> {
+        let ordering = match val.0 {
+            CategoricalOrdering::Physical => "physical",
+            CategoricalOrdering::Lexical => "lexical",
+        };
+        <&str>::to_napi_value(env, ordering)
+    }
+}
+
 impl FromNapiValue for Wrap<StartBy> {
     unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> JsResult<Self> {
         let start = String::from_napi_value(env, napi_val)?;
@@ -325,9 +388,10 @@ impl FromNapiValue for Wrap<StartBy> {
             "datapoint" => StartBy::DataPoint,
             "monday" => StartBy::Monday,
             v => {
-                return Err(napi::Error::from_reason(format!(
+                return Err(JsPolarsErr::Other(format!(
                     "closed must be one of {{'window', 'datapoint', 'monday'}}, got {v}",
-                )))
+                ))
+                .into_napi())
             }
         };
         Ok(Wrap(parsed))
@@ -343,9 +407,10 @@ impl FromNapiValue for Wrap<ClosedWindow> {
             "left" => ClosedWindow::Left,
             "right" => ClosedWindow::Right,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "closed should be any of {'none', 'left', 'right', 'both'}".to_owned(),
-                ))
+                )
+                .into_napi())
             }
         };
         Ok(Wrap(cw))
@@ -363,9 +428,10 @@ impl FromNapiValue for Wrap<RankMethod> {
             "ordinal" => RankMethod::Ordinal,
             "random" => RankMethod::Random,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "use one of {'average', 'min', 'max', 'dense', 'ordinal', 'random'}".to_owned(),
-                ))
+                )
+                .into_napi())
             }
         };
         Ok(Wrap(method))
@@ -407,9 +473,9 @@ impl FromNapiValue for Wrap<UniqueKeepStrategy> {
             "first" => UniqueKeepStrategy::First,
             "last" => UniqueKeepStrategy::Last,
             _ => {
-                return Err(napi::Error::from_reason(
-                    "use one of {'first', 'last'}".to_owned(),
-                ))
+                return Err(
+                    JsPolarsErr::Other("use one of {'first', 'last'}".to_owned()).into_napi(),
+                )
             }
         };
         Ok(Wrap(method))
@@ -423,9 +489,9 @@ impl FromNapiValue for Wrap<NullStrategy> {
             "ignore" => NullStrategy::Ignore,
             "propagate" => NullStrategy::Propagate,
             _ => {
-                return Err(napi::Error::from_reason(
-                    "use one of {'ignore', 'propagate'}".to_owned(),
-                ))
+                return Err(
+                    JsPolarsErr::Other("use one of {'ignore', 'propagate'}".to_owned()).into_napi(),
+                )
             }
         };
         Ok(Wrap(method))
@@ -439,9 +505,9 @@ impl FromNapiValue for Wrap<NullBehavior> {
             "drop" => NullBehavior::Drop,
             "ignore" => NullBehavior::Ignore,
             _ => {
-                return Err(napi::Error::from_reason(
-                    "use one of {'drop', 'ignore'}".to_owned(),
-                ))
+                return Err(
+                    JsPolarsErr::Other("use one of {'drop', 'ignore'}".to_owned()).into_napi(),
+                )
             }
         };
         Ok(Wrap(method))
@@ -459,11 +525,7 @@ impl FromNapiValue for Wrap<FillNullStrategy> {
             "mean" => FillNullStrategy::Mean,
             "zero" => FillNullStrategy::Zero,
             "one" => FillNullStrategy::One,
-            _ => {
-                return Err(napi::Error::from_reason(
-                    "Strategy not supported".to_owned(),
-                ))
-            }
+            _ => return Err(JsPolarsErr::Other("Strategy not supported".to_owned()).into_napi()),
         };
         Ok(Wrap(method))
     }
@@ -499,6 +561,25 @@ impl FromNapiValue for Wrap<f32> {
         Ok(Wrap(n as f32))
     }
 }
+/// Converts a BigInt to an `Int64` value if it is negative, `UInt64` otherwise.
+pub(crate) fn bigint_to_any_value(big: BigInt) -> JsPolarsResult<AnyValue<'static>> {
+    if big.sign_bit {
+        match big.get_i64() {
+            (value, true) => Ok(AnyValue::Int64(value)),
+            _ => Err(JsPolarsErr::Other(
+                "BigInt is out of range for Int64".to_owned(),
+            )),
+        }
+    } else {
+        match big.get_u64() {
+            (_, value, true) => Ok(AnyValue::UInt64(value)),
+            _ => Err(JsPolarsErr::Other(
+                "BigInt is out of range for UInt64".to_owned(),
+            )),
+        }
+    }
+}
+
 impl FromNapiValue for Wrap<u64> {
     unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> JsResult<Self> {
         let big = BigInt::from_napi_value(env, napi_val)?;
@@ -593,6 +674,17 @@ pub struct SinkParquetOptions {
     pub no_optimization: Option<bool>,
 }
 
+#[napi(object)]
+pub struct SinkIpcOptions {
+    pub compression: Option<String>,
+    pub maintain_order: Option<bool>,
+}
+
+#[napi(object)]
+pub struct SinkJsonOptions {
+    pub maintain_order: Option<bool>,
+}
+
 #[napi(object)]
 pub struct Shape {
     pub height: i64,
@@ -616,12 +708,22 @@ impl FromNapiValue for Wrap<TimeUnit> {
             "ns" => TimeUnit::Nanoseconds,
             "us" => TimeUnit::Microseconds,
             "ms" => TimeUnit::Milliseconds,
-            _ => panic!("not a valid timeunit"),
+            v => {
+                return Err(JsPolarsErr::Other(format!(
+                    "time unit must be one of {{'ns', 'us', 'ms'}}, got {v}",
+                ))
+                .into_napi())
+            }
         };
 
         Ok(Wrap(tu))
     }
 }
+/// Unwraps a property of a serialized JS DataType, erroring when it is absent.
+fn required<V>(value: Option<V>, variant: &str, key: &str) -> JsPolarsResult<V> {
+    value.ok_or_else(|| JsPolarsErr::Other(format!("DataType {} is missing '{}'", variant, key)))
+}
+
 impl FromNapiValue for Wrap<DataType> {
     unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> napi::Result<Self> {
         let ty = type_of!(env, napi_val)?;
@@ -648,62 +750,107 @@ impl FromNapiValue for Wrap<DataType> {
                     "Bool" => DataType::Boolean,
                     "Utf8" => DataType::String,
                     "String" => DataType::String,
+                    "Binary" => DataType::Binary,
                     "List" => {
-                        let inner = obj.get::<_, Array>("inner")?.unwrap();
-                        let inner_dtype: Object = inner.get::<Object>(0)?.unwrap();
-                        let napi_dt = Object::to_napi_value(env, inner_dtype).unwrap();
+                        let inner = required(obj.get::<_, Array>("inner")?, &variant, "inner")
+                            .map_err(JsPolarsErr::into_napi)?;
+                        let inner_dtype = required(inner.get::<Object>(0)?, &variant, "inner")
+                            .map_err(JsPolarsErr::into_napi)?;
+                        let napi_dt = Object::to_napi_value(env, inner_dtype)?;
 
                         let dt = Wrap::<DataType>::from_napi_value(env, napi_dt)?;
                         DataType::List(Box::new(dt.0))
                     }
                     "FixedSizeList" => {
-                        let inner = obj.get::<_, Array>("inner")?.unwrap();
-                        let inner_dtype: Object = inner.get::<Object>(0)?.unwrap();
-                        let napi_dt = Object::to_napi_value(env, inner_dtype).unwrap();
+                        let inner = required(obj.get::<_, Array>("inner")?, &variant, "inner")
+                            .map_err(JsPolarsErr::into_napi)?;
+                        let inner_dtype = required(inner.get::<Object>(0)?, &variant, "inner")
+                            .map_err(JsPolarsErr::into_napi)?;
+                        let napi_dt = Object::to_napi_value(env, inner_dtype)?;
 
                         let dt = Wrap::<DataType>::from_napi_value(env, napi_dt)?;
 
-                        let size = inner.get::<i32>(1)?.unwrap();
+                        let size = required(inner.get::<u32>(1)?, &variant, "listSize")
+                            .map_err(JsPolarsErr::into_napi)?;
 
                         DataType::Array(Box::new(dt.0), size as usize)
                     }
 
                     "Date" => DataType::Date,
                     "Datetime" => {
-                        let tu = obj.get::<_, Wrap<TimeUnit>>("timeUnit")?.unwrap();
-                        DataType::Datetime(tu.0, None)
+                        let tu = required(
+                            obj.get::<_, Wrap<TimeUnit>>("timeUnit")?,
+                            &variant,
+                            "timeUnit",
+                        )
+                        .map_err(JsPolarsErr::into_napi)?;
+                        let tz = obj.get::<_, Option<String>>("timeZone")?.flatten();
+                        DataType::Datetime(tu.0, tz)
                     }
                     "Time" => DataType::Time,
-                    "Object" => DataType::Object("object", None),
-                    "Categorical" => DataType::Categorical(None, Default::default()),
+                    "Duration" => {
+                        let tu = required(
+                            obj.get::<_, Wrap<TimeUnit>>("timeUnit")?,
+                            &variant,
+                            "timeUnit",
+                        )
+                        .map_err(JsPolarsErr::into_napi)?;
+                        DataType::Duration(tu.0)
+                    }
+                    "Object" => {
+                        register_object_type();
+                        DataType::Object(OBJECT_NAME, None)
+                    }
+                    "Categorical" => {
+                        let ordering = obj
+                            .get::<_, Wrap<CategoricalOrdering>>("ordering")?
+                            .map(|o| o.0)
+                            .unwrap_or_default();
+                        DataType::Categorical(None, ordering)
+                    }
+                    "Enum" => {
+                        let categories =
+                            obj.get::<_, Vec<String>>("categories")?.unwrap_or_default();
+                        create_enum_data_type(Utf8ViewArray::from_slice_values(&categories))
+                    }
                     "Struct" => {
-                        let inner = obj.get::<_, Array>("fields")?.unwrap();
+                        let inner = required(obj.get::<_, Array>("fields")?, &variant, "fields")
+                            .map_err(JsPolarsErr::into_napi)?;
                         let mut fldvec: Vec<Field> = Vec::with_capacity(inner.len() as usize);
                         for i in 0..inner.len() {
-                            let inner_dtype: Object = inner.get::<Object>(i)?.unwrap();
-                            let napi_dt = Object::to_napi_value(env, inner_dtype).unwrap();
-                            let obj = Object::from_napi_value(env, napi_dt)?;
-                            let name = obj.get::<_, String>("name")?.unwrap();
-                            let dt = obj.get::<_, Wrap<DataType>>("dtype")?.unwrap();
+                            let obj = required(inner.get::<Object>(i)?, &variant, "fields")
+                                .map_err(JsPolarsErr::into_napi)?;
+                            let name = required(obj.get::<_, String>("name")?, &variant, "name")
+                                .map_err(JsPolarsErr::into_napi)?;
+                            let dt =
+                                required(obj.get::<_, Wrap<DataType>>("dtype")?, &variant, "dtype")
+                                    .map_err(JsPolarsErr::into_napi)?;
                             let fld = Field::new(&name, dt.0);
                             fldvec.push(fld);
                         }
                         DataType::Struct(fldvec)
                     }
                     "Decimal" => {
-                        let inner = obj.get::<_, Array>("inner")?.unwrap(); // [precision, scale]
-                        let precision = inner.get::<Option<i32>>(0)?.unwrap().map(|x| x as usize);
-                        let scale = inner.get::<Option<i32>>(1)?.unwrap().map(|x| x as usize);
+                        // [precision, scale]
+                        let inner = required(obj.get::<_, Array>("inner")?, &variant, "inner")
+                            .map_err(JsPolarsErr::into_napi)?;
+                        let precision = inner.get::<Option<u32>>(0)?.flatten().map(|x| x as usize);
+                        let scale = inner.get::<Option<u32>>(1)?.flatten().map(|x| x as usize);
                         DataType::Decimal(precision, scale)
                     }
-                    tp => panic!("Type {} not implemented in str_to_polarstype", tp),
+                    tp => {
+                        return Err(JsPolarsErr::Other(format!(
+                            "DataType {} is not supported here",
+                            tp
+                        ))
+                        .into_napi())
+                    }
                 };
                 Ok(Wrap(dtype))
             }
-            _ => Err(Error::new(
-                Status::InvalidArg,
-                "not a valid conversion to 'DataType'".to_owned(),
-            )),
+            _ => Err(
+                JsPolarsErr::Other("not a valid conversion to 'DataType'".to_owned()).into_napi(),
+            ),
         }
     }
 }
@@ -727,10 +874,9 @@ impl FromNapiValue for Wrap<Schema> {
                         .collect::<Result<Schema>>()?,
                 ))
             }
-            _ => Err(Error::new(
-                Status::InvalidArg,
-                "not a valid conversion to 'Schema'".to_owned(),
-            )),
+            _ => {
+                Err(JsPolarsErr::Other("not a valid conversion to 'Schema'".to_owned()).into_napi())
+            }
         }
     }
 }
@@ -772,10 +918,10 @@ impl FromNapiValue for Wrap<ParallelStrategy> {
             "row_groups" => ParallelStrategy::RowGroups,
             "none" => ParallelStrategy::None,
             _ => {
-                return Err(Error::new(
-                    Status::InvalidArg,
+                return Err(JsPolarsErr::Other(
                     "expected one of {'auto', 'columns', 'row_groups', 'none'}".to_owned(),
-                ))
+                )
+                .into_napi())
             }
         };
         Ok(Wrap(unit))
@@ -789,10 +935,10 @@ impl FromNapiValue for Wrap<InterpolationMethod> {
             "linear" => InterpolationMethod::Linear,
             "nearest" => InterpolationMethod::Nearest,
             _ => {
-                return Err(Error::new(
-                    Status::InvalidArg,
-                    "expected one of {'linear', 'nearest'}".to_owned(),
-                ))
+                return Err(
+                    JsPolarsErr::Other("expected one of {'linear', 'nearest'}".to_owned())
+                        .into_napi(),
+                )
             }
         };
         Ok(Wrap(unit))
@@ -844,11 +990,49 @@ impl FromNapiValue for Wrap<JoinType> {
             "semi" => JoinType::Semi,
             "anti" => JoinType::Anti,
             "cross" => JoinType::Cross,
-            v =>
-                return Err(Error::new(
-                    Status::InvalidArg,
-                    format!("how must be one of {{'inner', 'left', 'outer', 'semi', 'anti', 'cross'}}, got {v}")
+            v => {
+                return Err(JsPolarsErr::Other(format!(
+                "how must be one of {{'inner', 'left', 'outer', 'semi', 'anti', 'cross'}}, got {v}"
+            ))
+                .into_napi())
+            }
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl FromNapiValue for Wrap<JoinValidation> {
+    unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> napi::Result<Self> {
+        let s = String::from_napi_value(env, napi_val)?;
+        let parsed = match s.as_ref() {
+            "m:m" => JoinValidation::ManyToMany,
+            "m:1" => JoinValidation::ManyToOne,
+            "1:m" => JoinValidation::OneToMany,
+            "1:1" => JoinValidation::OneToOne,
+            v => {
+                return Err(JsPolarsErr::Other(format!(
+                    "validate must be one of {{'m:m', 'm:1', '1:m', '1:1'}}, got {v}"
                 ))
+                .into_napi())
+            }
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl FromNapiValue for Wrap<AsofStrategy> {
+    unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> napi::Result<Self> {
+        let s = String::from_napi_value(env, napi_val)?;
+        let parsed = match s.as_ref() {
+            "backward" => AsofStrategy::Backward,
+            "forward" => AsofStrategy::Forward,
+            "nearest" => AsofStrategy::Nearest,
+            v => {
+                return Err(JsPolarsErr::Other(format!(
+                    "strategy must be one of {{'backward', 'forward', 'nearest'}}, got {v}"
+                ))
+                .into_napi())
+            }
         };
         Ok(Wrap(parsed))
     }
@@ -867,42 +1051,190 @@ pub enum TypedArrayBuffer {
     Float64(Float64Array),
 }
 
-impl From<&Series> for TypedArrayBuffer {
-    fn from(series: &Series) -> Self {
+/// Start addresses of the memory currently lent to JS. V8 does not allow two
+/// ArrayBuffers over the same memory, so memory that is already lent is copied.
+static LENT_MEMORY: Mutex<BTreeSet<usize>> = Mutex::new(BTreeSet::new());
+
+/// Marks memory as lent to JS until dropped, which happens in the finalizer of
+/// the ArrayBuffer over it.
+pub(crate) struct LentMemory(usize);
+
+impl LentMemory {
+    /// Returns `None` when the memory at `ptr` is already lent.
+    pub(crate) fn lend<T>(ptr: *const T) -> Option<Self> {
+        let addr = ptr as usize;
+        LENT_MEMORY
+            .lock()
+            .unwrap()
+            .insert(addr)
+            .then_some(LentMemory(addr))
+    }
+}
+
+impl Drop for LentMemory {
+    fn drop(&mut self) {
+        LENT_MEMORY.lock().unwrap().remove(&self.0);
+    }
+}
+
+/// Converts the values of a chunked array into a typed array. With `share`,
+/// the typed array is a view over the arrow buffer, which is kept alive until
+/// the typed array is garbage collected; otherwise the values are copied.
+macro_rules! typed_array {
+    ($ca:expr, $typed_array:ident, $share:expr) => {{
+        let ca = $ca.rechunk();
+        let values = match ca.downcast_iter().next() {
+            Some(arr) => arr.values().clone(),
+            None => Default::default(),
+        };
+        match $share.then(|| LentMemory::lend(values.as_ptr())).flatten() {
+            Some(lent) => {
+                let ptr = values.as_ptr() as *mut _;
+                let len = values.len();
+                // SAFETY: `values` owns the memory, and is moved into the finalizer.
+                unsafe {
+                    $typed_array::with_external_data(ptr, len, move |_, _| {
+                        drop(values);
+                        drop(lent);
+                    })
+                }
+            }
+            None => $typed_array::with_data_copied(values.as_slice()),
+        }
+    }};
+}
+
+impl TypedArrayBuffer {
+    /// Temporal series are converted as their physical integers. With `share`,
+    /// the typed array is a view over the memory of the series, see
+    /// [`TypedArrayWithValidity`].
+    fn new(series: &Series, share: bool) -> JsPolarsResult<Self> {
         let dt = series.dtype();
-        match dt {
-            DataType::Int8 => TypedArrayBuffer::Int8(Int8Array::with_data_copied(
-                series.i8().unwrap().rechunk().cont_slice().unwrap(),
+        if !(dt.is_numeric() || dt.is_temporal()) {
+            return Err(JsPolarsErr::Other(format!(
+                "cannot convert series of type {} to a TypedArray",
+                dt
+            )));
+        }
+        let physical = series.to_physical_repr();
+        let buffer = match physical.dtype() {
+            DataType::Int8 => TypedArrayBuffer::Int8(typed_array!(
+                physical.i8().map_err(JsPolarsErr::from)?,
+                Int8Array,
+                share
             )),
-            DataType::Int16 => TypedArrayBuffer::Int16(Int16Array::with_data_copied(
-                series.i16().unwrap().rechunk().cont_slice().unwrap(),
+            DataType::Int16 => TypedArrayBuffer::Int16(typed_array!(
+                physical.i16().map_err(JsPolarsErr::from)?,
+                Int16Array,
+                share
             )),
-            DataType::Int32 => TypedArrayBuffer::Int32(Int32Array::with_data_copied(
-                series.i32().unwrap().rechunk().cont_slice().unwrap(),
+            DataType::Int32 => TypedArrayBuffer::Int32(typed_array!(
+                physical.i32().map_err(JsPolarsErr::from)?,
+                Int32Array,
+                share
             )),
-            DataType::Int64 => TypedArrayBuffer::Int64(BigInt64Array::with_data_copied(
-                series.i64().unwrap().rechunk().cont_slice().unwrap(),
+            DataType::Int64 => TypedArrayBuffer::Int64(typed_array!(
+                physical.i64().map_err(JsPolarsErr::from)?,
+                BigInt64Array,
+                share
             )),
-            DataType::UInt8 => TypedArrayBuffer::UInt8(Uint8Array::with_data_copied(
-                series.u8().unwrap().rechunk().cont_slice().unwrap(),
+            DataType::UInt8 => TypedArrayBuffer::UInt8(typed_array!(
+                physical.u8().map_err(JsPolarsErr::from)?,
+                Uint8Array,
+                share
             )),
-            DataType::UInt16 => TypedArrayBuffer::UInt16(Uint16Array::with_data_copied(
-                series.u16().unwrap().rechunk().cont_slice().unwrap(),
+            DataType::UInt16 => TypedArrayBuffer::UInt16(typed_array!(
+                physical.u16().map_err(JsPolarsErr::from)?,
+                Uint16Array,
+                share
             )),
-            DataType::UInt32 => TypedArrayBuffer::UInt32(Uint32Array::with_data_copied(
-                series.u32().unwrap().rechunk().cont_slice().unwrap(),
+            DataType::UInt32 => TypedArrayBuffer::UInt32(typed_array!(
+                physical.u32().map_err(JsPolarsErr::from)?,
+                Uint32Array,
+                share
             )),
-            DataType::UInt64 => TypedArrayBuffer::UInt64(BigUint64Array::with_data_copied(
-                series.u64().unwrap().rechunk().cont_slice().unwrap(),
+            DataType::UInt64 => TypedArrayBuffer::UInt64(typed_array!(
+                physical.u64().map_err(JsPolarsErr::from)?,
+                BigUint64Array,
+                share
             )),
-            DataType::Float32 => TypedArrayBuffer::Float32(Float32Array::with_data_copied(
-                series.f32().unwrap().rechunk().cont_slice().unwrap(),
+            DataType::Float32 => TypedArrayBuffer::Float32(typed_array!(
+                physical.f32().map_err(JsPolarsErr::from)?,
+                Float32Array,
+                share
             )),
-            DataType::Float64 => TypedArrayBuffer::Float64(Float64Array::with_data_copied(
-                series.f64().unwrap().rechunk().cont_slice().unwrap(),
+            DataType::Float64 => TypedArrayBuffer::Float64(typed_array!(
+                physical.f64().map_err(JsPolarsErr::from)?,
+                Float64Array,
+                share
             )),
-            dt => panic!("to_list() not implemented for {:?}", dt),
-        }
+            dt => {
+                return Err(JsPolarsErr::Other(format!(
+                    "cannot convert series of type {} to a TypedArray",
+                    dt
+                )))
+            }
+        };
+        Ok(buffer)
+    }
+}
+
+impl TryFrom<&Series> for TypedArrayBuffer {
+    type Error = JsPolarsErr;
+
+    /// Copies the values, so that the typed array can be written to.
+    fn try_from(series: &Series) -> JsPolarsResult<Self> {
+        TypedArrayBuffer::new(series, false)
+    }
+}
+
+/// The values of a series as a typed array, with its validity as an arrow
+/// bitmap, where bit `i % 8` of byte `i / 8` is set when row `i` is valid.
+/// `validity` is omitted when the series has no nulls.
+///
+/// Both are views over the memory of the series and its clones, so they must
+/// not be written to. Memory that is already viewed from JS is copied instead,
+/// as is all of it on runtimes that do not allow external memory.
+#[napi(object, object_from_js = false)]
+pub struct TypedArrayWithValidity {
+    #[napi(
+        ts_type = "Int8Array | Int16Array | Int32Array | BigInt64Array | Uint8Array | Uint16Array | Uint32Array | BigUint64Array | Float32Array | Float64Array"
+    )]
+    pub values: TypedArrayBuffer,
+    pub validity: Option<Uint8Array>,
+}
+
+impl TryFrom<&Series> for TypedArrayWithValidity {
+    type Error = JsPolarsErr;
+
+    fn try_from(series: &Series) -> JsPolarsResult<Self> {
+        let series = series.rechunk();
+        let values = TypedArrayBuffer::new(&series, true)?;
+        let validity = match series.chunks().first().and_then(|arr| arr.validity()) {
+            Some(bitmap) if bitmap.unset_bits() > 0 => {
+                let bitmap = match bitmap.as_slice() {
+                    (_, 0, _) => bitmap.clone(),
+                    _ => bitmap.iter().collect(),
+                };
+                let (bytes, _, len) = bitmap.as_slice();
+                let len = len.div_ceil(8);
+                match LentMemory::lend(bytes.as_ptr()) {
+                    Some(lent) => {
+                        let ptr = bytes.as_ptr() as *mut u8;
+                        // SAFETY: `bitmap` owns the memory, and is moved into the finalizer.
+                        Some(unsafe {
+                            Uint8Array::with_external_data(ptr, len, move |_, _| {
+                                drop(bitmap);
+                                drop(lent);
+                            })
+                        })
+                    }
+                    None => Some(Uint8Array::with_data_copied(&bytes[..len])),
+                }
+            }
+            _ => None,
+        };
+        Ok(TypedArrayWithValidity { values, validity })
     }
 }
 
@@ -937,6 +1269,7 @@ impl ToNapiValue for Wrap<DataType> {
             DataType::Float64 => String::to_napi_value(env, "Float64".to_owned()),
             DataType::Boolean => String::to_napi_value(env, "Bool".to_owned()),
             DataType::String => String::to_napi_value(env, "String".to_owned()),
+            DataType::Binary => String::to_napi_value(env, "Binary".to_owned()),
             DataType::List(inner) => {
                 let env_ctx = Env::from_raw(env);
                 let mut obj = env_ctx.create_object()?;
@@ -960,8 +1293,32 @@ impl ToNapiValue for Wrap<DataType> {
                 Object::to_napi_value(env, obj)
             }
             DataType::Time => String::to_napi_value(env, "Time".to_owned()),
+            DataType::Duration(tu) => {
+                let env_ctx = Env::from_raw(env);
+                let mut obj = env_ctx.create_object()?;
+                obj.set("variant", "Duration")?;
+                obj.set("inner", vec![tu.to_ascii()])?;
+                Object::to_napi_value(env, obj)
+            }
             DataType::Object(..) => String::to_napi_value(env, "Object".to_owned()),
-            DataType::Categorical(..) => String::to_napi_value(env, "Categorical".to_owned()),
+            DataType::Categorical(_, ordering) => {
+                let env_ctx = Env::from_raw(env);
+                let mut obj = env_ctx.create_object()?;
+                obj.set("variant", "Categorical")?;
+                obj.set("inner", vec![Wrap(ordering)])?;
+                Object::to_napi_value(env, obj)
+            }
+            DataType::Enum(rev_map, _) => {
+                let categories: Vec<&str> = rev_map
+                    .as_ref()
+                    .map(|rev_map| rev_map.get_categories().values_iter().collect())
+                    .unwrap_or_default();
+                let env_ctx = Env::from_raw(env);
+                let mut obj = env_ctx.create_object()?;
+                obj.set("variant", "Enum")?;
+                obj.set("inner", vec![categories])?;
+                Object::to_napi_value(env, obj)
+            }
             DataType::Struct(flds) => {
                 let env_ctx = Env::from_raw(env);
 
@@ -1001,9 +1358,10 @@ impl ToNapiValue for Wrap<DataType> {
                 obj.set("inner", inner_arr)?;
                 Object::to_napi_value(env, obj)
             }
-            _ => {
-                todo!()
-            }
+            dt => Err(
+                JsPolarsErr::Other(format!("DataType {} cannot be converted to JS", dt))
+                    .into_napi(),
+            ),
         }
     }
 }
@@ -1020,7 +1378,7 @@ impl FromNapiValue for Wrap<NullValues> {
         } else {
             Err(
                 JsPolarsErr::Other("could not extract value from null_values argument".into())
-                    .into(),
+                    .into_napi(),
             )
         }
     }
@@ -1050,6 +1408,157 @@ impl FromJsUnknown for String {
     }
 }
 
+/// Copies the bytes of a `Buffer` or `Uint8Array`, or returns `None` for any other value.
+pub(crate) fn bytes_from_js(val: &JsUnknown) -> Result<Option<Vec<u8>>> {
+    if val.is_buffer()? {
+        let buf: JsBuffer = unsafe { val.cast() };
+        return Ok(Some(buf.into_value()?.to_vec()));
+    }
+    if val.is_typedarray()? {
+        let arr: JsTypedArray = unsafe { val.cast() };
+        let arr = arr.into_value()?;
+        if matches!(
+            arr.typedarray_type,
+            TypedArrayType::Uint8 | TypedArrayType::Uint8Clamped
+        ) {
+            let bytes: &[u8] = arr.as_ref();
+            return Ok(Some(bytes.to_vec()));
+        }
+    }
+    Ok(None)
+}
+
+pub(crate) fn nanoseconds_per_unit(tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+/// JS Dates are milliseconds since the epoch, so finer units are truncated.
+pub(crate) fn datetime_to_epoch_ms(v: i64, tu: TimeUnit) -> f64 {
+    let per_ms = nanoseconds_per_unit(TimeUnit::Milliseconds) / nanoseconds_per_unit(tu);
+    v.div_euclid(per_ms) as f64
+}
+
+/// Reads a duration in `tu` from JS. BigInts are taken as nanoseconds, which
+/// is how durations are returned to JS, while numbers are taken to be in `tu`.
+/// BigInts that are not a whole number of `tu` are rejected rather than truncated.
+pub(crate) fn duration_from_js(val: &JsUnknown, tu: TimeUnit) -> JsPolarsResult<Option<i64>> {
+    match val.get_type()? {
+        ValueType::Undefined | ValueType::Null => Ok(None),
+        ValueType::BigInt => {
+            let big: JsBigInt = unsafe { val.cast() };
+            let per_unit = nanoseconds_per_unit(tu);
+            match big.get_i64()? {
+                (ns, true) if ns % per_unit == 0 => Ok(Some(ns / per_unit)),
+                (ns, true) => Err(JsPolarsErr::Other(format!(
+                    "duration of {}ns is not a whole number of {}",
+                    ns, tu
+                ))),
+                (_, false) => Err(JsPolarsErr::Other(
+                    "duration does not fit in 64 bits of nanoseconds".to_owned(),
+                )),
+            }
+        }
+        ValueType::Number => {
+            let n: JsNumber = unsafe { val.cast() };
+            Ok(Some(n.get_double()? as i64))
+        }
+        ty => Err(JsPolarsErr::Other(format!(
+            "expected a BigInt or a number for a duration, got {}",
+            ty
+        ))),
+    }
+}
+
+/// Formats an unscaled decimal, e.g. `(-1234, 2)` as `"-12.34"`.
+pub(crate) fn decimal_to_string(v: i128, scale: usize) -> String {
+    let sign = if v < 0 { "-" } else { "" };
+    let digits = format!("{:0>width$}", v.unsigned_abs(), width = scale + 1);
+    if scale == 0 {
+        return format!("{sign}{digits}");
+    }
+    let (int, frac) = digits.split_at(digits.len() - scale);
+    format!("{sign}{int}.{frac}")
+}
+
+/// Parses a decimal such as `"-12.340"` into its unscaled value and scale.
+pub(crate) fn decimal_from_str(s: &str) -> JsPolarsResult<(i128, usize)> {
+    let invalid = || JsPolarsErr::Other(format!("'{}' is not a valid decimal", s));
+    let trimmed = s.trim();
+    let (negative, unsigned) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let v = format!("{int}{frac}")
+        .parse::<i128>()
+        .map_err(|_| invalid())?;
+    Ok((if negative { -v } else { v }, frac.len()))
+}
+
+/// Reads a JS BigInt as an `i128`, erroring when it does not fit.
+pub(crate) fn i128_from_js(val: &JsUnknown) -> JsPolarsResult<i128> {
+    let mut big: JsBigInt = unsafe { val.cast() };
+    let negative = big.get_words()?.0;
+    match big.get_i128()? {
+        (v, false) if (v < 0) == negative => Ok(v),
+        _ => Err(JsPolarsErr::Other(
+            "BigInt does not fit in 128 bits".to_owned(),
+        )),
+    }
+}
+
+/// Reads a `{ value: bigint, scale: number }` decimal object, returning `None`
+/// for objects of any other shape.
+pub(crate) fn decimal_object_from_js(obj: &JsObject) -> JsPolarsResult<Option<(i128, usize)>> {
+    if !obj.has_named_property("value")? || !obj.has_named_property("scale")? {
+        return Ok(None);
+    }
+    let value: JsUnknown = obj.get_named_property("value")?;
+    let scale: JsUnknown = obj.get_named_property("scale")?;
+    if value.get_type()? != ValueType::BigInt || scale.get_type()? != ValueType::Number {
+        return Ok(None);
+    }
+    let scale: JsNumber = unsafe { scale.cast() };
+    Ok(Some((i128_from_js(&value)?, scale.get_uint32()? as usize)))
+}
+
+/// Reads a decimal from a JS string, number, BigInt or decimal object.
+/// BigInts are taken as integers, i.e. with a scale of zero.
+pub(crate) fn decimal_from_js(val: &JsUnknown) -> JsPolarsResult<Option<(i128, usize)>> {
+    match val.get_type()? {
+        ValueType::Undefined | ValueType::Null => Ok(None),
+        ValueType::String => {
+            let s: JsString = unsafe { val.cast() };
+            decimal_from_str(s.into_utf8()?.as_str()?).map(Some)
+        }
+        ValueType::Number => {
+            let n: JsNumber = unsafe { val.cast() };
+            decimal_from_str(&n.get_double()?.to_string()).map(Some)
+        }
+        ValueType::BigInt => Ok(Some((i128_from_js(val)?, 0))),
+        ValueType::Object => {
+            let obj: JsObject = unsafe { val.cast() };
+            match decimal_object_from_js(&obj)? {
+                Some(decimal) => Ok(Some(decimal)),
+                None => Err(JsPolarsErr::Other(
+                    "expected a { value: bigint, scale: number } object for a decimal".to_owned(),
+                )),
+            }
+        }
+        ty => Err(JsPolarsErr::Other(format!(
+            "expected a string, number or BigInt for a decimal, got {}",
+            ty
+        ))),
+    }
+}
+
 impl FromJsUnknown for AnyValue<'_> {
     fn from_js(val: JsUnknown) -> Result<Self> {
         match val.get_type()? {
@@ -1057,39 +1566,128 @@ impl FromJsUnknown for AnyValue<'_> {
             ValueType::Boolean => bool::from_js(val).map(AnyValue::Boolean),
             ValueType::Number => f64::from_js(val).map(AnyValue::Float64),
             ValueType::String => String::from_js(val).map(|s| AnyValue::StringOwned(s.into())),
-            ValueType::BigInt => u64::from_js(val).map(AnyValue::UInt64),
+            ValueType::BigInt => {
+                let mut big: JsBigInt = unsafe { val.cast() };
+                let (sign_bit, words) = big.get_words()?;
+                bigint_to_any_value(BigInt { sign_bit, words }).map_err(JsPolarsErr::into_napi)
+            }
             ValueType::Object => {
                 if val.is_date()? {
                     let d: JsDate = unsafe { val.cast() };
                     let d = d.value_of()?;
                     let d = d as i64;
                     Ok(AnyValue::Datetime(d, TimeUnit::Milliseconds, &None))
+                } else if let Some(bytes) = bytes_from_js(&val)? {
+                    Ok(AnyValue::BinaryOwned(bytes))
                 } else {
-                    Err(JsPolarsErr::Other("Unsupported Data type".to_owned()).into())
+                    Err(JsPolarsErr::Other("Unsupported Data type".to_owned()).into_napi())
                 }
             }
-            _ => panic!("not supported"),
+            ty => Err(JsPolarsErr::Other(format!("{} values are not supported", ty)).into_napi()),
         }
     }
 }
 
+/// Infers the dtype of a JS value, descending into arrays and plain objects.
 impl FromJsUnknown for DataType {
     fn from_js(val: JsUnknown) -> Result<Self> {
-        match val.get_type()? {
-            ValueType::Undefined | ValueType::Null => Ok(DataType::Null),
-            ValueType::Boolean => Ok(DataType::Boolean),
-            ValueType::Number => Ok(DataType::Float64),
-            ValueType::String => Ok(DataType::String),
-            ValueType::BigInt => Ok(DataType::UInt64),
-            ValueType::Object => {
-                if val.is_date()? {
-                    Ok(DataType::Datetime(TimeUnit::Milliseconds, None))
+        infer_dtype(val, 0).map_err(JsPolarsErr::into_napi)
+    }
+}
+
+/// Values nested deeper than this are rejected, which also stops
+/// inference on objects that reference themselves.
+const MAX_INFER_DEPTH: usize = 64;
+
+fn infer_dtype(val: JsUnknown, depth: usize) -> JsPolarsResult<DataType> {
+    if depth > MAX_INFER_DEPTH {
+        return Err(JsPolarsErr::Other(format!(
+            "cannot infer a dtype from values nested more than {} levels deep, is the value circular?",
+            MAX_INFER_DEPTH
+        )));
+    }
+    match val.get_type()? {
+        ValueType::Boolean => Ok(DataType::Boolean),
+        ValueType::Number => Ok(DataType::Float64),
+        ValueType::String => Ok(DataType::String),
+        ValueType::BigInt => Ok(AnyValue::from_js(val)?.dtype()),
+        ValueType::Object => {
+            if val.is_array()? {
+                let arr: JsObject = unsafe { val.cast() };
+                // dont compare too many items, as it could be expensive
+                let len = std::cmp::min(arr.get_array_length()?, 10);
+                let mut inner = DataType::Null;
+                for idx in 0..len {
+                    let dtype = infer_dtype(arr.get_element::<JsUnknown>(idx)?, depth + 1)?;
+                    inner = coerce_data_type(&[inner, dtype]);
+                }
+                Ok(DataType::List(Box::new(inner)))
+            } else if val.is_date()? {
+                Ok(DataType::Datetime(TimeUnit::Milliseconds, None))
+            } else if bytes_from_js(&val)?.is_some() {
+                Ok(DataType::Binary)
+            } else {
+                let obj: JsObject = unsafe { val.cast() };
+                let fields = Object::keys(&obj)?
+                    .iter()
+                    .map(|key| {
+                        let dtype = match obj.get::<_, JsUnknown>(key)? {
+                            Some(val) => {
+                                infer_dtype(val, depth + 1).map_err(JsPolarsErr::into_napi)?
+                            }
+                            None => DataType::Null,
+                        };
+                        Ok(Field::new(key, dtype))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                if fields.is_empty() {
+                    Ok(DataType::Null)
                 } else {
-                    Ok(DataType::String)
+                    Ok(DataType::Struct(fields))
+                }
+            }
+        }
+        ValueType::Null | ValueType::Undefined => Ok(DataType::Null),
+        vtype => Err(JsPolarsErr::Other(format!(
+            "cannot infer a dtype from a JS {}",
+            vtype
+        ))),
+    }
+}
+
+/// Coerces the dtypes inferred for JS values into a single supertype.
+/// Nulls are ignored, and struct fields are merged by name.
+pub(crate) fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType {
+    datatypes.iter().fold(DataType::Null, |lhs, rhs| {
+        coerce_data_type_pair(lhs, rhs.borrow().clone())
+    })
+}
+
+fn coerce_data_type_pair(lhs: DataType, rhs: DataType) -> DataType {
+    use DataType::*;
+
+    match (lhs, rhs) {
+        (lhs, rhs) if lhs == rhs => lhs,
+        (Null, dtype) | (dtype, Null) => dtype,
+        (List(lhs), List(rhs)) => List(Box::new(coerce_data_type_pair(*lhs, *rhs))),
+        (scalar, List(list)) | (List(list), scalar) => {
+            List(Box::new(coerce_data_type_pair(scalar, *list)))
+        }
+        (Struct(mut fields), Struct(rhs)) => {
+            for fld in rhs {
+                match fields.iter_mut().find(|f| f.name() == fld.name()) {
+                    Some(f) => f.coerce(coerce_data_type_pair(f.dtype.clone(), fld.dtype)),
+                    None => fields.push(fld),
                 }
             }
-            _ => panic!("not supported"),
+            Struct(fields)
+        }
+        (Float64, UInt64) | (UInt64, Float64) => Float64,
+        (UInt64, Boolean) | (Boolean, UInt64) => UInt64,
+        (lhs, rhs) if lhs.is_numeric() && rhs.is_numeric() => {
+            polars_core::utils::try_get_supertype(&lhs, &rhs).unwrap_or(String)
         }
+        (_, _) => String,
     }
 }
 
@@ -1112,13 +1710,19 @@ impl FromJsUnknown for i64 {
         match val.get_type()? {
             ValueType::BigInt => {
                 let big: JsBigInt = unsafe { val.cast() };
-                big.try_into()
+                match big.get_i64()? {
+                    (value, true) => Ok(value),
+                    _ => Err(
+                        JsPolarsErr::Other("BigInt is out of range for Int64".to_owned())
+                            .into_napi(),
+                    ),
+                }
             }
             ValueType::Number => {
                 let s: JsNumber = val.try_into()?;
                 s.try_into()
             }
-            dt => Err(JsPolarsErr::Other(format!("cannot cast {} to i64", dt)).into()),
+            dt => Err(JsPolarsErr::Other(format!("cannot cast {} to i64", dt)).into_napi()),
         }
     }
 }
@@ -1128,13 +1732,19 @@ impl FromJsUnknown for u64 {
         match val.get_type()? {
             ValueType::BigInt => {
                 let big: JsBigInt = unsafe { val.cast() };
-                big.try_into()
+                match big.get_u64()? {
+                    (value, true) => Ok(value),
+                    _ => Err(
+                        JsPolarsErr::Other("BigInt is out of range for UInt64".to_owned())
+                            .into_napi(),
+                    ),
+                }
             }
             ValueType::Number => {
                 let s: JsNumber = val.try_into()?;
                 Ok(s.get_int64()? as u64)
             }
-            dt => Err(JsPolarsErr::Other(format!("cannot cast {} to u64", dt)).into()),
+            dt => Err(JsPolarsErr::Other(format!("cannot cast {} to u64", dt)).into_napi()),
         }
     }
 }
@@ -1219,7 +1829,7 @@ unsafe fn struct_dict<'a>(
 pub(crate) fn parse_fill_null_strategy(
     strategy: &str,
     limit: FillNullLimit,
-) -> JsResult<FillNullStrategy> {
+) -> JsPolarsResult<FillNullStrategy> {
     let parsed = match strategy {
         "forward" => FillNullStrategy::Forward(limit),
         "backward" => FillNullStrategy::Backward(limit),
@@ -1229,7 +1839,7 @@ pub(crate) fn parse_fill_null_strategy(
         "zero" => FillNullStrategy::Zero,
         "one" => FillNullStrategy::One,
         e => {
-            return Err(napi::Error::from_reason(
+            return Err(JsPolarsErr::Other(
                 format!("Strategy {e} not supported").to_owned(),
             ))
         }
@@ -1248,7 +1858,7 @@ where
 pub(crate) fn parse_parquet_compression(
     compression: String,
     compression_level: Option<i32>,
-) -> JsResult<ParquetCompression> {
+) -> JsPolarsResult<ParquetCompression> {
     let parsed = match compression.as_ref() {
         "uncompressed" => ParquetCompression::Uncompressed,
         "snappy" => ParquetCompression::Snappy,
@@ -1257,7 +1867,7 @@ pub(crate) fn parse_parquet_compression(
                 .map(|lvl| {
                     GzipLevel::try_new(lvl as u8)
                         // .map_err(|e| JsValueErr::new_err(format!("{e:?}")))
-                        .map_err(|e| napi::Error::from_reason(format!("{:?}", e)))
+                        .map_err(|e| JsPolarsErr::Other(format!("{:?}", e)))
                 })
                 .transpose()?,
         ),
@@ -1266,7 +1876,7 @@ pub(crate) fn parse_parquet_compression(
             compression_level
                 .map(|lvl| {
                     BrotliLevel::try_new(lvl as u32)
-                        .map_err(|e| napi::Error::from_reason(format!("{e:?}")))
+                        .map_err(|e| JsPolarsErr::Other(format!("{e:?}")))
                 })
                 .transpose()?,
         ),
@@ -1275,15 +1885,37 @@ pub(crate) fn parse_parquet_compression(
             compression_level
                 .map(|lvl| {
                     ZstdLevel::try_new(lvl)
-                        .map_err(|e| napi::Error::from_reason(format!("{e:?}")))
+                        .map_err(|e| JsPolarsErr::Other(format!("{e:?}")))
                 })
                 .transpose()?,
         ),
         e => {
-            return Err(napi::Error::from_reason(format!(
+            return Err(JsPolarsErr::Other(format!(
                 "parquet `compression` must be one of {{'uncompressed', 'snappy', 'gzip', 'lzo', 'brotli', 'lz4', 'zstd'}}, got {e}",
             )))
         }
     };
     Ok(parsed)
 }
+
+pub(crate) fn parse_ipc_compression(compression: String) -> JsPolarsResult<Option<IpcCompression>> {
+    let parsed = match compression.as_ref() {
+        "uncompressed" => None,
+        "lz4" => Some(IpcCompression::LZ4),
+        "zstd" => Some(IpcCompression::ZSTD),
+        e => {
+            return Err(JsPolarsErr::Other(format!(
+                "ipc `compression` must be one of {{'uncompressed', 'lz4', 'zstd'}}, got {e}",
+            )))
+        }
+    };
+    Ok(parsed)
+}
+
+/// Reads the byte of a single character option, such as a CSV separator.
+pub(crate) fn single_byte(s: &str, name: &str) -> JsPolarsResult<u8> {
+    s.as_bytes()
+        .first()
+        .copied()
+        .ok_or_else(|| JsPolarsErr::Other(format!("{} must not be empty", name)))
+}
@@ -1,16 +1,16 @@
 use crate::file::*;
 use crate::prelude::*;
 use crate::series::JsSeries;
-use napi::JsUnknown;
-use polars::frame::row::{infer_schema, Row};
+use napi::{JsFunction, JsObject, JsUnknown};
+use polars::frame::row::Row;
 use polars::frame::NullStrategy;
+use polars_core::utils::accumulate_dataframes_vertical;
 use polars_io::mmap::MmapBytesReader;
 use polars_io::RowIndex;
 
-use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufReader, BufWriter, Cursor};
+use std::io::{self, BufRead, BufReader, BufWriter, Cursor, Read, Write};
 use std::num::NonZeroUsize;
 
 #[napi]
@@ -90,7 +90,16 @@ pub struct ReadCsvOptions {
 fn mmap_reader_to_df<'a>(
     csv: impl MmapBytesReader + 'a,
     options: ReadCsvOptions,
-) -> napi::Result<JsDataFrame> {
+) -> JsPolarsResult<DataFrame> {
+    let df = csv_read_options(options)?
+        .into_reader_with_file_handle(csv)
+        .finish()
+        .map_err(JsPolarsErr::from)?;
+
+    Ok(df)
+}
+
+fn csv_read_options(options: ReadCsvOptions) -> JsPolarsResult<CsvReadOptions> {
     let null_values = options.null_values.map(|w| w.0);
     let row_count = options.row_count.map(RowIndex::from);
     let projection = options
@@ -110,7 +119,12 @@ fn mmap_reader_to_df<'a>(
     let encoding = match options.encoding.as_ref() {
         "utf8" => CsvEncoding::Utf8,
         "utf8-lossy" => CsvEncoding::LossyUtf8,
-        e => return Err(JsPolarsErr::Other(format!("encoding not {} not implemented.", e)).into()),
+        e => {
+            return Err(JsPolarsErr::Other(format!(
+                "encoding not {} not implemented.",
+                e
+            )))
+        }
     };
 
     let overwrite_dtype = options.dtypes.map(|overwrite_dtype| {
@@ -123,7 +137,7 @@ fn mmap_reader_to_df<'a>(
             .collect::<Schema>()
     });
 
-    let df = CsvReadOptions::default()
+    let read_options = CsvReadOptions::default()
         .with_infer_schema_length(Some(options.infer_schema_length.unwrap_or(100) as usize))
         .with_projection(projection.map(Arc::new))
         .with_has_header(options.has_header)
@@ -143,34 +157,91 @@ fn mmap_reader_to_df<'a>(
         .with_raise_if_empty(options.raise_if_empty)
         .with_parse_options(
             CsvParseOptions::default()
-                .with_separator(options.sep.unwrap_or(",".to_owned()).as_bytes()[0])
+                .with_separator(single_byte(options.sep.as_deref().unwrap_or(","), "sep")?)
                 .with_encoding(encoding)
                 .with_missing_is_null(options.missing_is_null)
                 .with_comment_prefix(options.comment_char.as_deref())
                 .with_null_values(null_values)
                 .with_try_parse_dates(options.try_parse_dates)
                 .with_quote_char(quote_char)
-                .with_eol_char(options.eol_char.as_bytes()[0])
+                .with_eol_char(single_byte(&options.eol_char, "eolChar")?)
                 .with_truncate_ragged_lines(options.truncate_ragged_lines),
-        )
-        .into_reader_with_file_handle(csv)
-        .finish()
-        .map_err(JsPolarsErr::from)?;
+        );
 
-    Ok(df.into())
+    Ok(read_options)
 }
 
-#[napi(catch_unwind)]
-pub fn read_csv(
+fn df_from_csv(
     path_or_buffer: Either<String, Buffer>,
     options: ReadCsvOptions,
-) -> napi::Result<JsDataFrame> {
+) -> JsPolarsResult<DataFrame> {
     match path_or_buffer {
         Either::A(path) => mmap_reader_to_df(std::fs::File::open(path)?, options),
         Either::B(buffer) => mmap_reader_to_df(Cursor::new(buffer.as_ref()), options),
     }
 }
 
+#[napi(catch_unwind)]
+pub fn read_csv(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadCsvOptions,
+) -> js::Result<JsDataFrame> {
+    let df = df_from_csv(path_or_buffer, options)?;
+    Ok(df.into())
+}
+
+#[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
+pub fn read_csv_async(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadCsvOptions,
+) -> AsyncTask<AsyncRead> {
+    AsyncTask::new(AsyncRead(Some(Box::new(move || {
+        df_from_csv(path_or_buffer, options)
+    }))))
+}
+
+/// The CSV parser needs its whole input in memory, so the stream is drained on
+/// the reading thread before parsing. With `nRows`, only enough lines to fill
+/// them are pulled.
+#[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
+pub fn read_csv_stream(
+    pull: JsFunction,
+    options: ReadCsvOptions,
+    env: Env,
+) -> js::Result<JsObject> {
+    let n_rows = options.n_rows.map(|n| n as usize);
+    let skipped_lines = options.skip_rows as usize
+        + options.has_header as usize
+        + options.skip_rows_after_header as usize;
+    let read_options = csv_read_options(options)?;
+    let parse = move |buf: &[u8]| {
+        read_options
+            .clone()
+            .into_reader_with_file_handle(Cursor::new(buf))
+            .finish()
+    };
+    read_from_stream(pull, env, move |stream| {
+        let mut stream = BufReader::new(stream);
+        let mut buf = Vec::new();
+        let Some(n_rows) = n_rows else {
+            stream.read_to_end(&mut buf)?;
+            return parse(&buf).map_err(JsPolarsErr::from);
+        };
+        let mut lines = skipped_lines + n_rows;
+        while read_lines(&mut stream, &mut buf, lines)? {
+            // comments and quoted line breaks make for fewer rows than lines,
+            // in which case the lines read so far are read again with more of them
+            if let Ok(df) = parse(&buf) {
+                if df.height() >= n_rows {
+                    return Ok(df);
+                }
+            }
+            lines *= 2;
+        }
+        parse(&buf).map_err(JsPolarsErr::from)
+    })
+}
+
 #[napi(object)]
 pub struct ReadJsonOptions {
     pub infer_schema_length: Option<u32>,
@@ -183,19 +254,22 @@ pub struct WriteJsonOptions {
     pub format: String,
 }
 
-#[napi(catch_unwind)]
-pub fn read_json_lines(
+fn df_from_json_lines(
     path_or_buffer: Either<String, Buffer>,
     options: ReadJsonOptions,
-) -> napi::Result<JsDataFrame> {
+) -> JsPolarsResult<DataFrame> {
     let infer_schema_length = options.infer_schema_length.unwrap_or(100) as usize;
     let batch_size = options
         .batch_size
-        .map(|b| NonZeroUsize::try_from(b as usize).unwrap());
+        .map(|b| {
+            NonZeroUsize::new(b as usize)
+                .ok_or_else(|| JsPolarsErr::Other("batchSize must be positive".to_owned()))
+        })
+        .transpose()?;
 
     let df = match path_or_buffer {
         Either::A(path) => JsonLineReader::from_path(path)
-            .expect("unable to read file")
+            .map_err(JsPolarsErr::from)?
             .infer_schema_len(Some(infer_schema_length))
             .with_chunk_size(batch_size)
             .finish()
@@ -209,22 +283,88 @@ pub fn read_json_lines(
                 .map_err(JsPolarsErr::from)?
         }
     };
-    Ok(df.into())
+    Ok(df)
 }
+
 #[napi(catch_unwind)]
-pub fn read_json(
+pub fn read_json_lines(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadJsonOptions,
+) -> js::Result<JsDataFrame> {
+    let df = df_from_json_lines(path_or_buffer, options)?;
+    Ok(df.into())
+}
+
+#[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
+pub fn read_json_lines_async(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadJsonOptions,
+) -> AsyncTask<AsyncRead> {
+    AsyncTask::new(AsyncRead(Some(Box::new(move || {
+        df_from_json_lines(path_or_buffer, options)
+    }))))
+}
+
+#[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
+pub fn read_json_lines_stream(
+    pull: JsFunction,
+    options: ReadJsonOptions,
+    env: Env,
+) -> js::Result<JsObject> {
+    let infer_schema_length = options.infer_schema_length.unwrap_or(100) as usize;
+    let batch_size = options
+        .batch_size
+        .map(|b| {
+            NonZeroUsize::new(b as usize)
+                .ok_or_else(|| JsPolarsErr::Other("batchSize must be positive".to_owned()))
+        })
+        .transpose()?;
+
+    // the first block also has to hold the lines the schema is inferred from
+    let block_lines = batch_size.map_or(10_000, NonZeroUsize::get);
+    let first_block_lines = block_lines.max(infer_schema_length);
+    read_from_stream(pull, env, move |stream| {
+        let mut stream = BufReader::new(stream);
+        let mut block = Vec::new();
+        let mut more = read_lines(&mut stream, &mut block, first_block_lines)?;
+        let df = JsonLineReader::new(Cursor::new(&block))
+            .infer_schema_len(Some(infer_schema_length))
+            .with_chunk_size(batch_size)
+            .finish()
+            .map_err(JsPolarsErr::from)?;
+        let schema = Arc::new(df.schema());
+        let mut dfs = vec![df];
+        while more {
+            block.clear();
+            more = read_lines(&mut stream, &mut block, block_lines)?;
+            if block.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let df = JsonLineReader::new(Cursor::new(&block))
+                .with_schema(schema.clone())
+                .with_chunk_size(batch_size)
+                .finish()
+                .map_err(JsPolarsErr::from)?;
+            dfs.push(df);
+        }
+        accumulate_dataframes_vertical(dfs).map_err(JsPolarsErr::from)
+    })
+}
+
+fn df_from_json(
     path_or_buffer: Either<String, Buffer>,
     options: ReadJsonOptions,
-) -> napi::Result<JsDataFrame> {
+) -> JsPolarsResult<DataFrame> {
     let infer_schema_length = options.infer_schema_length.unwrap_or(100) as usize;
     let batch_size = options.batch_size.unwrap_or(10000) as usize;
-    let batch_size = NonZeroUsize::new(batch_size).unwrap();
+    let batch_size = NonZeroUsize::new(batch_size)
+        .ok_or_else(|| JsPolarsErr::Other("batchSize must be positive".to_owned()))?;
     let format: JsonFormat = options
         .format
         .map(|s| match s.as_ref() {
             "lines" => Ok(JsonFormat::JsonLines),
             "json" => Ok(JsonFormat::Json),
-            _ => Err(napi::Error::from_reason(
+            _ => Err(JsPolarsErr::Other(
                 "format must be 'json' or `lines'".to_owned(),
             )),
         })
@@ -250,9 +390,28 @@ pub fn read_json(
                 .map_err(JsPolarsErr::from)?
         }
     };
+    Ok(df)
+}
+
+#[napi(catch_unwind)]
+pub fn read_json(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadJsonOptions,
+) -> js::Result<JsDataFrame> {
+    let df = df_from_json(path_or_buffer, options)?;
     Ok(df.into())
 }
 
+#[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
+pub fn read_json_async(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadJsonOptions,
+) -> AsyncTask<AsyncRead> {
+    AsyncTask::new(AsyncRead(Some(Box::new(move || {
+        df_from_json(path_or_buffer, options)
+    }))))
+}
+
 #[napi(object)]
 pub struct ReadParquetOptions {
     pub columns: Option<Vec<String>>,
@@ -261,12 +420,11 @@ pub struct ReadParquetOptions {
     pub row_count: Option<JsRowCount>,
 }
 
-#[napi(catch_unwind)]
-pub fn read_parquet(
+fn df_from_parquet(
     path_or_buffer: Either<String, Buffer>,
     options: ReadParquetOptions,
     parallel: Wrap<ParallelStrategy>,
-) -> napi::Result<JsDataFrame> {
+) -> JsPolarsResult<DataFrame> {
     let columns = options.columns;
 
     let projection = options
@@ -299,7 +457,28 @@ pub fn read_parquet(
         }
     };
     let df = result.map_err(JsPolarsErr::from)?;
-    Ok(JsDataFrame::new(df))
+    Ok(df)
+}
+
+#[napi(catch_unwind)]
+pub fn read_parquet(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadParquetOptions,
+    parallel: Wrap<ParallelStrategy>,
+) -> js::Result<JsDataFrame> {
+    let df = df_from_parquet(path_or_buffer, options, parallel)?;
+    Ok(df.into())
+}
+
+#[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
+pub fn read_parquet_async(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadParquetOptions,
+    parallel: Wrap<ParallelStrategy>,
+) -> AsyncTask<AsyncRead> {
+    AsyncTask::new(AsyncRead(Some(Box::new(move || {
+        df_from_parquet(path_or_buffer, options, parallel)
+    }))))
 }
 
 #[napi(object)]
@@ -310,11 +489,10 @@ pub struct ReadIpcOptions {
     pub row_count: Option<JsRowCount>,
 }
 
-#[napi(catch_unwind)]
-pub fn read_ipc(
+fn df_from_ipc(
     path_or_buffer: Either<String, Buffer>,
     options: ReadIpcOptions,
-) -> napi::Result<JsDataFrame> {
+) -> JsPolarsResult<DataFrame> {
     let columns = options.columns;
     let projection = options
         .projection
@@ -344,7 +522,52 @@ pub fn read_ipc(
         }
     };
     let df = result.map_err(JsPolarsErr::from)?;
-    Ok(JsDataFrame::new(df))
+    Ok(df)
+}
+
+#[napi(catch_unwind)]
+pub fn read_ipc(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadIpcOptions,
+) -> js::Result<JsDataFrame> {
+    let df = df_from_ipc(path_or_buffer, options)?;
+    Ok(df.into())
+}
+
+#[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
+pub fn read_ipc_async(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadIpcOptions,
+) -> AsyncTask<AsyncRead> {
+    AsyncTask::new(AsyncRead(Some(Box::new(move || {
+        df_from_ipc(path_or_buffer, options)
+    }))))
+}
+
+/// Reads the Arrow IPC streaming format, decoding each message as it arrives.
+#[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
+pub fn read_ipc_stream(
+    pull: JsFunction,
+    options: ReadIpcOptions,
+    env: Env,
+) -> js::Result<JsObject> {
+    let columns = options.columns;
+    let projection = options
+        .projection
+        .map(|projection| projection.into_iter().map(|p| p as usize).collect());
+    let row_count = options.row_count.map(|rc| rc.into());
+    let n_rows = options.n_rows.map(|nr| nr as usize);
+
+    read_from_stream(pull, env, move |stream| {
+        let df = IpcStreamReader::new(stream)
+            .with_projection(projection)
+            .with_columns(columns)
+            .with_n_rows(n_rows)
+            .with_row_index(row_count)
+            .finish()
+            .map_err(JsPolarsErr::from)?;
+        Ok(df)
+    })
 }
 
 #[napi(object)]
@@ -354,11 +577,10 @@ pub struct ReadAvroOptions {
     pub n_rows: Option<i64>,
 }
 
-#[napi(catch_unwind)]
-pub fn read_avro(
+fn df_from_avro(
     path_or_buffer: Either<String, Buffer>,
     options: ReadAvroOptions,
-) -> napi::Result<JsDataFrame> {
+) -> JsPolarsResult<DataFrame> {
     use polars::io::avro::AvroReader;
     let columns = options.columns;
     let projection = options
@@ -386,52 +608,124 @@ pub fn read_avro(
         }
     };
     let df = result.map_err(JsPolarsErr::from)?;
-    Ok(JsDataFrame::new(df))
+    Ok(df)
 }
 
+#[napi(catch_unwind)]
+pub fn read_avro(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadAvroOptions,
+) -> js::Result<JsDataFrame> {
+    let df = df_from_avro(path_or_buffer, options)?;
+    Ok(df.into())
+}
+
+#[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
+pub fn read_avro_async(
+    path_or_buffer: Either<String, Buffer>,
+    options: ReadAvroOptions,
+) -> AsyncTask<AsyncRead> {
+    AsyncTask::new(AsyncRead(Some(Box::new(move || {
+        df_from_avro(path_or_buffer, options)
+    }))))
+}
+
+type ReadFn = Box<dyn FnOnce() -> JsPolarsResult<DataFrame> + Send>;
+
+pub struct AsyncRead(Option<ReadFn>);
+
+/// Errors are kept until `resolve`, as they can only be thrown with their
+/// code from the JS thread.
+impl Task for AsyncRead {
+    type Output = JsPolarsResult<DataFrame>;
+    type JsValue = JsDataFrame;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let read = self.0.take().unwrap();
+        Ok(read())
+    }
+
+    fn resolve(&mut self, env: Env, df: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(df.map_err(|e| e.into_js(env))?.into())
+    }
+}
+
+/// Runs `read` on a dedicated thread against chunks pulled from the JS
+/// callback `pull`, returning a Promise for the resulting frame.
+///
+/// The libuv pool is avoided, as `fs` streams need it to produce chunks.
+fn read_from_stream<F>(pull: JsFunction, env: Env, read: F) -> JsPolarsResult<JsObject>
+where
+    F: FnOnce(ThreadsafeReadable) -> JsPolarsResult<DataFrame> + Send + 'static,
+{
+    let stream = ThreadsafeReadable::new(&env, pull)?;
+    let (deferred, promise) = env.create_deferred()?;
+    std::thread::spawn(move || {
+        let result = read(stream);
+        // settled on the JS thread, where errors get their code
+        deferred.resolve(move |env| Ok(JsDataFrame::new(result.map_err(|e| e.into_js(env))?)));
+    });
+    Ok(promise)
+}
+
+/// Appends the next `lines` lines of `stream` to `buf`.
+/// Returns `false` once the stream is exhausted.
+fn read_lines(stream: &mut impl BufRead, buf: &mut Vec<u8>, lines: usize) -> io::Result<bool> {
+    for _ in 0..lines {
+        if stream.read_until(b'\n', buf)? == 0 {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Builds a DataFrame from an array of objects. Nested arrays and objects are
+/// read as List and Struct columns. With `strict`, rows that are not objects
+/// and values that cannot be coerced to the schema raise an error instead of
+/// becoming null.
 #[napi(catch_unwind)]
 pub fn from_rows(
     rows: Array,
     schema: Option<Wrap<Schema>>,
     infer_schema_length: Option<u32>,
-    env: Env,
-) -> napi::Result<JsDataFrame> {
+    strict: Option<bool>,
+) -> js::Result<JsDataFrame> {
+    let strict = strict.unwrap_or(false);
     let schema = match schema {
         Some(s) => s.0,
         None => {
             let infer_schema_length = infer_schema_length.unwrap_or(100) as usize;
-            let pairs = obj_to_pairs(&rows, infer_schema_length);
-            infer_schema(pairs, infer_schema_length)
+            let pairs = obj_to_pairs(&rows, infer_schema_length, strict);
+            infer_schema(pairs)?
         }
     };
     let len = rows.len();
     let it: Vec<Row> = (0..len)
-        .into_iter()
         .map(|idx| {
-            let obj = rows
-                .get::<Object>(idx as u32)
-                .unwrap_or(None)
-                .unwrap_or_else(|| env.create_object().unwrap());
-            Row(schema
+            let obj = match rows.get::<Object>(idx) {
+                Ok(Some(obj)) => obj,
+                _ if strict => return Err(not_an_object(idx as usize)),
+                // rows that are not objects are all null
+                _ => return Ok(Row(vec![AnyValue::Null; schema.len()])),
+            };
+            let values = schema
                 .iter_fields()
-                .map(|fld| {
-                    let dtype = fld.data_type().clone();
-                    let key = fld.name();
-                    if let Ok(unknown) = obj.get(key) {
-                        let av = match unknown {
-                            Some(unknown) => unsafe {
-                                coerce_js_anyvalue(unknown, dtype).unwrap_or(AnyValue::Null)
-                            },
-                            None => AnyValue::Null,
-                        };
-                        av
-                    } else {
-                        AnyValue::Null
-                    }
+                .map(|fld| match obj.get::<_, JsUnknown>(fld.name()) {
+                    Ok(Some(unknown)) => unsafe {
+                        coerce_js_anyvalue(unknown, fld.data_type(), strict).or_else(|e| {
+                            if strict {
+                                Err(e)
+                            } else {
+                                Ok(AnyValue::Null)
+                            }
+                        })
+                    },
+                    _ => Ok(AnyValue::Null),
                 })
-                .collect())
+                .collect::<napi::Result<_>>()?;
+            Ok(Row(values))
         })
-        .collect();
+        .collect::<JsPolarsResult<_>>()?;
     let df = DataFrame::from_rows_and_schema(&it, &schema).map_err(JsPolarsErr::from)?;
     Ok(df.into())
 }
@@ -444,14 +738,14 @@ impl JsDataFrame {
     }
 
     #[napi(catch_unwind)]
-    pub fn serialize(&self, format: String) -> napi::Result<Buffer> {
+    pub fn serialize(&self, format: String) -> js::Result<Buffer> {
         let buf = match format.as_ref() {
             "bincode" => bincode::serialize(&self.df)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             "json" => serde_json::to_vec(&self.df)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "unexpected format. \n supported options are 'json', 'bincode'".to_owned(),
                 ))
             }
@@ -460,22 +754,35 @@ impl JsDataFrame {
     }
 
     #[napi(factory, catch_unwind)]
-    pub fn deserialize(buf: Buffer, format: String) -> napi::Result<JsDataFrame> {
+    pub fn deserialize(buf: Buffer, format: String) -> js::Result<JsDataFrame> {
         let df: DataFrame = match format.as_ref() {
             "bincode" => bincode::deserialize(&buf)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             "json" => serde_json::from_slice(&buf)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "unexpected format. \n supported options are 'json', 'bincode'".to_owned(),
                 ))
             }
         };
         Ok(df.into())
     }
+
+    /// Returns the frame as an apache-arrow `Table`-like object, with a record
+    /// batch per chunk.
+    #[napi(catch_unwind)]
+    pub fn to_arrow(&self, env: Env) -> js::Result<JsObject> {
+        crate::interop::dataframe_to_arrow(&env, &self.df)
+    }
+
+    #[napi(factory, catch_unwind)]
+    pub fn from_arrow(table: JsObject) -> js::Result<JsDataFrame> {
+        let df = crate::interop::arrow_to_dataframe(&table)?;
+        Ok(df.into())
+    }
     #[napi(constructor)]
-    pub fn from_columns(columns: Array) -> napi::Result<JsDataFrame> {
+    pub fn from_columns(columns: Array) -> js::Result<JsDataFrame> {
         let len = columns.len();
         let cols: Vec<Series> = (0..len)
             .map(|idx| {
@@ -499,61 +806,61 @@ impl JsDataFrame {
     }
 
     #[napi(catch_unwind)]
-    pub fn add(&self, s: &JsSeries) -> napi::Result<JsDataFrame> {
+    pub fn add(&self, s: &JsSeries) -> js::Result<JsDataFrame> {
         let df = (&self.df + &s.series).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn sub(&self, s: &JsSeries) -> napi::Result<JsDataFrame> {
+    pub fn sub(&self, s: &JsSeries) -> js::Result<JsDataFrame> {
         let df = (&self.df - &s.series).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn div(&self, s: &JsSeries) -> napi::Result<JsDataFrame> {
+    pub fn div(&self, s: &JsSeries) -> js::Result<JsDataFrame> {
         let df = (&self.df / &s.series).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn mul(&self, s: &JsSeries) -> napi::Result<JsDataFrame> {
+    pub fn mul(&self, s: &JsSeries) -> js::Result<JsDataFrame> {
         let df = (&self.df * &s.series).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn rem(&self, s: &JsSeries) -> napi::Result<JsDataFrame> {
+    pub fn rem(&self, s: &JsSeries) -> js::Result<JsDataFrame> {
         let df = (&self.df % &s.series).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn add_df(&self, s: &JsDataFrame) -> napi::Result<JsDataFrame> {
+    pub fn add_df(&self, s: &JsDataFrame) -> js::Result<JsDataFrame> {
         let df = (&self.df + &s.df).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn sub_df(&self, s: &JsDataFrame) -> napi::Result<JsDataFrame> {
+    pub fn sub_df(&self, s: &JsDataFrame) -> js::Result<JsDataFrame> {
         let df = (&self.df - &s.df).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn div_df(&self, s: &JsDataFrame) -> napi::Result<JsDataFrame> {
+    pub fn div_df(&self, s: &JsDataFrame) -> js::Result<JsDataFrame> {
         let df = (&self.df / &s.df).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn mul_df(&self, s: &JsDataFrame) -> napi::Result<JsDataFrame> {
+    pub fn mul_df(&self, s: &JsDataFrame) -> js::Result<JsDataFrame> {
         let df = (&self.df * &s.df).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn rem_df(&self, s: &JsDataFrame) -> napi::Result<JsDataFrame> {
+    pub fn rem_df(&self, s: &JsDataFrame) -> js::Result<JsDataFrame> {
         let df = (&self.df % &s.df).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
@@ -565,7 +872,7 @@ impl JsDataFrame {
         df.into()
     }
     #[napi(catch_unwind)]
-    pub fn fill_null(&self, strategy: Wrap<FillNullStrategy>) -> napi::Result<JsDataFrame> {
+    pub fn fill_null(&self, strategy: Wrap<FillNullStrategy>) -> js::Result<JsDataFrame> {
         let df = self.df.fill_null(strategy.0).map_err(JsPolarsErr::from)?;
         Ok(JsDataFrame::new(df))
     }
@@ -577,7 +884,7 @@ impl JsDataFrame {
         right_on: Vec<&str>,
         how: String,
         suffix: Option<String>,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let how = match how.as_ref() {
             "left" => JoinType::Left,
             "inner" => JoinType::Inner,
@@ -592,7 +899,12 @@ impl JsDataFrame {
                 tolerance_str: None,
             }),
             "cross" => JoinType::Cross,
-            _ => panic!("not supported"),
+            v => {
+                return Err(JsPolarsErr::Other(format!(
+                    "how must be one of {{'left', 'inner', 'outer', 'semi', 'anti', 'asof', 'cross'}}, got {}",
+                    v
+                )))
+            }
         };
 
         let df = self
@@ -624,7 +936,7 @@ impl JsDataFrame {
     }
 
     #[napi(setter, js_name = "columns", catch_unwind)]
-    pub fn set_columns(&mut self, names: Vec<&str>) -> napi::Result<()> {
+    pub fn set_columns(&mut self, names: Vec<&str>) -> js::Result<()> {
         self.df
             .set_column_names(&names)
             .map_err(JsPolarsErr::from)?;
@@ -632,7 +944,7 @@ impl JsDataFrame {
     }
 
     #[napi(catch_unwind)]
-    pub fn with_column(&mut self, s: &JsSeries) -> napi::Result<JsDataFrame> {
+    pub fn with_column(&mut self, s: &JsSeries) -> js::Result<JsDataFrame> {
         let mut df = self.df.clone();
         df.with_column(s.series.clone())
             .map_err(JsPolarsErr::from)?;
@@ -667,39 +979,39 @@ impl JsDataFrame {
         self.df.schema().into()
     }
     #[napi(catch_unwind)]
-    pub fn hstack_mut(&mut self, columns: Array) -> napi::Result<()> {
+    pub fn hstack_mut(&mut self, columns: Array) -> js::Result<()> {
         let columns = to_series_collection(columns);
         self.df.hstack_mut(&columns).map_err(JsPolarsErr::from)?;
         Ok(())
     }
     #[napi(catch_unwind)]
-    pub fn hstack(&self, columns: Array) -> napi::Result<JsDataFrame> {
+    pub fn hstack(&self, columns: Array) -> js::Result<JsDataFrame> {
         let columns = to_series_collection(columns);
         let df = self.df.hstack(&columns).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
     #[napi(catch_unwind)]
-    pub fn extend(&mut self, df: &JsDataFrame) -> napi::Result<()> {
+    pub fn extend(&mut self, df: &JsDataFrame) -> js::Result<()> {
         self.df.extend(&df.df).map_err(JsPolarsErr::from)?;
         Ok(())
     }
     #[napi(catch_unwind)]
-    pub fn vstack_mut(&mut self, df: &JsDataFrame) -> napi::Result<()> {
+    pub fn vstack_mut(&mut self, df: &JsDataFrame) -> js::Result<()> {
         self.df.vstack_mut(&df.df).map_err(JsPolarsErr::from)?;
         Ok(())
     }
     #[napi(catch_unwind)]
-    pub fn vstack(&mut self, df: &JsDataFrame) -> napi::Result<JsDataFrame> {
+    pub fn vstack(&mut self, df: &JsDataFrame) -> js::Result<JsDataFrame> {
         let df = self.df.vstack(&df.df).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
     #[napi(catch_unwind)]
-    pub fn drop_in_place(&mut self, name: String) -> napi::Result<JsSeries> {
+    pub fn drop_in_place(&mut self, name: String) -> js::Result<JsSeries> {
         let s = self.df.drop_in_place(&name).map_err(JsPolarsErr::from)?;
         Ok(JsSeries { series: s })
     }
     #[napi(catch_unwind)]
-    pub fn drop_nulls(&self, subset: Option<Vec<String>>) -> napi::Result<JsDataFrame> {
+    pub fn drop_nulls(&self, subset: Option<Vec<String>>) -> js::Result<JsDataFrame> {
         let df = self
             .df
             .drop_nulls(subset.as_ref().map(|s| s.as_ref()))
@@ -708,7 +1020,7 @@ impl JsDataFrame {
     }
 
     #[napi(catch_unwind)]
-    pub fn drop(&self, name: String) -> napi::Result<JsDataFrame> {
+    pub fn drop(&self, name: String) -> js::Result<JsDataFrame> {
         let df = self.df.drop(&name).map_err(JsPolarsErr::from)?;
         Ok(JsDataFrame::new(df))
     }
@@ -724,7 +1036,7 @@ impl JsDataFrame {
         self.df.get_column_index(&name).map(|i| i as i64)
     }
     #[napi(catch_unwind)]
-    pub fn column(&self, name: String) -> napi::Result<JsSeries> {
+    pub fn column(&self, name: String) -> js::Result<JsSeries> {
         let series = self
             .df
             .column(&name)
@@ -733,30 +1045,28 @@ impl JsDataFrame {
         Ok(series)
     }
     #[napi(catch_unwind)]
-    pub fn select(&self, selection: Vec<&str>) -> napi::Result<JsDataFrame> {
+    pub fn select(&self, selection: Vec<&str>) -> js::Result<JsDataFrame> {
         let df = self.df.select(&selection).map_err(JsPolarsErr::from)?;
         Ok(JsDataFrame::new(df))
     }
     #[napi(catch_unwind)]
-    pub fn filter(&self, mask: &JsSeries) -> napi::Result<JsDataFrame> {
+    pub fn filter(&self, mask: &JsSeries) -> js::Result<JsDataFrame> {
         let filter_series = &mask.series;
         if let Ok(ca) = filter_series.bool() {
             let df = self.df.filter(ca).map_err(JsPolarsErr::from)?;
             Ok(JsDataFrame::new(df))
         } else {
-            Err(napi::Error::from_reason(
-                "Expected a boolean mask".to_owned(),
-            ))
+            Err(JsPolarsErr::Other("Expected a boolean mask".to_owned()))
         }
     }
     #[napi(catch_unwind)]
-    pub fn take(&self, indices: Vec<u32>) -> napi::Result<JsDataFrame> {
+    pub fn take(&self, indices: Vec<u32>) -> js::Result<JsDataFrame> {
         let indices = UInt32Chunked::from_vec("", indices);
         let df = self.df.take(&indices).map_err(JsPolarsErr::from)?;
         Ok(JsDataFrame::new(df))
     }
     #[napi(catch_unwind)]
-    pub fn take_with_series(&self, indices: &JsSeries) -> napi::Result<JsDataFrame> {
+    pub fn take_with_series(&self, indices: &JsSeries) -> js::Result<JsDataFrame> {
         let idx = indices.series.u32().map_err(JsPolarsErr::from)?;
         let df = self.df.take(idx).map_err(JsPolarsErr::from)?;
         Ok(JsDataFrame::new(df))
@@ -768,7 +1078,7 @@ impl JsDataFrame {
         descending: bool,
         nulls_last: bool,
         maintain_order: bool,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let df = self
             .df
             .sort(
@@ -787,7 +1097,7 @@ impl JsDataFrame {
         by_column: String,
         descending: bool,
         maintain_order: bool,
-    ) -> napi::Result<()> {
+    ) -> js::Result<()> {
         self.df
             .sort_in_place(
                 [&by_column],
@@ -799,7 +1109,7 @@ impl JsDataFrame {
         Ok(())
     }
     #[napi(catch_unwind)]
-    pub fn replace(&mut self, column: String, new_col: &JsSeries) -> napi::Result<()> {
+    pub fn replace(&mut self, column: String, new_col: &JsSeries) -> js::Result<()> {
         self.df
             .replace(&column, new_col.series.clone())
             .map_err(JsPolarsErr::from)?;
@@ -807,7 +1117,7 @@ impl JsDataFrame {
     }
 
     #[napi(catch_unwind)]
-    pub fn rename(&mut self, column: String, new_col: String) -> napi::Result<()> {
+    pub fn rename(&mut self, column: String, new_col: String) -> js::Result<()> {
         self.df
             .rename(&column, &new_col)
             .map_err(JsPolarsErr::from)?;
@@ -815,7 +1125,7 @@ impl JsDataFrame {
     }
 
     #[napi(catch_unwind)]
-    pub fn replace_at_idx(&mut self, index: f64, new_col: &JsSeries) -> napi::Result<()> {
+    pub fn replace_at_idx(&mut self, index: f64, new_col: &JsSeries) -> js::Result<()> {
         self.df
             .replace_column(index as usize, new_col.series.clone())
             .map_err(JsPolarsErr::from)?;
@@ -823,7 +1133,7 @@ impl JsDataFrame {
     }
 
     #[napi(catch_unwind)]
-    pub fn insert_at_idx(&mut self, index: f64, new_col: &JsSeries) -> napi::Result<()> {
+    pub fn insert_at_idx(&mut self, index: f64, new_col: &JsSeries) -> js::Result<()> {
         self.df
             .insert_column(index as usize, new_col.series.clone())
             .map_err(JsPolarsErr::from)?;
@@ -849,12 +1159,12 @@ impl JsDataFrame {
         JsDataFrame::new(df)
     }
     #[napi(catch_unwind)]
-    pub fn is_unique(&self) -> napi::Result<JsSeries> {
+    pub fn is_unique(&self) -> js::Result<JsSeries> {
         let mask = self.df.is_unique().map_err(JsPolarsErr::from)?;
         Ok(mask.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn is_duplicated(&self) -> napi::Result<JsSeries> {
+    pub fn is_duplicated(&self) -> js::Result<JsSeries> {
         let mask = self.df.is_duplicated().map_err(JsPolarsErr::from)?;
         Ok(mask.into_series().into())
     }
@@ -867,7 +1177,7 @@ impl JsDataFrame {
         }
     }
     #[napi(catch_unwind)]
-    pub fn with_row_count(&self, name: String, offset: Option<u32>) -> napi::Result<JsDataFrame> {
+    pub fn with_row_count(&self, name: String, offset: Option<u32>) -> js::Result<JsDataFrame> {
         let df = self
             .df
             .with_row_index(&name, offset)
@@ -880,7 +1190,7 @@ impl JsDataFrame {
         by: Vec<&str>,
         select: Option<Vec<String>>,
         agg: String,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let gb = self.df.group_by(&by).map_err(JsPolarsErr::from)?;
         let selection = match select.as_ref() {
             Some(s) => gb.select(s),
@@ -899,7 +1209,7 @@ impl JsDataFrame {
         maintain_order: bool,
         sort_columns: bool,
         separator: Option<&str>,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let fun = match maintain_order {
             true => polars::prelude::pivot::pivot_stable,
             false => polars::prelude::pivot::pivot,
@@ -914,7 +1224,7 @@ impl JsDataFrame {
             separator,
         )
         .map(|df| df.into())
-        .map_err(|e| napi::Error::from_reason(format!("Could not pivot: {}", e)))
+        .map_err(|e| JsPolarsErr::Other(format!("Could not pivot: {}", e)))
     }
     #[napi(catch_unwind)]
     pub fn clone(&self) -> JsDataFrame {
@@ -928,7 +1238,7 @@ impl JsDataFrame {
         value_name: Option<String>,
         variable_name: Option<String>,
         streamable: Option<bool>,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let args = MeltArgs {
             id_vars: strings_to_smartstrings(id_vars),
             value_vars: strings_to_smartstrings(value_vars),
@@ -947,7 +1257,7 @@ impl JsDataFrame {
         groups: Vec<String>,
         stable: bool,
         include_key: bool,
-    ) -> napi::Result<Vec<JsDataFrame>> {
+    ) -> js::Result<Vec<JsDataFrame>> {
         let out = if stable {
             self.df.partition_by_stable(groups, include_key)
         } else {
@@ -970,7 +1280,7 @@ impl JsDataFrame {
         subset: Option<Vec<String>>,
         keep: Wrap<UniqueKeepStrategy>,
         slice: Option<Wrap<(i64, usize)>>,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let subset = subset.as_ref().map(|v| v.as_ref());
         let df = self
             .df
@@ -990,7 +1300,7 @@ impl JsDataFrame {
     }
 
     #[napi(catch_unwind)]
-    pub fn hmean(&self, null_strategy: Wrap<NullStrategy>) -> napi::Result<Option<JsSeries>> {
+    pub fn hmean(&self, null_strategy: Wrap<NullStrategy>) -> js::Result<Option<JsSeries>> {
         let s = self
             .df
             .mean_horizontal(null_strategy.0)
@@ -998,19 +1308,19 @@ impl JsDataFrame {
         Ok(s.map(|s| s.into()))
     }
     #[napi(catch_unwind)]
-    pub fn hmax(&self) -> napi::Result<Option<JsSeries>> {
+    pub fn hmax(&self) -> js::Result<Option<JsSeries>> {
         let s = self.df.max_horizontal().map_err(JsPolarsErr::from)?;
         Ok(s.map(|s| s.into()))
     }
 
     #[napi(catch_unwind)]
-    pub fn hmin(&self) -> napi::Result<Option<JsSeries>> {
+    pub fn hmin(&self) -> js::Result<Option<JsSeries>> {
         let s = self.df.min_horizontal().map_err(JsPolarsErr::from)?;
         Ok(s.map(|s| s.into()))
     }
 
     #[napi(catch_unwind)]
-    pub fn hsum(&self, null_strategy: Wrap<NullStrategy>) -> napi::Result<Option<JsSeries>> {
+    pub fn hsum(&self, null_strategy: Wrap<NullStrategy>) -> js::Result<Option<JsSeries>> {
         let s = self
             .df
             .sum_horizontal(null_strategy.0)
@@ -1018,11 +1328,7 @@ impl JsDataFrame {
         Ok(s.map(|s| s.into()))
     }
     #[napi(catch_unwind)]
-    pub fn to_dummies(
-        &self,
-        separator: Option<&str>,
-        drop_first: bool,
-    ) -> napi::Result<JsDataFrame> {
+    pub fn to_dummies(&self, separator: Option<&str>, drop_first: bool) -> js::Result<JsDataFrame> {
         let df = self
             .df
             .to_dummies(separator, drop_first)
@@ -1046,7 +1352,7 @@ impl JsDataFrame {
         k1: Wrap<u64>,
         k2: Wrap<u64>,
         k3: Wrap<u64>,
-    ) -> napi::Result<JsSeries> {
+    ) -> js::Result<JsSeries> {
         let hb = polars::export::ahash::RandomState::with_seeds(k0.0, k1.0, k2.0, k3.0);
         let hash = self.df.hash_rows(Some(hb)).map_err(JsPolarsErr::from)?;
         Ok(hash.into_series().into())
@@ -1057,7 +1363,7 @@ impl JsDataFrame {
         &mut self,
         keep_names_as: Option<String>,
         names: Option<Either<String, Vec<String>>>,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let names = names.map(|e| match e {
             Either::A(s) => either::Either::Left(s),
             Either::B(v) => either::Either::Right(v),
@@ -1076,7 +1382,7 @@ impl JsDataFrame {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<i64>,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let df = self
             .df
             .sample_n(&n.series, with_replacement, shuffle, seed.map(|s| s as u64))
@@ -1091,7 +1397,7 @@ impl JsDataFrame {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<i64>,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let df = self
             .df
             .sample_frac(
@@ -1112,7 +1418,7 @@ impl JsDataFrame {
         every: String,
         offset: String,
         stable: bool,
-    ) -> napi::Result<JsDataFrame> {
+    ) -> js::Result<JsDataFrame> {
         let out = if stable {
             self.df.upsample_stable(
                 by,
@@ -1137,7 +1443,7 @@ impl JsDataFrame {
         s.into_series().into()
     }
     #[napi(catch_unwind)]
-    pub fn unnest(&self, names: Vec<String>) -> napi::Result<JsDataFrame> {
+    pub fn unnest(&self, names: Vec<String>) -> js::Result<JsDataFrame> {
         let df = self.df.unnest(names).map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
@@ -1300,19 +1606,20 @@ impl JsDataFrame {
     // Ok(())
     // }
 
-    #[napi(catch_unwind)]
+    #[napi(catch_unwind, ts_return_type = "Promise<void> | void")]
     pub fn write_csv(
         &mut self,
         path_or_buffer: JsUnknown,
         options: WriteCsvOptions,
         env: Env,
-    ) -> napi::Result<()> {
+    ) -> js::Result<Option<JsObject>> {
         let include_header = options.include_header.unwrap_or(true);
-        let sep = options.sep.unwrap_or(",".to_owned()).as_bytes()[0];
-        let quote = options.quote.unwrap_or("\"".to_owned()).as_bytes()[0];
+        let sep = single_byte(options.sep.as_deref().unwrap_or(","), "sep")?;
+        let quote = single_byte(options.quote.as_deref().unwrap_or("\""), "quote")?;
         let include_bom = options.include_bom.unwrap_or(false);
         let line_terminator = options.line_terminator.unwrap_or("\n".to_owned());
-        let batch_size = NonZeroUsize::new(options.batch_size.unwrap_or(1024) as usize);
+        let batch_size = NonZeroUsize::new(options.batch_size.unwrap_or(1024) as usize)
+            .ok_or_else(|| JsPolarsErr::Other("batchSize must be positive".to_owned()))?;
         let date_format = options.date_format;
         let time_format = options.time_format;
         let datetime_format = options.datetime_format;
@@ -1321,203 +1628,152 @@ impl JsDataFrame {
             .null_value
             .unwrap_or(SerializeOptions::default().null);
 
-        match path_or_buffer.get_type()? {
-            ValueType::String => {
-                let path: napi::JsString = unsafe { path_or_buffer.cast() };
-                let path = path.into_utf8()?.into_owned()?;
-
-                let f = std::fs::File::create(path).unwrap();
-                let f = BufWriter::new(f);
-                CsvWriter::new(f)
-                    .include_bom(include_bom)
-                    .include_header(include_header)
-                    .with_separator(sep)
-                    .with_line_terminator(line_terminator)
-                    .with_batch_size(batch_size.unwrap())
-                    .with_datetime_format(datetime_format)
-                    .with_date_format(date_format)
-                    .with_time_format(time_format)
-                    .with_float_precision(float_precision)
-                    .with_null_value(null_value)
-                    .with_quote_char(quote)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)?;
-            }
-            ValueType::Object => {
-                let inner: napi::JsObject = unsafe { path_or_buffer.cast() };
-                let writeable = JsWriteStream { inner, env: &env };
-
-                CsvWriter::new(writeable)
-                    .include_bom(include_bom)
-                    .include_header(include_header)
-                    .with_separator(sep)
-                    .with_line_terminator(line_terminator)
-                    .with_batch_size(batch_size.unwrap())
-                    .with_datetime_format(datetime_format)
-                    .with_date_format(date_format)
-                    .with_time_format(time_format)
-                    .with_float_precision(float_precision)
-                    .with_null_value(null_value)
-                    .with_quote_char(quote)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)?;
-            }
-            _ => panic!(),
-        };
-        Ok(())
+        write_to(&mut self.df, path_or_buffer, env, move |df, f| {
+            CsvWriter::new(f)
+                .include_bom(include_bom)
+                .include_header(include_header)
+                .with_separator(sep)
+                .with_line_terminator(line_terminator)
+                .with_batch_size(batch_size)
+                .with_datetime_format(datetime_format)
+                .with_date_format(date_format)
+                .with_time_format(time_format)
+                .with_float_precision(float_precision)
+                .with_null_value(null_value)
+                .with_quote_char(quote)
+                .finish(df)
+        })
     }
 
-    #[napi(catch_unwind)]
+    #[napi(catch_unwind, ts_return_type = "Promise<void> | void")]
     pub fn write_parquet(
         &mut self,
         path_or_buffer: JsUnknown,
         compression: Wrap<ParquetCompression>,
         env: Env,
-    ) -> napi::Result<()> {
+    ) -> js::Result<Option<JsObject>> {
         let compression = compression.0;
 
-        match path_or_buffer.get_type()? {
-            ValueType::String => {
-                let path: napi::JsString = unsafe { path_or_buffer.cast() };
-                let path = path.into_utf8()?.into_owned()?;
-
-                let f = std::fs::File::create(path).unwrap();
-                let f = BufWriter::new(f);
-                ParquetWriter::new(f)
-                    .with_compression(compression)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)?;
-            }
-            ValueType::Object => {
-                let inner: napi::JsObject = unsafe { path_or_buffer.cast() };
-                let writeable = JsWriteStream { inner, env: &env };
-
-                ParquetWriter::new(writeable)
-                    .with_compression(compression)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)?;
-            }
-            _ => panic!(),
-        };
-        Ok(())
+        write_to(&mut self.df, path_or_buffer, env, move |df, f| {
+            ParquetWriter::new(f)
+                .with_compression(compression)
+                .finish(df)
+                .map(|_| ())
+        })
     }
-    #[napi(catch_unwind)]
+    #[napi(catch_unwind, ts_return_type = "Promise<void> | void")]
     pub fn write_ipc(
         &mut self,
         path_or_buffer: JsUnknown,
         compression: Wrap<Option<IpcCompression>>,
         env: Env,
-    ) -> napi::Result<()> {
+    ) -> js::Result<Option<JsObject>> {
         let compression = compression.0;
 
-        match path_or_buffer.get_type()? {
-            ValueType::String => {
-                let path: napi::JsString = unsafe { path_or_buffer.cast() };
-                let path = path.into_utf8()?.into_owned()?;
-                let f = std::fs::File::create(path).unwrap();
-                let f = BufWriter::new(f);
-                IpcWriter::new(f)
-                    .with_compression(compression)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)?;
-            }
-            ValueType::Object => {
-                let inner: napi::JsObject = unsafe { path_or_buffer.cast() };
-                let writeable = JsWriteStream { inner, env: &env };
-                IpcWriter::new(writeable)
-                    .with_compression(compression)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)?;
-            }
-            _ => panic!(),
-        };
-        Ok(())
+        write_to(&mut self.df, path_or_buffer, env, move |df, f| {
+            IpcWriter::new(f).with_compression(compression).finish(df)
+        })
     }
-    #[napi(catch_unwind)]
+    #[napi(catch_unwind, ts_return_type = "Promise<void> | void")]
     pub fn write_json(
         &mut self,
         path_or_buffer: JsUnknown,
         options: WriteJsonOptions,
         env: Env,
-    ) -> napi::Result<()> {
+    ) -> js::Result<Option<JsObject>> {
         let json_format = options.format;
         let json_format = match json_format.as_ref() {
             "json" => JsonFormat::Json,
             "lines" => JsonFormat::JsonLines,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "format must be 'json' or `lines'".to_owned(),
                 ))
             }
         };
 
-        match path_or_buffer.get_type()? {
-            ValueType::String => {
-                let path: napi::JsString = unsafe { path_or_buffer.cast() };
-                let path = path.into_utf8()?.into_owned()?;
-                let f = std::fs::File::create(path).unwrap();
-                let f = BufWriter::new(f);
-                JsonWriter::new(f)
-                    .with_json_format(json_format)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)?;
-            }
-            ValueType::Object => {
-                let inner: napi::JsObject = unsafe { path_or_buffer.cast() };
-                let writeable = JsWriteStream { inner, env: &env };
-                JsonWriter::new(writeable)
-                    .with_json_format(json_format)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)
-                    .unwrap()
-            }
-            _ => panic!(),
-        };
-        Ok(())
+        write_to(&mut self.df, path_or_buffer, env, move |df, f| {
+            JsonWriter::new(f).with_json_format(json_format).finish(df)
+        })
     }
-    #[napi(catch_unwind)]
+    #[napi(catch_unwind, ts_return_type = "Promise<void> | void")]
     pub fn write_avro(
         &mut self,
         path_or_buffer: JsUnknown,
         compression: String,
         env: Env,
-    ) -> napi::Result<()> {
+    ) -> js::Result<Option<JsObject>> {
         use polars::io::avro::{AvroCompression, AvroWriter};
         let compression = match compression.as_ref() {
             "uncompressed" => None,
             "snappy" => Some(AvroCompression::Snappy),
             "deflate" => Some(AvroCompression::Deflate),
-            s => return Err(JsPolarsErr::Other(format!("compression {} not supported", s)).into()),
-        };
-
-        match path_or_buffer.get_type()? {
-            ValueType::String => {
-                let path: napi::JsString = unsafe { path_or_buffer.cast() };
-                let path = path.into_utf8()?.into_owned()?;
-                let f = std::fs::File::create(path).unwrap();
-                let f = BufWriter::new(f);
-                AvroWriter::new(f)
-                    .with_compression(compression)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)?;
-            }
-            ValueType::Object => {
-                let inner: napi::JsObject = unsafe { path_or_buffer.cast() };
-                let writeable = JsWriteStream { inner, env: &env };
-
-                AvroWriter::new(writeable)
-                    .with_compression(compression)
-                    .finish(&mut self.df)
-                    .map_err(JsPolarsErr::from)?;
+            s => {
+                return Err(JsPolarsErr::Other(format!(
+                    "compression {} not supported",
+                    s
+                )))
             }
-            _ => panic!(),
         };
-        Ok(())
+
+        write_to(&mut self.df, path_or_buffer, env, move |df, f| {
+            AvroWriter::new(f).with_compression(compression).finish(df)
+        })
+    }
+}
+
+/// Serializes `df` with `write` to a file path, a JS write stream, or a
+/// `(chunk, done) => void` callback.
+///
+/// Paths and streams are written to synchronously. A callback is written to
+/// from a separate thread, honouring backpressure, and a Promise is returned
+/// that settles once every chunk has been accepted. A dedicated thread is used
+/// rather than the libuv pool, as `fs` streams need the pool to make progress.
+fn write_to<F>(
+    df: &mut DataFrame,
+    path_or_buffer: JsUnknown,
+    env: Env,
+    write: F,
+) -> JsPolarsResult<Option<JsObject>>
+where
+    F: FnOnce(&mut DataFrame, &mut dyn Write) -> PolarsResult<()> + Send + 'static,
+{
+    match path_or_buffer.get_type()? {
+        ValueType::String => {
+            let path: napi::JsString = unsafe { path_or_buffer.cast() };
+            let path = path.into_utf8()?.into_owned()?;
+            let f = std::fs::File::create(path).map_err(JsPolarsErr::from)?;
+            let mut f = BufWriter::new(f);
+            write(df, &mut f).map_err(JsPolarsErr::from)?;
+            f.flush().map_err(JsPolarsErr::from)?;
+            Ok(None)
+        }
+        ValueType::Object => {
+            let inner: napi::JsObject = unsafe { path_or_buffer.cast() };
+            let mut writeable = JsWriteStream::new(inner, &env);
+            write(df, &mut writeable).map_err(JsPolarsErr::from)?;
+            Ok(None)
+        }
+        ValueType::Function => {
+            let callback: JsFunction = unsafe { path_or_buffer.cast() };
+            let writeable = ThreadsafeWriteable::new(&env, callback)?;
+            let mut df = df.clone();
+            let (deferred, promise) = env.create_deferred()?;
+            std::thread::spawn(move || {
+                let mut f = BufWriter::new(writeable);
+                let result = write(&mut df, &mut f).and_then(|_| Ok(f.flush()?));
+                deferred.resolve(move |env| result.map_err(|e| JsPolarsErr::from(e).into_js(env)));
+            });
+            Ok(Some(promise))
+        }
+        _ => Err(JsPolarsErr::Other(
+            "expected a file path, a writable stream or a write callback".to_owned(),
+        )),
     }
 }
 
 #[allow(deprecated)]
-fn finish_groupby(gb: GroupBy, agg: &str) -> napi::Result<JsDataFrame> {
+fn finish_groupby(gb: GroupBy, agg: &str) -> JsPolarsResult<JsDataFrame> {
     let df = match agg {
         "min" => gb.min(),
         "max" => gb.max(),
@@ -1539,95 +1795,76 @@ fn finish_groupby(gb: GroupBy, agg: &str) -> napi::Result<JsDataFrame> {
     Ok(JsDataFrame::new(df))
 }
 
-fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType {
-    use DataType::*;
-
-    let are_all_equal = datatypes.windows(2).all(|w| w[0].borrow() == w[1].borrow());
-
-    if are_all_equal {
-        return datatypes[0].borrow().clone();
-    }
-
-    let (lhs, rhs) = (datatypes[0].borrow(), datatypes[1].borrow());
-
-    return match (lhs, rhs) {
-        (lhs, rhs) if lhs == rhs => lhs.clone(),
-        (List(lhs), List(rhs)) => {
-            let inner = coerce_data_type(&[lhs.as_ref(), rhs.as_ref()]);
-            List(Box::new(inner))
-        }
-        (scalar, List(list)) => {
-            let inner = coerce_data_type(&[scalar, list.as_ref()]);
-            List(Box::new(inner))
-        }
-        (List(list), scalar) => {
-            let inner = coerce_data_type(&[scalar, list.as_ref()]);
-            List(Box::new(inner))
-        }
-        (Float64, UInt64) => Float64,
-        (UInt64, Float64) => Float64,
-        (UInt64, Boolean) => UInt64,
-        (Boolean, UInt64) => UInt64,
-        (_, _) => String,
-    };
-}
-
-fn obj_to_pairs(rows: &Array, len: usize) -> impl '_ + Iterator<Item = Vec<(String, DataType)>> {
+/// Infers the dtypes of the first `len` rows. Without `strict`, rows that are
+/// not objects are skipped and values without a dtype are taken as null.
+fn obj_to_pairs(
+    rows: &Array,
+    len: usize,
+    strict: bool,
+) -> impl '_ + Iterator<Item = JsPolarsResult<Vec<(String, DataType)>>> {
     let len = std::cmp::min(len, rows.len() as usize);
     (0..len).map(move |idx| {
-        let obj = rows.get::<Object>(idx as u32).unwrap().unwrap();
+        let obj = match rows.get::<Object>(idx as u32) {
+            Ok(Some(obj)) => obj,
+            _ if strict => return Err(not_an_object(idx)),
+            _ => return Ok(vec![]),
+        };
 
-        let keys = Object::keys(&obj).unwrap();
-        keys.iter()
+        Object::keys(&obj)?
+            .into_iter()
             .map(|key| {
-                let value = obj.get::<_, napi::JsUnknown>(&key).unwrap_or(None);
-                let dtype = match value {
-                    Some(val) => {
-                        let ty = val.get_type().unwrap();
-                        match ty {
-                            ValueType::Boolean => DataType::Boolean,
-                            ValueType::Number => DataType::Float64,
-                            ValueType::String => DataType::String,
-                            ValueType::Object => {
-                                if val.is_array().unwrap() {
-                                    let arr: napi::JsObject = unsafe { val.cast() };
-                                    let len = arr.get_array_length().unwrap();
-                                    // dont compare too many items, as it could be expensive
-                                    let max_take = std::cmp::min(len as usize, 10);
-                                    let mut dtypes: Vec<DataType> =
-                                        Vec::with_capacity(len as usize);
-
-                                    for idx in 0..max_take {
-                                        let item: napi::JsUnknown =
-                                            arr.get_element(idx as u32).unwrap();
-                                        let ty = item.get_type().unwrap();
-                                        let dt: Wrap<DataType> = ty.into();
-                                        dtypes.push(dt.0)
-                                    }
-                                    let dtype = coerce_data_type(&dtypes);
-
-                                    DataType::List(dtype.into())
-                                } else if val.is_date().unwrap() {
-                                    DataType::Datetime(TimeUnit::Milliseconds, None)
-                                } else {
-                                    DataType::Struct(vec![])
-                                }
-                            }
-                            ValueType::BigInt => DataType::UInt64,
-                            _ => DataType::Null,
-                        }
-                    }
+                let dtype = match obj.get::<_, napi::JsUnknown>(&key)? {
+                    Some(val) => match DataType::from_js(val) {
+                        Ok(dtype) => dtype,
+                        Err(e) if strict => return Err(e.into()),
+                        Err(_) => DataType::Null,
+                    },
                     None => DataType::Null,
                 };
-                (key.to_owned(), dtype)
+                Ok((key, dtype))
             })
             .collect()
     })
 }
 
-unsafe fn coerce_js_anyvalue<'a>(val: JsUnknown, dtype: DataType) -> JsResult<AnyValue<'a>> {
+fn not_an_object(idx: usize) -> JsPolarsErr {
+    JsPolarsErr::Other(format!("row {} is not an object", idx))
+}
+
+/// Unlike `polars::frame::row::infer_schema`, struct fields seen in different
+/// rows are merged, and columns that are always null are kept.
+fn infer_schema(
+    pairs: impl Iterator<Item = JsPolarsResult<Vec<(String, DataType)>>>,
+) -> JsPolarsResult<Schema> {
+    let mut dtypes: PlIndexMap<String, DataType> = PlIndexMap::new();
+    for row in pairs {
+        for (key, dtype) in row? {
+            let entry = dtypes.entry(key).or_insert(DataType::Null);
+            *entry = coerce_data_type(&[&*entry, &dtype]);
+        }
+    }
+    Ok(dtypes
+        .into_iter()
+        .map(|(name, dtype)| Field::new(&name, dtype))
+        .collect())
+}
+
+/// Coerces a JS value to `dtype`. Values that cannot be coerced become null,
+/// or raise an error if `strict` is set.
+unsafe fn coerce_js_anyvalue<'a>(
+    val: JsUnknown,
+    dtype: &DataType,
+    strict: bool,
+) -> JsResult<AnyValue<'a>> {
     use DataType::*;
-    let vtype = val.get_type().unwrap();
+    let vtype = val.get_type()?;
+    let fallback = || {
+        if strict {
+            Err(JsPolarsErr::Other(format!("cannot coerce JS {} to {}", vtype, dtype)).into_napi())
+        } else {
+            Ok(AnyValue::Null)
+        }
+    };
     match (vtype, dtype) {
         (ValueType::Null | ValueType::Undefined | ValueType::Unknown, _) => Ok(AnyValue::Null),
         (ValueType::String, String) => AnyValue::from_js(val),
@@ -1704,13 +1941,56 @@ unsafe fn coerce_js_anyvalue<'a>(val: JsUnknown, dtype: DataType) -> JsResult<An
                 let d = d.value_of()?;
                 Ok(AnyValue::Datetime(d as i64, TimeUnit::Milliseconds, &None))
             } else {
-                Ok(AnyValue::Null)
+                fallback()
             }
         }
-        (ValueType::Object, DataType::List(_)) => {
-            let s = val.to_series();
+        (ValueType::String, Categorical(..) | Enum(..)) => AnyValue::from_js(val),
+        (ValueType::String, Binary) => {
+            std::string::String::from_js(val).map(|s| AnyValue::BinaryOwned(s.into_bytes()))
+        }
+        (ValueType::Object, Binary) => match bytes_from_js(&val)? {
+            Some(bytes) => Ok(AnyValue::BinaryOwned(bytes)),
+            None => fallback(),
+        },
+        (_, Duration(tu)) => match duration_from_js(&val, *tu).map_err(JsPolarsErr::into_napi)? {
+            Some(v) => Ok(AnyValue::Duration(v, *tu)),
+            None => fallback(),
+        },
+        (_, Decimal(..)) => match decimal_from_js(&val).map_err(JsPolarsErr::into_napi)? {
+            Some((v, scale)) => Ok(AnyValue::Decimal(v, scale)),
+            None => fallback(),
+        },
+        (_, Time) => {
+            match duration_from_js(&val, TimeUnit::Nanoseconds).map_err(JsPolarsErr::into_napi)? {
+                Some(v) => Ok(AnyValue::Time(v)),
+                None => fallback(),
+            }
+        }
+        (ValueType::Object, List(inner)) if val.is_array()? => {
+            let arr: JsObject = val.cast();
+            let len = arr.get_array_length()?;
+            let mut values = Vec::with_capacity(len as usize);
+            for idx in 0..len {
+                let item: JsUnknown = arr.get_element(idx)?;
+                values.push(coerce_js_anyvalue(item, inner, strict)?);
+            }
+            let s = Series::from_any_values_and_dtype("", &values, inner, strict)
+                .map_err(JsPolarsErr::from)
+                .map_err(JsPolarsErr::into_napi)?;
             Ok(AnyValue::List(s))
         }
-        _ => Ok(AnyValue::Null),
+        (ValueType::Object, Struct(fields)) if !val.is_array()? => {
+            let obj: JsObject = val.cast();
+            let mut values = Vec::with_capacity(fields.len());
+            for fld in fields {
+                let av = match obj.get::<_, JsUnknown>(fld.name())? {
+                    Some(v) => coerce_js_anyvalue(v, fld.data_type(), strict)?,
+                    None => AnyValue::Null,
+                };
+                values.push(av);
+            }
+            Ok(AnyValue::StructOwned(Box::new((values, fields.clone()))))
+        }
+        _ => fallback(),
     }
 }
@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use crate::series::JsSeries;
+use napi::{JsUnknown, NapiRaw};
 #[napi(js_name = "DataType")]
 pub enum JsDataType {
     Int8,
@@ -22,10 +23,14 @@ pub enum JsDataType {
     Object,
     Categorical,
     Struct,
+    // appended so the values of the variants above stay the same
+    Binary,
+    Duration,
+    Enum,
 }
 impl JsDataType {
-    pub fn from_str(s: &str) -> Self {
-        match s {
+    pub fn from_str(s: &str) -> JsPolarsResult<Self> {
+        let dtype = match s {
             "Int8" => JsDataType::Int8,
             "Int16" => JsDataType::Int16,
             "Int32" => JsDataType::Int32,
@@ -39,22 +44,28 @@ impl JsDataType {
             "Bool" => JsDataType::Bool,
             "Utf8" => JsDataType::Utf8,
             "String" => JsDataType::String,
+            "Binary" => JsDataType::Binary,
             "List" => JsDataType::List,
             "Date" => JsDataType::Date,
             "Datetime" => JsDataType::Datetime,
+            "Duration" => JsDataType::Duration,
             "Time" => JsDataType::Time,
             "Object" => JsDataType::Object,
             "Categorical" => JsDataType::Categorical,
+            "Enum" => JsDataType::Enum,
             "Struct" => JsDataType::Struct,
-            _ => panic!("not a valid dtype"),
-        }
+            _ => return Err(JsPolarsErr::Other(format!("'{}' is not a valid dtype", s))),
+        };
+        Ok(dtype)
     }
 }
 
-impl From<&DataType> for JsDataType {
-    fn from(dt: &DataType) -> Self {
+impl TryFrom<&DataType> for JsDataType {
+    type Error = napi::Error;
+
+    fn try_from(dt: &DataType) -> napi::Result<Self> {
         use JsDataType::*;
-        match dt {
+        let dtype = match dt {
             DataType::Int8 => Int8,
             DataType::Int16 => Int16,
             DataType::Int32 => Int32,
@@ -67,22 +78,32 @@ impl From<&DataType> for JsDataType {
             DataType::Float64 => Float64,
             DataType::Boolean => Bool,
             DataType::String => Utf8,
+            DataType::Binary => Binary,
             DataType::List(_) => List,
             DataType::Date => Date,
             DataType::Datetime(_, _) => Datetime,
+            DataType::Duration(_) => Duration,
             DataType::Time => Time,
             DataType::Object(..) => Object,
             DataType::Categorical(..) => Categorical,
+            DataType::Enum(..) => Enum,
             DataType::Struct(_) => Struct,
-            _ => panic!("null or unknown not expected here"),
-        }
+            dt => {
+                return Err(
+                    JsPolarsErr::Other(format!("{} has no corresponding DataType", dt)).into_napi(),
+                )
+            }
+        };
+        Ok(dtype)
     }
 }
 
-impl From<napi::TypedArrayType> for JsDataType {
-    fn from(dt: napi::TypedArrayType) -> Self {
+impl TryFrom<napi::TypedArrayType> for JsDataType {
+    type Error = napi::Error;
+
+    fn try_from(dt: napi::TypedArrayType) -> napi::Result<Self> {
         use napi::TypedArrayType::*;
-        match dt {
+        let dtype = match dt {
             Int8 => JsDataType::Int8,
             Uint8 => JsDataType::UInt8,
             Uint8Clamped => JsDataType::UInt8,
@@ -94,8 +115,14 @@ impl From<napi::TypedArrayType> for JsDataType {
             Float64 => JsDataType::Float64,
             BigInt64 => JsDataType::Int64,
             BigUint64 => JsDataType::UInt64,
-            _ => panic!("unknown datatype"),
-        }
+            dt => {
+                return Err(
+                    JsPolarsErr::Other(format!("{:?} typed arrays are not supported", dt))
+                        .into_napi(),
+                )
+            }
+        };
+        Ok(dtype)
     }
 }
 
@@ -105,6 +132,7 @@ pub enum JsAnyValue {
     Boolean(bool),
     Utf8(String),
     String(String),
+    Binary(Vec<u8>),
     UInt8(u8),
     UInt16(u16),
     UInt32(u32),
@@ -119,6 +147,7 @@ pub enum JsAnyValue {
     Datetime(i64, TimeUnit, Option<TimeZone>),
     Duration(i64, TimeUnit),
     Time(i64),
+    Decimal(i128, usize),
     List(Series),
     Struct(Vec<JsAnyValue>),
 }
@@ -130,27 +159,37 @@ impl<'a> FromNapiValue for JsAnyValue {
             ValueType::Boolean => JsAnyValue::Boolean(bool::from_napi_value(env, napi_val)?),
             ValueType::Number => JsAnyValue::Float64(f64::from_napi_value(env, napi_val)?),
             ValueType::String => JsAnyValue::Utf8(String::from_napi_value(env, napi_val)?),
-            ValueType::BigInt => JsAnyValue::UInt64(Wrap::<u64>::from_napi_value(env, napi_val)?.0),
+            ValueType::BigInt => bigint_to_any_value(BigInt::from_napi_value(env, napi_val)?)
+                .map_err(JsPolarsErr::into_napi)?
+                .try_into()?,
             ValueType::Object => {
-                if let Ok(s) = <&JsSeries>::from_napi_value(env, napi_val) {
+                let unknown = JsUnknown::from_napi_value(env, napi_val)?;
+                if let Some(bytes) = bytes_from_js(&unknown)? {
+                    JsAnyValue::Binary(bytes)
+                } else if let Ok(s) = <&JsSeries>::from_napi_value(env, napi_val) {
                     JsAnyValue::List(s.series.clone())
                 } else if let Ok(d) = napi::JsDate::from_napi_value(env, napi_val) {
                     let d = d.value_of()?;
                     let dt = d as i64;
                     JsAnyValue::Datetime(dt, TimeUnit::Milliseconds, None)
+                } else if let Some((v, scale)) =
+                    decimal_object_from_js(&unknown.coerce_to_object()?)
+                        .map_err(JsPolarsErr::into_napi)?
+                {
+                    JsAnyValue::Decimal(v, scale)
                 } else {
-                    return Err(Error::new(
-                        Status::InvalidArg,
+                    return Err(JsPolarsErr::Other(
                         "Unknown JS variables cannot be represented as a JsAnyValue".to_owned(),
-                    ));
+                    )
+                    .into_napi());
                 }
             }
             ValueType::Null | ValueType::Undefined => JsAnyValue::Null,
             _ => {
-                return Err(Error::new(
-                    Status::InvalidArg,
+                return Err(JsPolarsErr::Other(
                     "Unknown JS variables cannot be represented as a JsAnyValue".to_owned(),
-                ))
+                )
+                .into_napi())
             }
         };
         Ok(val)
@@ -167,11 +206,16 @@ impl<'a> FromNapiValue for Wrap<AnyValue<'a>> {
                 let s = String::from_napi_value(env, napi_val)?;
                 AnyValue::StringOwned(s.into())
             }
-            ValueType::BigInt => AnyValue::UInt64(Wrap::<u64>::from_napi_value(env, napi_val)?.0),
+            ValueType::BigInt => bigint_to_any_value(BigInt::from_napi_value(env, napi_val)?)
+                .map_err(JsPolarsErr::into_napi)?,
             ValueType::Object => {
-                if let Ok(vals) = Vec::<Wrap<AnyValue>>::from_napi_value(env, napi_val) {
+                let unknown = JsUnknown::from_napi_value(env, napi_val)?;
+                if let Some(bytes) = bytes_from_js(&unknown)? {
+                    AnyValue::BinaryOwned(bytes)
+                } else if let Ok(vals) = Vec::<Wrap<AnyValue>>::from_napi_value(env, napi_val) {
                     let vals = std::mem::transmute::<_, Vec<AnyValue>>(vals);
-                    let s = Series::new("", vals);
+                    let s = Series::from_any_values("", &vals, false)
+                        .map_err(|e| JsPolarsErr::from(e).into_napi())?;
                     AnyValue::List(s)
                 } else if let Ok(s) = <&JsSeries>::from_napi_value(env, napi_val) {
                     AnyValue::List(s.series.clone())
@@ -179,19 +223,31 @@ impl<'a> FromNapiValue for Wrap<AnyValue<'a>> {
                     let d = d.value_of()?;
                     let dt = d as i64;
                     AnyValue::Datetime(dt, TimeUnit::Milliseconds, &None)
+                } else if let Some((v, scale)) =
+                    decimal_object_from_js(&unknown.coerce_to_object()?)
+                        .map_err(JsPolarsErr::into_napi)?
+                {
+                    AnyValue::Decimal(v, scale)
                 } else {
-                    return Err(Error::new(
-                        Status::InvalidArg,
-                        "Unknown JS variables cannot be represented as a JsAnyValue".to_owned(),
-                    ));
+                    let obj = Object::from_napi_value(env, napi_val)?;
+                    let keys = Object::keys(&obj)?;
+                    let mut vals = Vec::with_capacity(keys.len());
+                    let mut fields = Vec::with_capacity(keys.len());
+                    for key in keys {
+                        let val: JsUnknown = obj.get_named_property(&key)?;
+                        let av = Wrap::<AnyValue>::from_napi_value(env, val.raw())?.0;
+                        fields.push(Field::new(&key, av.dtype()));
+                        vals.push(av);
+                    }
+                    AnyValue::StructOwned(Box::new((vals, fields)))
                 }
             }
             ValueType::Null | ValueType::Undefined => AnyValue::Null,
             _ => {
-                return Err(Error::new(
-                    Status::InvalidArg,
+                return Err(JsPolarsErr::Other(
                     "Unknown JS variables cannot be represented as a JsAnyValue".to_owned(),
-                ))
+                )
+                .into_napi())
             }
         };
 
@@ -220,6 +276,7 @@ impl ToNapiValue for JsAnyValue {
             JsAnyValue::Int8(n) => i32::to_napi_value(env, n as i32),
             JsAnyValue::Int16(n) => i32::to_napi_value(env, n as i32),
             JsAnyValue::Int32(n) => i32::to_napi_value(env, n),
+            JsAnyValue::Int64(n) if crate::int64_as_bigint() => i64n::to_napi_value(env, i64n(n)),
             JsAnyValue::Int64(n) => i64::to_napi_value(env, n),
             JsAnyValue::UInt8(n) => u32::to_napi_value(env, n as u32),
             JsAnyValue::UInt16(n) => u32::to_napi_value(env, n as u32),
@@ -229,6 +286,7 @@ impl ToNapiValue for JsAnyValue {
             JsAnyValue::Float64(n) => f64::to_napi_value(env, n),
             JsAnyValue::Utf8(s) => String::to_napi_value(env, s),
             JsAnyValue::String(s) => String::to_napi_value(env, s),
+            JsAnyValue::Binary(b) => Buffer::to_napi_value(env, Buffer::from(b)),
             JsAnyValue::Date(v) => {
                 let mut ptr = std::ptr::null_mut();
 
@@ -239,18 +297,23 @@ impl ToNapiValue for JsAnyValue {
 
                 Ok(ptr)
             }
-            JsAnyValue::Datetime(v, _, _) => {
+            JsAnyValue::Datetime(v, tu, _) => {
                 let mut ptr = std::ptr::null_mut();
 
                 check_status!(
-                    napi::sys::napi_create_date(env, v as f64, &mut ptr),
+                    napi::sys::napi_create_date(env, datetime_to_epoch_ms(v, tu), &mut ptr),
                     "Failed to convert rust type `AnyValue::Date` into napi value",
                 )?;
 
                 Ok(ptr)
             }
-            JsAnyValue::Duration(v, _) => i64::to_napi_value(env, v),
-            JsAnyValue::Time(v) => i64::to_napi_value(env, v),
+            JsAnyValue::Duration(v, tu) => {
+                i128::to_napi_value(env, v as i128 * nanoseconds_per_unit(tu) as i128)
+            }
+            JsAnyValue::Time(v) => i128::to_napi_value(env, v as i128),
+            JsAnyValue::Decimal(v, scale) => {
+                String::to_napi_value(env, decimal_to_string(v, scale))
+            }
             JsAnyValue::List(ser) => JsSeries::to_napi_value(env, ser.into()),
             JsAnyValue::Struct(vals) => {
                 let vals = std::mem::transmute::<_, Vec<JsAnyValue>>(vals);
@@ -260,12 +323,15 @@ impl ToNapiValue for JsAnyValue {
     }
 }
 
-impl<'a> From<JsAnyValue> for AnyValue<'a> {
-    fn from(av: JsAnyValue) -> Self {
-        match av {
+impl<'a> TryFrom<JsAnyValue> for AnyValue<'a> {
+    type Error = napi::Error;
+
+    fn try_from(av: JsAnyValue) -> napi::Result<Self> {
+        let av = match av {
             JsAnyValue::Null => AnyValue::Null,
             JsAnyValue::Boolean(v) => AnyValue::Boolean(v),
-            JsAnyValue::Utf8(v) => AnyValue::StringOwned(v.into()),
+            JsAnyValue::Utf8(v) | JsAnyValue::String(v) => AnyValue::StringOwned(v.into()),
+            JsAnyValue::Binary(v) => AnyValue::BinaryOwned(v),
             JsAnyValue::UInt8(v) => AnyValue::UInt8(v),
             JsAnyValue::UInt16(v) => AnyValue::UInt16(v),
             JsAnyValue::UInt32(v) => AnyValue::UInt32(v),
@@ -278,20 +344,32 @@ impl<'a> From<JsAnyValue> for AnyValue<'a> {
             JsAnyValue::Float64(v) => AnyValue::Float64(v),
             JsAnyValue::Date(v) => AnyValue::Date(v),
             JsAnyValue::Datetime(v, w, _) => AnyValue::Datetime(v, w, &None),
-            JsAnyValue::Duration(v, _) => AnyValue::Duration(v, TimeUnit::Milliseconds),
+            JsAnyValue::Duration(v, tu) => AnyValue::Duration(v, tu),
             JsAnyValue::Time(v) => AnyValue::Time(v),
+            JsAnyValue::Decimal(v, scale) => AnyValue::Decimal(v, scale),
             JsAnyValue::List(v) => AnyValue::List(v),
-            _ => todo!(), // JsAnyValue::Struct(v) => AnyValue::Struct(v),
-        }
+            // the field names of a struct are not kept
+            JsAnyValue::Struct(_) => {
+                return Err(JsPolarsErr::Other(
+                    "struct values cannot be converted without their fields".to_owned(),
+                )
+                .into_napi())
+            }
+        };
+        Ok(av)
     }
 }
 
-impl From<AnyValue<'_>> for JsAnyValue {
-    fn from(av: AnyValue) -> Self {
-        match av {
+impl TryFrom<AnyValue<'_>> for JsAnyValue {
+    type Error = napi::Error;
+
+    fn try_from(av: AnyValue) -> napi::Result<Self> {
+        let av = match av {
             AnyValue::Null => JsAnyValue::Null,
             AnyValue::Boolean(v) => JsAnyValue::Boolean(v),
             AnyValue::String(v) => JsAnyValue::Utf8(v.to_owned()),
+            AnyValue::Binary(v) => JsAnyValue::Binary(v.to_vec()),
+            AnyValue::BinaryOwned(v) => JsAnyValue::Binary(v),
             AnyValue::UInt8(v) => JsAnyValue::UInt8(v),
             AnyValue::UInt16(v) => JsAnyValue::UInt16(v),
             AnyValue::UInt32(v) => JsAnyValue::UInt32(v),
@@ -303,21 +381,34 @@ impl From<AnyValue<'_>> for JsAnyValue {
             AnyValue::Float32(v) => JsAnyValue::Float32(v),
             AnyValue::Float64(v) => JsAnyValue::Float64(v),
             AnyValue::Date(v) => JsAnyValue::Date(v),
-            AnyValue::Datetime(v, w, _) => JsAnyValue::Datetime(v, w, None),
-            AnyValue::Duration(v, _) => JsAnyValue::Duration(v, TimeUnit::Milliseconds),
+            AnyValue::Datetime(v, w, tz) => JsAnyValue::Datetime(v, w, tz.clone()),
+            AnyValue::Duration(v, tu) => JsAnyValue::Duration(v, tu),
             AnyValue::Time(v) => JsAnyValue::Time(v),
+            AnyValue::Decimal(v, scale) => JsAnyValue::Decimal(v, scale),
             AnyValue::List(v) => JsAnyValue::List(v),
-            _ => todo!(), // JsAnyValue::Struct(v) => AnyValue::Struct(v),
-        }
+            ref av @ (AnyValue::Categorical(..) | AnyValue::Enum(..)) => {
+                JsAnyValue::Utf8(av.get_str().unwrap().to_owned())
+            }
+            av => {
+                return Err(
+                    JsPolarsErr::Other(format!("{} values are not supported", av.dtype()))
+                        .into_napi(),
+                )
+            }
+        };
+        Ok(av)
     }
 }
 
-impl From<&JsAnyValue> for DataType {
-    fn from(av: &JsAnyValue) -> Self {
-        match av {
+impl TryFrom<&JsAnyValue> for DataType {
+    type Error = napi::Error;
+
+    fn try_from(av: &JsAnyValue) -> napi::Result<Self> {
+        let dtype = match av {
             JsAnyValue::Null => DataType::Null,
             JsAnyValue::Boolean(_) => DataType::Boolean,
-            JsAnyValue::Utf8(_) => DataType::String,
+            JsAnyValue::Utf8(_) | JsAnyValue::String(_) => DataType::String,
+            JsAnyValue::Binary(_) => DataType::Binary,
             JsAnyValue::UInt8(_) => DataType::UInt8,
             JsAnyValue::UInt16(_) => DataType::UInt16,
             JsAnyValue::UInt32(_) => DataType::UInt32,
@@ -329,11 +420,19 @@ impl From<&JsAnyValue> for DataType {
             JsAnyValue::Float32(_) => DataType::Float32,
             JsAnyValue::Float64(_) => DataType::Float64,
             JsAnyValue::Date(_) => DataType::Date,
-            JsAnyValue::Datetime(_, _, _) => DataType::Datetime(TimeUnit::Milliseconds, None),
-            JsAnyValue::Duration(_, _) => DataType::Duration(TimeUnit::Milliseconds),
+            JsAnyValue::Datetime(_, tu, tz) => DataType::Datetime(*tu, tz.clone()),
+            JsAnyValue::Duration(_, tu) => DataType::Duration(*tu),
             JsAnyValue::Time(_) => DataType::Time,
-            _ => todo!(), // JsAnyValue::Struct(v) => AnyValue::Struct(v),
-        }
+            JsAnyValue::Decimal(_, scale) => DataType::Decimal(None, Some(*scale)),
+            JsAnyValue::List(s) => DataType::List(Box::new(s.dtype().clone())),
+            JsAnyValue::Struct(_) => {
+                return Err(JsPolarsErr::Other(
+                    "the dtype of a struct value cannot be known without its fields".to_owned(),
+                )
+                .into_napi())
+            }
+        };
+        Ok(dtype)
     }
 }
 
@@ -344,9 +443,7 @@ macro_rules! impl_av_into {
             fn try_into(self) -> napi::Result<$type> {
                 match self.0 {
                     $pattern => $extracted_value,
-                    _ => Err(napi::Error::from_reason(
-                        "invalid primitive cast".to_owned(),
-                    )),
+                    _ => Err(JsPolarsErr::Other("invalid primitive cast".to_owned()).into_napi()),
                 }
             }
         }
@@ -357,9 +454,7 @@ impl<'a> TryInto<&'a str> for Wrap<AnyValue<'a>> {
     fn try_into(self) -> napi::Result<&'a str> {
         match self.0 {
             AnyValue::String(v) => Ok(v),
-            _ => Err(napi::Error::from_reason(
-                "invalid primitive cast".to_owned(),
-            )),
+            _ => Err(JsPolarsErr::Other("invalid primitive cast".to_owned()).into_napi()),
         }
     }
 }
@@ -395,12 +490,15 @@ impl Into<DataType> for JsDataType {
             JsDataType::Bool => Boolean,
             JsDataType::Utf8 => String,
             JsDataType::String => String,
+            JsDataType::Binary => Binary,
             JsDataType::List => List(DataType::Null.into()),
             JsDataType::Date => Date,
             JsDataType::Datetime => Datetime(TimeUnit::Milliseconds, None),
+            JsDataType::Duration => Duration(TimeUnit::Milliseconds),
             JsDataType::Time => Time,
             JsDataType::Object => Object("object", None),
             JsDataType::Categorical => Categorical(None, Default::default()),
+            JsDataType::Enum => Enum(None, Default::default()),
             JsDataType::Struct => Struct(vec![]),
         }
     }
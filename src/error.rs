@@ -1,4 +1,7 @@
+use napi::bindgen_prelude::JsError;
+use napi::{sys, Env, JsObject};
 use polars::prelude::PolarsError;
+use std::cell::Cell;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -7,12 +10,128 @@ pub enum JsPolarsErr {
     Any(#[from] PolarsError),
     #[error("{0}")]
     Other(String),
+    /// Errors of napi itself, thrown as they are.
+    #[error(transparent)]
+    Napi(#[from] napi::Error),
+}
+
+pub type JsPolarsResult<T> = std::result::Result<T, JsPolarsErr>;
+
+pub mod js {
+    /// The result of exported functions, so that their errors are thrown
+    /// with a `code`. napi-derive only unwraps return types named `Result`.
+    pub type Result<T> = std::result::Result<T, super::JsPolarsErr>;
+}
+
+impl From<std::io::Error> for JsPolarsErr {
+    fn from(err: std::io::Error) -> Self {
+        JsPolarsErr::Any(err.into())
+    }
+}
+
+impl JsPolarsErr {
+    /// Stable error kind, exposed to JS as `PolarsError.code`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            JsPolarsErr::Any(err) => polars_err_code(err),
+            JsPolarsErr::Other(_) | JsPolarsErr::Napi(_) => "InvalidArgument",
+        }
+    }
+
+    /// The column or field the error refers to, if any.
+    pub fn column(&self) -> Option<String> {
+        match self {
+            JsPolarsErr::Any(err) => polars_err_column(err),
+            JsPolarsErr::Other(_) | JsPolarsErr::Napi(_) => None,
+        }
+    }
+
+    /// Convert into the error handed back to napi, which throws it or
+    /// rejects with it. Only call this where the error leaves Rust, e.g. in
+    /// `Task::resolve`: the JS object is released once thrown.
+    pub fn into_js(self, env: Env) -> napi::Error {
+        match self {
+            JsPolarsErr::Napi(err) => err,
+            err => match err.to_js_error(env) {
+                Ok(js_err) => napi::Error::from(js_err.into_unknown()),
+                Err(e) => e,
+            },
+        }
+    }
+
+    /// Create the `PolarsError` thrown to JS, with its `code` and `column`.
+    pub fn to_js_error(&self, env: Env) -> napi::Result<JsObject> {
+        let mut err = env.create_error(napi::Error::from_reason(self.to_string()))?;
+        err.set_named_property("name", env.create_string("PolarsError")?)?;
+        err.set_named_property("code", env.create_string(self.code())?)?;
+        if let Some(column) = self.column() {
+            err.set_named_property("column", env.create_string(&column)?)?;
+        }
+        Ok(err)
+    }
+}
+
+fn polars_err_code(err: &PolarsError) -> &'static str {
+    match err {
+        PolarsError::ColumnNotFound(_) => "ColumnNotFound",
+        PolarsError::ComputeError(_) => "ComputeError",
+        PolarsError::Duplicate(_) => "Duplicate",
+        PolarsError::InvalidOperation(_) => "InvalidOperation",
+        PolarsError::IO { .. } => "IO",
+        PolarsError::NoData(_) => "NoData",
+        PolarsError::OutOfBounds(_) => "OutOfBounds",
+        PolarsError::SchemaFieldNotFound(_) => "SchemaFieldNotFound",
+        PolarsError::SchemaMismatch(_) => "SchemaMismatch",
+        PolarsError::ShapeMismatch(_) => "ShapeMismatch",
+        PolarsError::StringCacheMismatch(_) => "StringCacheMismatch",
+        PolarsError::StructFieldNotFound(_) => "StructFieldNotFound",
+        PolarsError::Context { error, .. } => polars_err_code(error),
+    }
+}
+
+fn polars_err_column(err: &PolarsError) -> Option<String> {
+    match err {
+        PolarsError::ColumnNotFound(name)
+        | PolarsError::SchemaFieldNotFound(name)
+        | PolarsError::StructFieldNotFound(name) => {
+            let name = name.lines().next()?.trim().trim_matches('"');
+            Some(name.to_owned())
+        }
+        PolarsError::Context { error, .. } => polars_err_column(error),
+        _ => None,
+    }
+}
+
+thread_local! {
+    /// The env of the JS thread that loaded the module, see [`init_errors`].
+    static JS_ENV: Cell<Option<sys::napi_env>> = const { Cell::new(None) };
 }
 
-impl std::convert::From<JsPolarsErr> for napi::Error {
-    fn from(err: JsPolarsErr) -> napi::Error {
-        let reason = format!("{}", err);
+/// Called once by every JS thread that loads the bindings, so that the
+/// errors of exported functions can be thrown with their `code`.
+#[napi]
+pub fn init_errors(env: Env) {
+    JS_ENV.with(|js_env| js_env.set(Some(env.raw())));
+}
+
+/// Used by napi-derive to throw the error of an exported function returning
+/// [`js::Result`], right after it returned on the JS thread.
+impl From<JsPolarsErr> for JsError {
+    fn from(err: JsPolarsErr) -> JsError {
+        match JS_ENV.with(Cell::get) {
+            Some(env) => JsError::from(err.into_js(unsafe { Env::from_raw(env) })),
+            None => JsError::from(err.into_napi()),
+        }
+    }
+}
 
-        napi::Error::from_reason(reason)
+impl JsPolarsErr {
+    /// Convert into a plain napi error, without a `code`. Only for trait
+    /// impls that have to return a `napi::Result`.
+    pub fn into_napi(self) -> napi::Error {
+        match self {
+            JsPolarsErr::Napi(err) => err,
+            err => napi::Error::from_reason(err.to_string()),
+        }
     }
 }
@@ -1,14 +1,22 @@
-use napi::bindgen_prelude::{Buffer, Null};
 use napi::threadsafe_function::*;
-use napi::{Either, JsFunction, JsObject};
+use napi::{
+    CallContext, Env, JsBuffer, JsError, JsFunction, JsObject, JsUnknown, NapiRaw, NapiValue,
+    ValueType,
+};
 use std::io;
-use std::io::Write;
+use std::io::{Read, Write};
+use std::sync::mpsc::{sync_channel, SyncSender};
 
 pub struct JsFileLike<'a> {
     pub inner: JsObject,
     pub env: &'a napi::Env,
 }
 
+/// Writes synchronously to a JS object with a `write` method.
+///
+/// The JS thread is blocked while writing, so a `false` return from `write`
+/// cannot be waited on; use [`ThreadsafeWriteable`] for streams that need
+/// backpressure.
 pub struct JsWriteStream<'a> {
     pub inner: JsObject,
     pub env: &'a napi::Env,
@@ -25,17 +33,228 @@ impl<'a> JsWriteStream<'a> {
         JsWriteStream { inner, env }
     }
 }
+
+/// A chunk handed to the JS side of a [`ThreadsafeWriteable`], along with the
+/// channel its `done` callback reports back on.
+pub struct WriteRequest {
+    chunk: Vec<u8>,
+    done: SyncSender<io::Result<()>>,
+}
+
+/// Writes to a JS callback of the form `(chunk, done) => void` from a thread
+/// other than the JS thread.
+///
+/// Every write blocks until the callback invokes `done`, so a callback that
+/// waits for the stream's `'drain'` event before calling it applies Node's
+/// backpressure to the writer. Calling `done(err)`, or throwing, fails the
+/// write with `err`.
 pub struct ThreadsafeWriteable {
-    pub inner: ThreadsafeFunction<Either<Buffer, Null>, ErrorStrategy::CalleeHandled>,
+    pub inner: ThreadsafeFunction<WriteRequest, ErrorStrategy::Fatal>,
+}
+
+impl ThreadsafeWriteable {
+    pub fn new(env: &Env, callback: JsFunction) -> napi::Result<Self> {
+        let inner = catch_errors(env, callback)?.create_threadsafe_function(
+            0,
+            |ctx: ThreadSafeCallContext<WriteRequest>| {
+                let WriteRequest { chunk, done } = ctx.value;
+                let chunk = ctx.env.create_buffer_with_data(chunk)?.into_raw();
+                let done = ctx.env.create_function_from_closure("done", move |cx| {
+                    let result = match callback_error(&cx)? {
+                        Some(err) => Err(err),
+                        None => Ok(()),
+                    };
+                    // the writer may already have given up on this chunk, or
+                    // the callback may have thrown after calling `done`
+                    let _ = done.try_send(result);
+                    cx.env.get_undefined()
+                })?;
+                Ok(vec![chunk.into_unknown(), done.into_unknown()])
+            },
+        )?;
+        Ok(ThreadsafeWriteable { inner })
+    }
+}
+
+/// Pulls chunks from a JS callback of the form `(done) => void` on a thread
+/// other than the JS thread.
+///
+/// Each call to the callback must be answered with `done(null, chunk)` for the
+/// next `Buffer`, `done(null, null)` once the source is exhausted, or
+/// `done(err)` to fail the read, as does throwing. A new chunk is only requested once the
+/// previous one has been consumed, so the source is read at the pace of the
+/// reader.
+pub struct ThreadsafeReadable {
+    pub inner: ThreadsafeFunction<SyncSender<io::Result<Option<Vec<u8>>>>, ErrorStrategy::Fatal>,
+    chunk: Vec<u8>,
+    pos: usize,
+    eof: bool,
+}
+
+impl ThreadsafeReadable {
+    pub fn new(env: &Env, callback: JsFunction) -> napi::Result<Self> {
+        let inner = catch_errors(env, callback)?.create_threadsafe_function(
+            0,
+            |ctx: ThreadSafeCallContext<SyncSender<io::Result<Option<Vec<u8>>>>>| {
+                let next = ctx.value;
+                let done = ctx.env.create_function_from_closure("done", move |cx| {
+                    let result = match callback_error(&cx)? {
+                        Some(err) => Err(err),
+                        None if cx.length < 2 => Ok(None),
+                        None => {
+                            let chunk: JsUnknown = cx.get(1)?;
+                            match chunk.get_type()? {
+                                ValueType::Undefined | ValueType::Null => Ok(None),
+                                _ if chunk.is_buffer()? => {
+                                    let chunk: JsBuffer = unsafe { chunk.cast() };
+                                    Ok(Some(chunk.into_value()?.to_vec()))
+                                }
+                                _ => Err(io::Error::other("stream chunks must be Buffers")),
+                            }
+                        }
+                    };
+                    // the reader may already have given up on the stream, or
+                    // the callback may have thrown after calling `done`
+                    let _ = next.try_send(result);
+                    cx.env.get_undefined()
+                })?;
+                Ok(vec![done])
+            },
+        )?;
+        Ok(ThreadsafeReadable {
+            inner,
+            chunk: Vec::new(),
+            pos: 0,
+            eof: false,
+        })
+    }
+
+    fn next_chunk(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let (next, rx) = sync_channel(1);
+        match self.inner.call(next, ThreadsafeFunctionCallMode::Blocking) {
+            napi::Status::Ok => {}
+            status => {
+                return Err(io::Error::new(
+                    io::ErrorKind::BrokenPipe,
+                    format!("unable to call read stream: {}", status),
+                ))
+            }
+        }
+        rx.recv().map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "read stream was closed before the next chunk was read",
+            )
+        })?
+    }
+}
+
+impl Read for ThreadsafeReadable {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos == self.chunk.len() {
+            if self.eof {
+                return Ok(0);
+            }
+            match self.next_chunk()? {
+                Some(chunk) => {
+                    self.chunk = chunk;
+                    self.pos = 0;
+                }
+                None => self.eof = true,
+            }
+        }
+        let n = buf.len().min(self.chunk.len() - self.pos);
+        buf[..n].copy_from_slice(&self.chunk[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// Wraps `callback` so that an exception it throws is handed to the `done`
+/// callback it was given as its last argument.
+///
+/// Threadsafe functions report exceptions as uncaught, which would crash the
+/// process and leave the Rust side waiting for a `done` that never comes.
+fn catch_errors(env: &Env, callback: JsFunction) -> napi::Result<JsFunction> {
+    let wrapper = env.create_function_from_closure("catchErrors", |cx| {
+        let callback: JsFunction = cx.get(0)?;
+        let args = (1..cx.length)
+            .map(|idx| cx.get::<JsUnknown>(idx))
+            .collect::<napi::Result<Vec<_>>>()?;
+        if let Err(err) = callback.call(None, &args) {
+            let done: JsFunction = cx.get(cx.length - 1)?;
+            // hands over the thrown value itself when it is an Error
+            let err = unsafe {
+                let raw = JsError::from(err).into_value(cx.env.raw());
+                JsUnknown::from_raw_unchecked(cx.env.raw(), raw)
+            };
+            done.call(None, &[err])?;
+        }
+        cx.env.get_undefined()
+    })?;
+    // binding the callback as the first argument keeps it alive with the wrapper
+    let this = unsafe { JsObject::from_raw_unchecked(env.raw(), wrapper.raw()) };
+    let bind: JsFunction = this.get_named_property("bind")?;
+    let bound = bind.call(
+        Some(&this),
+        &[env.get_undefined()?.into_unknown(), callback.into_unknown()],
+    )?;
+    Ok(unsafe { bound.cast() })
+}
+
+/// Reads the error passed as the first argument of a node-style callback.
+fn callback_error(cx: &CallContext) -> napi::Result<Option<io::Error>> {
+    if cx.length == 0 {
+        return Ok(None);
+    }
+    let err: JsUnknown = cx.get(0)?;
+    match err.get_type()? {
+        ValueType::Undefined | ValueType::Null => Ok(None),
+        _ => Ok(Some(io::Error::other(js_error_message(err)?))),
+    }
+}
+
+/// Uses `err.message` for JS errors, and the string form of anything else.
+fn js_error_message(err: JsUnknown) -> napi::Result<String> {
+    if err.get_type()? == ValueType::Object {
+        let obj: JsObject = unsafe { err.cast() };
+        if obj.has_named_property("message")? {
+            let message: JsUnknown = obj.get_named_property("message")?;
+            return message.coerce_to_string()?.into_utf8()?.into_owned();
+        }
+    }
+    err.coerce_to_string()?.into_utf8()?.into_owned()
+}
+
+fn to_io_error(err: napi::Error) -> io::Error {
+    io::Error::other(err.reason)
 }
 
 impl Write for ThreadsafeWriteable {
     fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
-        let tsfn = self.inner.clone();
-        tsfn.call(
-            Ok(Either::A(buf.into())),
-            ThreadsafeFunctionCallMode::Blocking,
-        );
+        let (done, rx) = sync_channel(1);
+        let request = WriteRequest {
+            chunk: buf.to_vec(),
+            done,
+        };
+        match self
+            .inner
+            .call(request, ThreadsafeFunctionCallMode::Blocking)
+        {
+            napi::Status::Ok => {}
+            status => {
+                return Err(io::Error::new(
+                    io::ErrorKind::BrokenPipe,
+                    format!("unable to call write stream: {}", status),
+                ))
+            }
+        }
+        rx.recv().map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "write stream was closed before the chunk was written",
+            )
+        })??;
         Ok(buf.len())
     }
 
@@ -46,10 +265,16 @@ impl Write for ThreadsafeWriteable {
 }
 impl Write for JsFileLike<'_> {
     fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
-        let stream_write: JsFunction = self.inner.get_named_property("push").unwrap();
-        let bytes = self.env.create_buffer_with_data(buf.to_owned()).unwrap();
+        let stream_write: JsFunction =
+            self.inner.get_named_property("push").map_err(to_io_error)?;
+        let bytes = self
+            .env
+            .create_buffer_with_data(buf.to_owned())
+            .map_err(to_io_error)?;
         let js_buff = bytes.into_raw();
-        stream_write.call(Some(&self.inner), &[js_buff]).unwrap();
+        stream_write
+            .call(Some(&self.inner), &[js_buff])
+            .map_err(to_io_error)?;
         Ok(buf.len())
     }
 
@@ -61,10 +286,18 @@ impl Write for JsFileLike<'_> {
 
 impl Write for JsWriteStream<'_> {
     fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
-        let stream_write: JsFunction = self.inner.get_named_property("write").unwrap();
-        let bytes = self.env.create_buffer_with_data(buf.to_owned()).unwrap();
+        let stream_write: JsFunction = self
+            .inner
+            .get_named_property("write")
+            .map_err(to_io_error)?;
+        let bytes = self
+            .env
+            .create_buffer_with_data(buf.to_owned())
+            .map_err(to_io_error)?;
         let js_buff = bytes.into_raw();
-        stream_write.call(Some(&self.inner), &[js_buff]).unwrap();
+        stream_write
+            .call(Some(&self.inner), &[js_buff])
+            .map_err(to_io_error)?;
         Ok(buf.len())
     }
 
@@ -1,4 +1,5 @@
 use crate::dataframe::*;
+use crate::error::{js, JsPolarsErr};
 use crate::export::JsLazyFrame;
 use crate::lazy::dsl::JsExpr;
 use polars::prelude::{
@@ -7,16 +8,16 @@ use polars::prelude::{
 use polars_core::functions as pl_functions;
 
 #[napi(catch_unwind)]
-pub fn horizontal_concat(dfs: Vec<&JsDataFrame>) -> napi::Result<JsDataFrame> {
+pub fn horizontal_concat(dfs: Vec<&JsDataFrame>) -> js::Result<JsDataFrame> {
     let dfs: Vec<DataFrame> = dfs.iter().map(|df| df.df.clone()).collect();
-    let df = pl_functions::concat_df_horizontal(&dfs).map_err(crate::error::JsPolarsErr::from)?;
+    let df = pl_functions::concat_df_horizontal(&dfs).map_err(JsPolarsErr::from)?;
     Ok(df.into())
 }
 
 #[napi(catch_unwind)]
-pub fn diagonal_concat(dfs: Vec<&JsDataFrame>) -> napi::Result<JsDataFrame> {
+pub fn diagonal_concat(dfs: Vec<&JsDataFrame>) -> js::Result<JsDataFrame> {
     let dfs: Vec<DataFrame> = dfs.iter().map(|df| df.df.clone()).collect();
-    let df = pl_functions::concat_df_diagonal(&dfs).map_err(crate::error::JsPolarsErr::from)?;
+    let df = pl_functions::concat_df_diagonal(&dfs).map_err(JsPolarsErr::from)?;
     Ok(df.into())
 }
 
@@ -25,7 +26,7 @@ pub fn concat_lf(
     ldfs: Vec<&JsLazyFrame>,
     how: Option<String>,
     rechunk: Option<bool>,
-) -> napi::Result<JsLazyFrame> {
+) -> js::Result<JsLazyFrame> {
     let ldfs: Vec<LazyFrame> = ldfs.iter().map(|ldf| ldf.ldf.clone()).collect();
 
     let union_args = UnionArgs {
@@ -45,13 +46,13 @@ pub fn concat_lf(
             },
         ),
         Some(unknown) => {
-            return Err(napi::Error::from_reason(format!(
+            return Err(JsPolarsErr::Other(format!(
                 "Unknown concat method: {}",
                 unknown
             )))
         }
     }
-    .map_err(crate::error::JsPolarsErr::from)?;
+    .map_err(JsPolarsErr::from)?;
 
     Ok(ldf.into())
 }
@@ -0,0 +1,771 @@
+//! Exchange of Arrow arrays with JS.
+//!
+//! Arrays are passed as plain objects shaped like apache-arrow's `Data`
+//! (`type`, `length`, `offset`, `nullCount`, `nullBitmap`, `valueOffsets`,
+//! `values`, `children`), and types like its `DataType`, keyed by the `Type`
+//! enum in `typeId`. This lets apache-arrow tables be read directly. Our
+//! output has plain types, which apache-arrow needs rebuilt as its `DataType`
+//! classes before the arrays can be handed to `makeData`.
+//!
+//! Exported buffers are external `ArrayBuffer`s over the Arrow memory, which
+//! is kept alive until the JS side is garbage collected. Imported buffers are
+//! copied, as JS memory cannot be held on to outside of the JS thread.
+use crate::prelude::*;
+use napi::{JsObject, JsTypedArray, JsTypedArrayValue, JsUnknown, TypedArrayType, ValueType};
+use polars::export::arrow::array::{
+    Array, ArrayRef, BinaryArray, BooleanArray, FixedSizeListArray, ListArray, NullArray,
+    PrimitiveArray, StructArray, Utf8Array,
+};
+use polars::export::arrow::bitmap::Bitmap;
+use polars::export::arrow::buffer::Buffer;
+use polars::export::arrow::datatypes::{ArrowDataType, Field as ArrowField, TimeUnit};
+use polars::export::arrow::offset::{Offset, OffsetsBuffer};
+use polars::export::arrow::types::NativeType;
+
+// apache-arrow `Type` ids
+const TYPE_NULL: i32 = 1;
+const TYPE_INT: i32 = 2;
+const TYPE_FLOAT: i32 = 3;
+const TYPE_BINARY: i32 = 4;
+const TYPE_UTF8: i32 = 5;
+const TYPE_BOOL: i32 = 6;
+const TYPE_DECIMAL: i32 = 7;
+const TYPE_DATE: i32 = 8;
+const TYPE_TIME: i32 = 9;
+const TYPE_TIMESTAMP: i32 = 10;
+const TYPE_LIST: i32 = 12;
+const TYPE_STRUCT: i32 = 13;
+const TYPE_FIXED_SIZE_LIST: i32 = 16;
+const TYPE_DURATION: i32 = 18;
+const TYPE_LARGE_BINARY: i32 = 19;
+const TYPE_LARGE_UTF8: i32 = 20;
+
+/// Converts each chunk of `s` into a `Data` object, returning them with the
+/// `Field` describing them.
+pub(crate) fn series_to_arrow(env: &Env, s: &Series) -> JsPolarsResult<(JsObject, Vec<JsObject>)> {
+    let s = match s.dtype() {
+        // apache-arrow's dictionaries are not worth the round trip
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+            s.cast(&DataType::String).map_err(JsPolarsErr::from)?
+        }
+        DataType::Object(_, _) => {
+            return Err(JsPolarsErr::Other(format!(
+                "cannot convert Object column '{}' to arrow",
+                s.name()
+            )))
+        }
+        _ => s.clone(),
+    };
+    let dtype = export_type(&s.dtype().to_arrow(false));
+    let field = field_to_js(env, &ArrowField::new(s.name(), dtype, true))?;
+    let data = (0..s.n_chunks())
+        .map(|idx| array_to_js(env, s.to_arrow(idx, false).as_ref()))
+        .collect::<JsPolarsResult<Vec<_>>>()?;
+    Ok((field, data))
+}
+
+/// Builds a series of arrow type `dtype` from the `Data` objects in `data`,
+/// one chunk per object.
+pub(crate) fn arrow_to_series(
+    name: &str,
+    dtype: &JsObject,
+    data: &[JsObject],
+) -> JsPolarsResult<Series> {
+    let chunks = data
+        .iter()
+        .map(array_from_js)
+        .collect::<JsPolarsResult<Vec<_>>>()?;
+    if chunks.is_empty() {
+        let dtype = DataType::from(&type_from_js(dtype)?);
+        return Ok(Series::new_empty(name, &dtype));
+    }
+    let s = Series::try_from((name, chunks)).map_err(JsPolarsErr::from)?;
+    Ok(s)
+}
+
+/// Converts `df` into an apache-arrow `Table`-like object of the form
+/// `{ schema: { fields }, batches: [{ length, data }] }`, with a batch per chunk.
+pub(crate) fn dataframe_to_arrow(env: &Env, df: &DataFrame) -> JsPolarsResult<JsObject> {
+    let mut df = df.clone();
+    df.align_chunks();
+    let lengths: Vec<usize> = match df.get_columns().first() {
+        Some(s) => s.chunk_lengths().collect(),
+        None => vec![],
+    };
+    let mut fields = Vec::with_capacity(df.width());
+    let mut children: Vec<Vec<JsObject>> = lengths.iter().map(|_| vec![]).collect();
+    for s in df.get_columns() {
+        let (field, data) = series_to_arrow(env, s)?;
+        fields.push(field);
+        for (batch, data) in children.iter_mut().zip(data) {
+            batch.push(data);
+        }
+    }
+    let struct_type = ArrowDataType::Struct(
+        df.get_columns()
+            .iter()
+            .map(|s| ArrowField::new(s.name(), export_type(&s.dtype().to_arrow(false)), true))
+            .collect(),
+    );
+    let mut batches = Vec::with_capacity(lengths.len());
+    for (len, children) in lengths.into_iter().zip(children) {
+        let mut data = env.create_object()?;
+        data.set("type", type_to_js(env, &struct_type)?)?;
+        data.set("length", len as u32)?;
+        data.set("offset", 0)?;
+        data.set("nullCount", 0)?;
+        data.set("children", children)?;
+        let mut batch = env.create_object()?;
+        batch.set("length", len as u32)?;
+        batch.set("data", data)?;
+        batches.push(batch);
+    }
+    let mut schema = env.create_object()?;
+    schema.set("fields", fields)?;
+    let mut table = env.create_object()?;
+    table.set("schema", schema)?;
+    table.set("batches", batches)?;
+    Ok(table)
+}
+
+/// Reads an apache-arrow `Table`, or an object of the same shape.
+pub(crate) fn arrow_to_dataframe(table: &JsObject) -> JsPolarsResult<DataFrame> {
+    let schema: JsObject = table.get_named_property("schema")?;
+    let fields: Vec<JsObject> = schema.get_named_property("fields")?;
+    let batches: Vec<JsObject> = table.get("batches")?.unwrap_or_default();
+    let mut columns: Vec<Vec<JsObject>> = fields.iter().map(|_| vec![]).collect();
+    for batch in batches {
+        let data: JsObject = batch.get_named_property("data")?;
+        let children: Vec<JsObject> = data.get_named_property("children")?;
+        if children.len() != fields.len() {
+            return Err(JsPolarsErr::Other(format!(
+                "record batch has {} columns, but the schema has {}",
+                children.len(),
+                fields.len()
+            )));
+        }
+        for (column, child) in columns.iter_mut().zip(children) {
+            column.push(child);
+        }
+    }
+    let columns = fields
+        .iter()
+        .zip(columns)
+        .map(|(field, data)| {
+            let name: String = field.get("name")?.unwrap_or_default();
+            arrow_to_series(&name, &field.get_named_property("type")?, &data)
+        })
+        .collect::<JsPolarsResult<Vec<_>>>()?;
+    let df = DataFrame::new(columns).map_err(JsPolarsErr::from)?;
+    Ok(df)
+}
+
+/// apache-arrow has no large lists, so they are exported as lists.
+fn export_type(dtype: &ArrowDataType) -> ArrowDataType {
+    match dtype {
+        ArrowDataType::LargeList(f) | ArrowDataType::List(f) => {
+            ArrowDataType::List(Box::new(export_field(f)))
+        }
+        ArrowDataType::FixedSizeList(f, size) => {
+            ArrowDataType::FixedSizeList(Box::new(export_field(f)), *size)
+        }
+        ArrowDataType::Struct(fields) => {
+            ArrowDataType::Struct(fields.iter().map(export_field).collect())
+        }
+        dt => dt.clone(),
+    }
+}
+
+fn export_field(field: &ArrowField) -> ArrowField {
+    ArrowField::new(
+        &field.name,
+        export_type(&field.data_type),
+        field.is_nullable,
+    )
+}
+
+fn time_unit_to_js(unit: &TimeUnit) -> u32 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 1,
+        TimeUnit::Microsecond => 2,
+        TimeUnit::Nanosecond => 3,
+    }
+}
+
+fn time_unit_from_js(unit: u32) -> JsPolarsResult<TimeUnit> {
+    match unit {
+        0 => Ok(TimeUnit::Second),
+        1 => Ok(TimeUnit::Millisecond),
+        2 => Ok(TimeUnit::Microsecond),
+        3 => Ok(TimeUnit::Nanosecond),
+        u => Err(JsPolarsErr::Other(format!("unknown arrow time unit {}", u))),
+    }
+}
+
+fn field_to_js(env: &Env, field: &ArrowField) -> JsPolarsResult<JsObject> {
+    let mut obj = env.create_object()?;
+    obj.set("name", field.name.as_str())?;
+    obj.set("type", type_to_js(env, &field.data_type)?)?;
+    obj.set("nullable", field.is_nullable)?;
+    Ok(obj)
+}
+
+fn type_to_js(env: &Env, dtype: &ArrowDataType) -> JsPolarsResult<JsObject> {
+    use ArrowDataType::*;
+    let mut obj = env.create_object()?;
+    let int = |obj: &mut JsObject, bit_width: u32, is_signed: bool| -> napi::Result<i32> {
+        obj.set("bitWidth", bit_width)?;
+        obj.set("isSigned", is_signed)?;
+        Ok(TYPE_INT)
+    };
+    let type_id = match dtype {
+        Null => TYPE_NULL,
+        Boolean => TYPE_BOOL,
+        Int8 => int(&mut obj, 8, true)?,
+        Int16 => int(&mut obj, 16, true)?,
+        Int32 => int(&mut obj, 32, true)?,
+        Int64 => int(&mut obj, 64, true)?,
+        UInt8 => int(&mut obj, 8, false)?,
+        UInt16 => int(&mut obj, 16, false)?,
+        UInt32 => int(&mut obj, 32, false)?,
+        UInt64 => int(&mut obj, 64, false)?,
+        Float32 | Float64 => {
+            obj.set("precision", if dtype == &Float32 { 1 } else { 2 })?;
+            TYPE_FLOAT
+        }
+        Decimal(precision, scale) => {
+            obj.set("precision", *precision as u32)?;
+            obj.set("scale", *scale as u32)?;
+            obj.set("bitWidth", 128)?;
+            TYPE_DECIMAL
+        }
+        Date32 | Date64 => {
+            obj.set("unit", if dtype == &Date32 { 0 } else { 1 })?;
+            TYPE_DATE
+        }
+        Time32(unit) | Time64(unit) => {
+            obj.set("unit", time_unit_to_js(unit))?;
+            obj.set("bitWidth", if matches!(dtype, Time32(_)) { 32 } else { 64 })?;
+            TYPE_TIME
+        }
+        Timestamp(unit, tz) => {
+            obj.set("unit", time_unit_to_js(unit))?;
+            obj.set("timezone", tz.as_deref())?;
+            TYPE_TIMESTAMP
+        }
+        Duration(unit) => {
+            obj.set("unit", time_unit_to_js(unit))?;
+            TYPE_DURATION
+        }
+        Binary => TYPE_BINARY,
+        LargeBinary => TYPE_LARGE_BINARY,
+        Utf8 => TYPE_UTF8,
+        LargeUtf8 => TYPE_LARGE_UTF8,
+        List(field) => {
+            obj.set("children", vec![field_to_js(env, field)?])?;
+            TYPE_LIST
+        }
+        FixedSizeList(field, size) => {
+            obj.set("listSize", *size as u32)?;
+            obj.set("children", vec![field_to_js(env, field)?])?;
+            TYPE_FIXED_SIZE_LIST
+        }
+        Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|f| field_to_js(env, f))
+                .collect::<JsPolarsResult<Vec<_>>>()?;
+            obj.set("children", fields)?;
+            TYPE_STRUCT
+        }
+        dt => {
+            return Err(JsPolarsErr::Other(format!(
+                "cannot convert arrow type {:?} to JS",
+                dt
+            )))
+        }
+    };
+    obj.set("typeId", type_id)?;
+    Ok(obj)
+}
+
+fn field_from_js(obj: &JsObject) -> JsPolarsResult<ArrowField> {
+    let name: String = obj.get("name")?.unwrap_or_default();
+    let dtype = type_from_js(&obj.get_named_property("type")?)?;
+    let nullable: bool = obj.get("nullable")?.unwrap_or(true);
+    Ok(ArrowField::new(&name, dtype, nullable))
+}
+
+fn child_fields_from_js(obj: &JsObject) -> JsPolarsResult<Vec<ArrowField>> {
+    let children: Vec<JsObject> = obj.get("children")?.unwrap_or_default();
+    children.iter().map(field_from_js).collect()
+}
+
+fn type_from_js(obj: &JsObject) -> JsPolarsResult<ArrowDataType> {
+    use ArrowDataType::*;
+    let type_id: i32 = obj.get_named_property("typeId")?;
+    let prop = |key: &str| -> JsPolarsResult<u32> {
+        obj.get::<_, u32>(key)?.ok_or_else(|| {
+            JsPolarsErr::Other(format!("arrow type {} is missing '{}'", type_id, key))
+        })
+    };
+    let dtype = match type_id {
+        TYPE_NULL => Null,
+        TYPE_BOOL => Boolean,
+        TYPE_INT => {
+            let is_signed: bool = obj.get("isSigned")?.unwrap_or(true);
+            match (prop("bitWidth")?, is_signed) {
+                (8, true) => Int8,
+                (16, true) => Int16,
+                (32, true) => Int32,
+                (64, true) => Int64,
+                (8, false) => UInt8,
+                (16, false) => UInt16,
+                (32, false) => UInt32,
+                (64, false) => UInt64,
+                (bits, _) => {
+                    return Err(JsPolarsErr::Other(format!(
+                        "unsupported integer width {}",
+                        bits
+                    )))
+                }
+            }
+        }
+        TYPE_FLOAT => match prop("precision")? {
+            1 => Float32,
+            2 => Float64,
+            _ => return Err(JsPolarsErr::Other("half floats are not supported".into())),
+        },
+        TYPE_DECIMAL => match prop("bitWidth").unwrap_or(128) {
+            128 => Decimal(prop("precision")? as usize, prop("scale")? as usize),
+            bits => {
+                return Err(JsPolarsErr::Other(format!(
+                    "unsupported decimal width {}",
+                    bits
+                )))
+            }
+        },
+        TYPE_DATE => match prop("unit")? {
+            0 => Date32,
+            _ => Date64,
+        },
+        TYPE_TIME => {
+            let unit = time_unit_from_js(prop("unit")?)?;
+            match prop("bitWidth")? {
+                32 => Time32(unit),
+                _ => Time64(unit),
+            }
+        }
+        TYPE_TIMESTAMP => {
+            let tz: Option<String> = match obj.get::<_, JsUnknown>("timezone")? {
+                Some(tz) if tz.get_type()? == ValueType::String => {
+                    Some(tz.coerce_to_string()?.into_utf8()?.into_owned()?)
+                }
+                _ => None,
+            };
+            Timestamp(time_unit_from_js(prop("unit")?)?, tz)
+        }
+        TYPE_DURATION => Duration(time_unit_from_js(prop("unit")?)?),
+        TYPE_BINARY => Binary,
+        TYPE_LARGE_BINARY => LargeBinary,
+        TYPE_UTF8 => Utf8,
+        TYPE_LARGE_UTF8 => LargeUtf8,
+        TYPE_LIST | TYPE_FIXED_SIZE_LIST => {
+            let field = child_fields_from_js(obj)?
+                .pop()
+                .ok_or_else(|| JsPolarsErr::Other("list type is missing its child".into()))?;
+            if type_id == TYPE_LIST {
+                List(Box::new(field))
+            } else {
+                FixedSizeList(Box::new(field), prop("listSize")? as usize)
+            }
+        }
+        TYPE_STRUCT => Struct(child_fields_from_js(obj)?),
+        id => {
+            return Err(JsPolarsErr::Other(format!(
+                "unsupported arrow type id {}",
+                id
+            )))
+        }
+    };
+    Ok(dtype)
+}
+
+/// Wraps `len` elements of memory owned by `owner` in a typed array, without
+/// copying unless the memory is already viewed from JS.
+fn external_typed_array<O: 'static>(
+    env: &Env,
+    owner: O,
+    ptr: *const u8,
+    len: usize,
+    kind: TypedArrayType,
+) -> napi::Result<JsTypedArray> {
+    let byte_len = len * typed_array_element_size(kind);
+    let arraybuffer = match LentMemory::lend(ptr) {
+        // SAFETY: the memory is immutable, and is kept alive by `owner` until the
+        // ArrayBuffer is finalized.
+        Some(lent) => unsafe {
+            env.create_arraybuffer_with_borrowed_data(
+                ptr as *mut u8,
+                byte_len,
+                (owner, lent),
+                |owner, _| drop(owner),
+            )?
+        },
+        None => {
+            let bytes = unsafe { std::slice::from_raw_parts(ptr, byte_len) };
+            env.create_arraybuffer_with_data(bytes.to_vec())?
+        }
+    };
+    arraybuffer.into_raw().into_typedarray(kind, len, 0)
+}
+
+fn buffer_to_js<T: NativeType>(
+    env: &Env,
+    buffer: &Buffer<T>,
+    kind: TypedArrayType,
+) -> napi::Result<JsTypedArray> {
+    let len = buffer.len() * std::mem::size_of::<T>() / typed_array_element_size(kind);
+    external_typed_array(env, buffer.clone(), buffer.as_ptr() as *const u8, len, kind)
+}
+
+/// Bitmaps are exported without a bit offset, so are only shared when they start on bit 0.
+fn bitmap_to_js(env: &Env, bitmap: &Bitmap) -> napi::Result<JsTypedArray> {
+    let bitmap = match bitmap.as_slice() {
+        (_, 0, _) => bitmap.clone(),
+        _ => Bitmap::from_iter(bitmap.iter()),
+    };
+    let (bytes, _, len) = bitmap.as_slice();
+    let ptr = bytes.as_ptr();
+    external_typed_array(
+        env,
+        bitmap.clone(),
+        ptr,
+        len.div_ceil(8),
+        TypedArrayType::Uint8,
+    )
+}
+
+fn typed_array_element_size(kind: TypedArrayType) -> usize {
+    match kind {
+        TypedArrayType::Int8 | TypedArrayType::Uint8 | TypedArrayType::Uint8Clamped => 1,
+        TypedArrayType::Int16 | TypedArrayType::Uint16 => 2,
+        TypedArrayType::Int32 | TypedArrayType::Uint32 | TypedArrayType::Float32 => 4,
+        _ => 8,
+    }
+}
+
+fn primitive_to_js<T: NativeType>(
+    env: &Env,
+    arr: &dyn Array,
+    kind: TypedArrayType,
+) -> napi::Result<JsTypedArray> {
+    let arr = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    buffer_to_js(env, arr.values(), kind)
+}
+
+fn offsets_to_js<O: Offset>(env: &Env, offsets: &OffsetsBuffer<O>) -> napi::Result<JsTypedArray> {
+    let kind = if O::IS_LARGE {
+        TypedArrayType::BigInt64
+    } else {
+        TypedArrayType::Int32
+    };
+    buffer_to_js(env, offsets.buffer(), kind)
+}
+
+fn array_to_js(env: &Env, arr: &dyn Array) -> JsPolarsResult<JsObject> {
+    use ArrowDataType::*;
+    let dtype = export_type(arr.data_type());
+    let mut data = env.create_object()?;
+    data.set("type", type_to_js(env, &dtype)?)?;
+    data.set("length", arr.len() as u32)?;
+    data.set("offset", 0)?;
+    data.set("nullCount", arr.null_count() as u32)?;
+    if let Some(validity) = arr.validity() {
+        data.set("nullBitmap", bitmap_to_js(env, validity)?)?;
+    }
+    let mut children = vec![];
+    match arr.data_type() {
+        Null => {}
+        Boolean => {
+            let arr = arr.as_any().downcast_ref::<BooleanArray>().unwrap();
+            data.set("values", bitmap_to_js(env, arr.values())?)?;
+        }
+        Int8 => data.set(
+            "values",
+            primitive_to_js::<i8>(env, arr, TypedArrayType::Int8)?,
+        )?,
+        Int16 => data.set(
+            "values",
+            primitive_to_js::<i16>(env, arr, TypedArrayType::Int16)?,
+        )?,
+        Int32 | Date32 | Time32(_) => data.set(
+            "values",
+            primitive_to_js::<i32>(env, arr, TypedArrayType::Int32)?,
+        )?,
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => data.set(
+            "values",
+            primitive_to_js::<i64>(env, arr, TypedArrayType::BigInt64)?,
+        )?,
+        UInt8 => data.set(
+            "values",
+            primitive_to_js::<u8>(env, arr, TypedArrayType::Uint8)?,
+        )?,
+        UInt16 => data.set(
+            "values",
+            primitive_to_js::<u16>(env, arr, TypedArrayType::Uint16)?,
+        )?,
+        UInt32 => data.set(
+            "values",
+            primitive_to_js::<u32>(env, arr, TypedArrayType::Uint32)?,
+        )?,
+        UInt64 => data.set(
+            "values",
+            primitive_to_js::<u64>(env, arr, TypedArrayType::BigUint64)?,
+        )?,
+        Float32 => data.set(
+            "values",
+            primitive_to_js::<f32>(env, arr, TypedArrayType::Float32)?,
+        )?,
+        Float64 => data.set(
+            "values",
+            primitive_to_js::<f64>(env, arr, TypedArrayType::Float64)?,
+        )?,
+        // apache-arrow reads decimals as little endian 32 bit words
+        Decimal(_, _) => data.set(
+            "values",
+            primitive_to_js::<i128>(env, arr, TypedArrayType::Uint32)?,
+        )?,
+        Utf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            data.set("valueOffsets", offsets_to_js(env, arr.offsets())?)?;
+            data.set(
+                "values",
+                buffer_to_js(env, arr.values(), TypedArrayType::Uint8)?,
+            )?;
+        }
+        LargeUtf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            data.set("valueOffsets", offsets_to_js(env, arr.offsets())?)?;
+            data.set(
+                "values",
+                buffer_to_js(env, arr.values(), TypedArrayType::Uint8)?,
+            )?;
+        }
+        Binary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            data.set("valueOffsets", offsets_to_js(env, arr.offsets())?)?;
+            data.set(
+                "values",
+                buffer_to_js(env, arr.values(), TypedArrayType::Uint8)?,
+            )?;
+        }
+        LargeBinary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            data.set("valueOffsets", offsets_to_js(env, arr.offsets())?)?;
+            data.set(
+                "values",
+                buffer_to_js(env, arr.values(), TypedArrayType::Uint8)?,
+            )?;
+        }
+        List(_) => {
+            let arr = arr.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            data.set("valueOffsets", offsets_to_js(env, arr.offsets())?)?;
+            children.push(array_to_js(env, arr.values().as_ref())?);
+        }
+        LargeList(_) => {
+            let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let offsets = arr
+                .offsets()
+                .iter()
+                .map(|o| i32::try_from(*o))
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|_| JsPolarsErr::Other("list is too large to export".into()))?;
+            let offsets: Buffer<i32> = offsets.into();
+            data.set(
+                "valueOffsets",
+                buffer_to_js(env, &offsets, TypedArrayType::Int32)?,
+            )?;
+            children.push(array_to_js(env, arr.values().as_ref())?);
+        }
+        FixedSizeList(_, _) => {
+            let arr = arr.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            children.push(array_to_js(env, arr.values().as_ref())?);
+        }
+        Struct(_) => {
+            let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+            for child in arr.values() {
+                children.push(array_to_js(env, child.as_ref())?);
+            }
+        }
+        dt => {
+            return Err(JsPolarsErr::Other(format!(
+                "cannot convert arrow type {:?} to JS",
+                dt
+            )))
+        }
+    }
+    data.set("children", children)?;
+    Ok(data)
+}
+
+/// Copies the first `len` bytes viewed by a typed array.
+fn typed_array_bytes(value: JsTypedArrayValue, len: usize) -> JsPolarsResult<Vec<u8>> {
+    let byte_len = value.length * typed_array_element_size(value.typedarray_type);
+    if len > byte_len {
+        return Err(JsPolarsErr::Other(
+            "arrow buffer is shorter than its length".into(),
+        ));
+    }
+    let offset = value.byte_offset;
+    let arraybuffer = value.arraybuffer.into_value()?;
+    Ok(arraybuffer[offset..offset + len].to_vec())
+}
+
+/// Reads the typed array under `key`, if it is present and not empty.
+fn buffer_from_js(data: &JsObject, key: &str) -> napi::Result<Option<JsTypedArrayValue>> {
+    let value: Option<JsUnknown> = data.get(key)?;
+    match value {
+        Some(value) if value.is_typedarray()? => {
+            let value = unsafe { value.cast::<JsTypedArray>() }.into_value()?;
+            Ok((value.length > 0).then_some(value))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn required_buffer(data: &JsObject, key: &str) -> JsPolarsResult<JsTypedArrayValue> {
+    buffer_from_js(data, key)?
+        .ok_or_else(|| JsPolarsErr::Other(format!("arrow data is missing '{}'", key)))
+}
+
+fn values_from_js<T: NativeType>(
+    data: &JsObject,
+    key: &str,
+    len: usize,
+) -> JsPolarsResult<Buffer<T>> {
+    if len == 0 {
+        return Ok(Buffer::default());
+    }
+    let value = required_buffer(data, key)?;
+    let bytes = typed_array_bytes(value, len * std::mem::size_of::<T>())?;
+    let values: Vec<T> = bytes
+        .chunks_exact(std::mem::size_of::<T>())
+        .map(|chunk| T::from_le_bytes(chunk.try_into().ok().unwrap()))
+        .collect();
+    Ok(values.into())
+}
+
+fn bitmap_from_js(
+    data: &JsObject,
+    key: &str,
+    offset: usize,
+    len: usize,
+) -> JsPolarsResult<Option<Bitmap>> {
+    match buffer_from_js(data, key)? {
+        Some(value) => {
+            let bytes = typed_array_bytes(value, (offset + len).div_ceil(8))?;
+            let bitmap = Bitmap::try_new(bytes, offset + len).map_err(JsPolarsErr::from)?;
+            Ok(Some(bitmap.sliced(offset, len)))
+        }
+        None => Ok(None),
+    }
+}
+
+fn offsets_from_js<O: Offset>(data: &JsObject, len: usize) -> JsPolarsResult<OffsetsBuffer<O>> {
+    let offsets: Vec<O> = values_from_js::<O>(data, "valueOffsets", len + 1)?.to_vec();
+    let offsets = OffsetsBuffer::try_from(offsets).map_err(JsPolarsErr::from)?;
+    Ok(offsets)
+}
+
+fn children_from_js(data: &JsObject) -> JsPolarsResult<Vec<ArrayRef>> {
+    let children: Vec<JsObject> = data.get("children")?.unwrap_or_default();
+    children.iter().map(array_from_js).collect()
+}
+
+fn first_child_from_js(data: &JsObject) -> JsPolarsResult<ArrayRef> {
+    children_from_js(data)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| JsPolarsErr::Other("arrow data is missing its child".into()))
+}
+
+/// Reads an apache-arrow style `Data` object. As in apache-arrow, `values` and
+/// `valueOffsets` start at `offset`, while bitmaps are indexed from bit `offset`.
+fn array_from_js(data: &JsObject) -> JsPolarsResult<ArrayRef> {
+    use ArrowDataType::*;
+    let dtype = type_from_js(&data.get_named_property("type")?)?;
+    let len = data.get::<_, u32>("length")?.unwrap_or(0) as usize;
+    let offset = data.get::<_, u32>("offset")?.unwrap_or(0) as usize;
+    let null_count = data.get::<_, i64>("nullCount")?.unwrap_or(-1);
+    let validity = match null_count {
+        0 => None,
+        _ => bitmap_from_js(data, "nullBitmap", offset, len)?,
+    };
+    macro_rules! primitive {
+        ($T:ty) => {
+            PrimitiveArray::<$T>::try_new(dtype, values_from_js(data, "values", len)?, validity)
+                .map(|arr| arr.boxed())
+        };
+    }
+    let arr = match &dtype {
+        Null => Ok(NullArray::new(dtype, len).boxed()),
+        Boolean => {
+            let values = match bitmap_from_js(data, "values", offset, len)? {
+                Some(values) => values,
+                None => Bitmap::new_zeroed(len),
+            };
+            BooleanArray::try_new(dtype, values, validity).map(|arr| arr.boxed())
+        }
+        Int8 => primitive!(i8),
+        Int16 => primitive!(i16),
+        Int32 | Date32 | Time32(_) => primitive!(i32),
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => primitive!(i64),
+        UInt8 => primitive!(u8),
+        UInt16 => primitive!(u16),
+        UInt32 => primitive!(u32),
+        UInt64 => primitive!(u64),
+        Float32 => primitive!(f32),
+        Float64 => primitive!(f64),
+        Decimal(_, _) => primitive!(i128),
+        Utf8 | Binary => {
+            let offsets = offsets_from_js::<i32>(data, len)?;
+            let values = values_from_js(data, "values", *offsets.last() as usize)?;
+            match dtype {
+                Utf8 => Utf8Array::try_new(dtype, offsets, values, validity).map(|arr| arr.boxed()),
+                _ => BinaryArray::try_new(dtype, offsets, values, validity).map(|arr| arr.boxed()),
+            }
+        }
+        LargeUtf8 | LargeBinary => {
+            let offsets = offsets_from_js::<i64>(data, len)?;
+            let values = values_from_js(data, "values", *offsets.last() as usize)?;
+            match dtype {
+                LargeUtf8 => {
+                    Utf8Array::try_new(dtype, offsets, values, validity).map(|arr| arr.boxed())
+                }
+                _ => BinaryArray::try_new(dtype, offsets, values, validity).map(|arr| arr.boxed()),
+            }
+        }
+        List(_) => {
+            let offsets = offsets_from_js::<i32>(data, len)?;
+            let values = first_child_from_js(data)?;
+            ListArray::try_new(dtype, offsets, values, validity).map(|arr| arr.boxed())
+        }
+        FixedSizeList(_, _) => {
+            let values = first_child_from_js(data)?;
+            FixedSizeListArray::try_new(dtype, values, validity).map(|arr| arr.boxed())
+        }
+        Struct(_) => {
+            let values = children_from_js(data)?;
+            StructArray::try_new(dtype, values, validity).map(|arr| arr.boxed())
+        }
+        dt => {
+            return Err(JsPolarsErr::Other(format!(
+                "unsupported arrow type {:?}",
+                dt
+            )))
+        }
+    };
+    arr.map_err(JsPolarsErr::from)
+}
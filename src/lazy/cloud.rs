@@ -0,0 +1,169 @@
+//! The csv and ndjson readers of this polars version can only read local files.
+//! Remote objects are therefore downloaded when the query runs, one at a time,
+//! into temporary files that are removed as soon as they have been read.
+use crate::prelude::*;
+use polars_core::error::to_compute_err;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::cloud::{build_object_store, glob, CloudOptions, PolarsObjectStore};
+use polars_io::pl_async::get_runtime;
+use std::any::Any;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Objects are fetched in ranges of this size, so they never have to fit in memory.
+const CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Builds the local scan of a downloaded file, with the schema once it is known.
+pub(crate) type ScanLocal =
+    Box<dyn Fn(&Path, Option<SchemaRef>) -> PolarsResult<LazyFrame> + Send + Sync>;
+
+pub(crate) struct RemoteScan {
+    url: String,
+    cloud_options: Option<CloudOptions>,
+    /// Lines of the first object needed to infer the schema, `None` for all of them.
+    infer_lines: Option<usize>,
+    scan_local: ScanLocal,
+}
+
+impl RemoteScan {
+    pub(crate) fn new(
+        url: String,
+        cloud_options: Option<CloudOptions>,
+        infer_lines: Option<usize>,
+        scan_local: ScanLocal,
+    ) -> Self {
+        Self {
+            url,
+            cloud_options,
+            infer_lines,
+            scan_local,
+        }
+    }
+
+    fn urls(&self) -> PolarsResult<Vec<String>> {
+        if !is_glob(&self.url) {
+            return Ok(vec![self.url.clone()]);
+        }
+        let urls = glob(&self.url, self.cloud_options.as_ref())?;
+        polars_ensure!(!urls.is_empty(), ComputeError: "no objects match '{}'", self.url);
+        Ok(urls)
+    }
+
+    /// Download `url` to `file`. With `max_lines`, stop after the first complete line past it.
+    fn download(&self, url: &str, file: &Path, max_lines: Option<usize>) -> PolarsResult<()> {
+        let mut out = File::create(file).map_err(to_compute_err)?;
+        get_runtime().block_on(async {
+            let (location, store) = build_object_store(url, self.cloud_options.as_ref()).await?;
+            let store = PolarsObjectStore::new(store);
+            let path = location.prefix.into();
+            let size = store.head(&path).await?.size;
+            let mut lines = 0;
+            let mut offset = 0;
+            while offset < size {
+                let end = usize::min(offset + CHUNK_SIZE, size);
+                let bytes = store.get_range(&path, offset..end).await?;
+                offset = end;
+                if let Some(max_lines) = max_lines {
+                    lines += bytes.iter().filter(|b| **b == b'\n').count();
+                    if lines > max_lines {
+                        let last_line = bytes.iter().rposition(|b| *b == b'\n').unwrap();
+                        out.write_all(&bytes[..=last_line])
+                            .map_err(to_compute_err)?;
+                        break;
+                    }
+                }
+                out.write_all(&bytes).map_err(to_compute_err)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl AnonymousScan for RemoteScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Infer the schema from the head of the first object. This is only called when the scan is
+    /// created without a schema.
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        let url = &self.urls()?[0];
+        let file = TempFile::new();
+        self.download(url, &file.0, self.infer_lines)?;
+        (self.scan_local)(&file.0, None)?.schema()
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut remaining = scan_opts.n_rows;
+        let mut dfs = vec![];
+        for url in self.urls()? {
+            if remaining == Some(0) && !dfs.is_empty() {
+                break;
+            }
+            let file = TempFile::new();
+            self.download(&url, &file.0, None)?;
+            let mut lf = (self.scan_local)(&file.0, Some(scan_opts.schema.clone()))?;
+            if let Some(n) = remaining {
+                // like the local scans, the row limit counts the rows before the predicate
+                let df = lf.limit(n as IdxSize).collect()?;
+                remaining = Some(n - df.height());
+                lf = df.lazy();
+            }
+            if let Some(predicate) = &scan_opts.predicate {
+                lf = lf.filter(predicate.clone());
+            }
+            if let Some(schema) = &scan_opts.output_schema {
+                lf = lf.select(
+                    schema
+                        .iter_names()
+                        .map(|name| col(name))
+                        .collect::<Vec<_>>(),
+                );
+            } else if let Some(columns) = &scan_opts.with_columns {
+                lf = lf.select(columns.iter().map(|name| col(name)).collect::<Vec<_>>());
+            }
+            dfs.push(lf.collect()?);
+        }
+        accumulate_dataframes_vertical(dfs)
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+/// Same check as the local file scans use to decide whether to expand a path.
+fn is_glob(url: &str) -> bool {
+    url.contains('*') || url.contains('?') || url.contains('[')
+}
+
+/// A file in the temp directory that is deleted when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new() -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let name = format!(
+            "nodejs-polars-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        Self(std::env::temp_dir().join(name))
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
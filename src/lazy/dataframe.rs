@@ -1,12 +1,17 @@
+use super::cloud::RemoteScan;
 use super::dsl::*;
 use crate::dataframe::JsDataFrame;
 use crate::prelude::*;
 use polars::prelude::{col, lit, ClosedWindow, JoinType};
 use polars_io::cloud::CloudOptions;
+use polars_io::utils::is_cloud_url;
 use polars_io::{HiveOptions, RowIndex};
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::sync::Mutex;
 
 #[napi]
 #[repr(transparent)]
@@ -54,14 +59,14 @@ impl JsLazyFrame {
     }
 
     #[napi(catch_unwind)]
-    pub fn serialize(&self, format: String) -> napi::Result<Buffer> {
+    pub fn serialize(&self, format: String) -> js::Result<Buffer> {
         let buf = match format.as_ref() {
             "bincode" => bincode::serialize(&self.ldf.logical_plan)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             "json" => serde_json::to_vec(&self.ldf.logical_plan)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "unexpected format. \n supported options are 'json', 'bincode'".to_owned(),
                 ))
             }
@@ -70,14 +75,14 @@ impl JsLazyFrame {
     }
 
     #[napi(factory, catch_unwind)]
-    pub fn deserialize(buf: Buffer, format: String) -> napi::Result<JsLazyFrame> {
+    pub fn deserialize(buf: Buffer, format: String) -> js::Result<JsLazyFrame> {
         let lp: DslPlan = match format.as_ref() {
             "bincode" => bincode::deserialize(&buf)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             "json" => serde_json::from_slice(&buf)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "unexpected format. \n supported options are 'json', 'bincode'".to_owned(),
                 ))
             }
@@ -89,12 +94,12 @@ impl JsLazyFrame {
         Ok(lf.clone())
     }
     #[napi(catch_unwind)]
-    pub fn describe_plan(&self) -> napi::Result<String> {
+    pub fn describe_plan(&self) -> js::Result<String> {
         let result = self.ldf.describe_plan().map_err(JsPolarsErr::from)?;
         Ok(result)
     }
     #[napi(catch_unwind)]
-    pub fn describe_optimized_plan(&self) -> napi::Result<String> {
+    pub fn describe_optimized_plan(&self) -> js::Result<String> {
         let result = self
             .ldf
             .describe_optimized_plan()
@@ -102,10 +107,11 @@ impl JsLazyFrame {
         Ok(result)
     }
     #[napi(catch_unwind)]
-    pub fn to_dot(&self, optimized: bool) -> napi::Result<String> {
+    pub fn to_dot(&self, optimized: bool) -> js::Result<String> {
         let result = self.ldf.to_dot(optimized).map_err(JsPolarsErr::from)?;
         Ok(result)
     }
+    #[allow(clippy::too_many_arguments)]
     #[napi(catch_unwind)]
     pub fn optimization_toggle(
         &self,
@@ -115,12 +121,18 @@ impl JsLazyFrame {
         simplify_expr: Option<bool>,
         _string_cache: Option<bool>,
         slice_pushdown: Option<bool>,
+        comm_subplan_elim: Option<bool>,
+        comm_subexpr_elim: Option<bool>,
+        streaming: Option<bool>,
     ) -> JsLazyFrame {
         let type_coercion = type_coercion.unwrap_or(true);
         let predicate_pushdown = predicate_pushdown.unwrap_or(true);
         let projection_pushdown = projection_pushdown.unwrap_or(true);
         let simplify_expr = simplify_expr.unwrap_or(true);
         let slice_pushdown = slice_pushdown.unwrap_or(true);
+        let comm_subplan_elim = comm_subplan_elim.unwrap_or(true);
+        let comm_subexpr_elim = comm_subexpr_elim.unwrap_or(true);
+        let streaming = streaming.unwrap_or(false);
 
         let ldf = self.ldf.clone();
         let ldf = ldf
@@ -128,7 +140,10 @@ impl JsLazyFrame {
             .with_predicate_pushdown(predicate_pushdown)
             .with_simplify_expr(simplify_expr)
             .with_slice_pushdown(slice_pushdown)
-            .with_projection_pushdown(projection_pushdown);
+            .with_projection_pushdown(projection_pushdown)
+            .with_comm_subplan_elim(comm_subplan_elim)
+            .with_comm_subexpr_elim(comm_subexpr_elim)
+            .with_streaming(streaming);
         ldf.into()
     }
     #[napi(catch_unwind)]
@@ -173,26 +188,90 @@ impl JsLazyFrame {
         ldf.cache().into()
     }
     #[napi(catch_unwind)]
-    pub fn collect_sync(&self) -> napi::Result<JsDataFrame> {
+    pub fn collect_sync(&self) -> js::Result<JsDataFrame> {
         let ldf = self.ldf.clone();
         let df = ldf.collect().map_err(JsPolarsErr::from)?;
         Ok(df.into())
     }
 
     #[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
-    pub fn collect(&self) -> AsyncTask<AsyncCollect> {
+    pub fn collect(&self, token: Option<&JsCancelToken>) -> AsyncTask<AsyncCollect> {
         let ldf = self.ldf.clone();
-        AsyncTask::new(AsyncCollect(ldf))
+        AsyncTask::new(AsyncCollect((ldf, token.cloned())))
+    }
+
+    /// Collect the query and time each node of the physical plan.
+    /// Returns the result and a frame of `node`, `start` and `end` timings in microseconds.
+    #[napi(catch_unwind)]
+    pub fn profile_sync(&self) -> js::Result<Vec<JsDataFrame>> {
+        let ldf = self.ldf.clone();
+        let (df, timings) = ldf.profile().map_err(JsPolarsErr::from)?;
+        Ok(vec![df.into(), timings.into()])
+    }
+
+    #[napi(ts_return_type = "Promise<JsDataFrame[]>", catch_unwind)]
+    pub fn profile(&self) -> AsyncTask<AsyncProfile> {
+        let ldf = self.ldf.clone();
+        AsyncTask::new(AsyncProfile(ldf))
+    }
+
+    /// Run the query on the streaming engine and hand the produced chunks to JS one at a time.
+    /// At most `buffer_size` chunks are queued; the engine blocks until JS pulls the next one.
+    #[napi(catch_unwind)]
+    pub fn collect_batches(&self, buffer_size: Option<u32>) -> JsBatchIterator {
+        let buffer_size = buffer_size.unwrap_or(1) as usize;
+        let (sender, receiver) = sync_channel::<PolarsResult<DataFrame>>(buffer_size);
+        let closed = Arc::new(AtomicBool::new(false));
+        let batch_sender = sender.clone();
+        let batch_closed = closed.clone();
+        let ldf = self.ldf.clone().with_streaming(true).map(
+            move |df: DataFrame| {
+                polars_ensure!(
+                    !batch_closed.load(Ordering::Relaxed),
+                    ComputeError: "batch iterator was closed before the query finished"
+                );
+                let empty = df.clear();
+                batch_sender.send(Ok(df)).map_err(|_| {
+                    polars_err!(ComputeError: "batch iterator was closed before the query finished")
+                })?;
+                Ok(empty)
+            },
+            AllowedOptimizations {
+                streaming: true,
+                ..Default::default()
+            },
+            None,
+            Some("BATCH ITERATOR"),
+        );
+        std::thread::spawn(move || {
+            // a panic would otherwise drop the sender and look like the end of the stream
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| ldf.collect()))
+                .unwrap_or_else(|panic| {
+                    let msg = panic
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| panic.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "unknown panic".to_owned());
+                    Err(polars_err!(ComputeError: "query panicked: {}", msg))
+                });
+            if let Err(err) = result {
+                let _ = sender.send(Err(err));
+            }
+        });
+        JsBatchIterator {
+            receiver: Arc::new(Mutex::new(Some(receiver))),
+            closed,
+        }
     }
 
     #[napi(ts_return_type = "Promise<JsDataFrame>", catch_unwind)]
-    pub fn fetch(&self, n_rows: i64) -> AsyncTask<AsyncFetch> {
+    pub fn fetch(&self, n_rows: i64, token: Option<&JsCancelToken>) -> AsyncTask<AsyncFetch> {
         let ldf = self.ldf.clone();
-        AsyncTask::new(AsyncFetch((ldf, n_rows as usize)))
+        AsyncTask::new(AsyncFetch((ldf, n_rows as usize, token.cloned())))
     }
 
     #[napi(catch_unwind)]
-    pub fn fetch_sync(&self, n_rows: i64) -> napi::Result<JsDataFrame> {
+    pub fn fetch_sync(&self, n_rows: i64) -> js::Result<JsDataFrame> {
         let ldf = self.ldf.clone();
         let df = ldf.fetch(n_rows as usize).map_err(JsPolarsErr::from)?;
         Ok(df.into())
@@ -295,36 +374,42 @@ impl JsLazyFrame {
         allow_parallel: bool,
         force_parallel: bool,
         suffix: String,
-        strategy: String,
+        strategy: Wrap<AsofStrategy>,
         tolerance: Option<Wrap<AnyValue<'_>>>,
         tolerance_str: Option<String>,
-    ) -> JsLazyFrame {
-        let strategy = match strategy.as_ref() {
-            "forward" => AsofStrategy::Forward,
-            "backward" => AsofStrategy::Backward,
-            _ => panic!("expected one of {{'forward', 'backward'}}"),
-        };
+    ) -> js::Result<JsLazyFrame> {
+        if left_by.is_some() != right_by.is_some() {
+            return Err(JsPolarsErr::Other(
+                "expected 'by' columns on both sides of the asof join".to_owned(),
+            ));
+        }
+        let tolerance = tolerance
+            .map(|t| t.0.into_static())
+            .transpose()
+            .map_err(JsPolarsErr::from)?;
         let ldf = self.ldf.clone();
         let other = other.ldf.clone();
         let left_on = left_on.inner.clone();
         let right_on = right_on.inner.clone();
-        ldf.join_builder()
+        Ok(ldf
+            .join_builder()
             .with(other)
             .left_on([left_on])
             .right_on([right_on])
             .allow_parallel(allow_parallel)
             .force_parallel(force_parallel)
             .how(JoinType::AsOf(AsOfOptions {
-                strategy,
+                strategy: strategy.0,
                 left_by: left_by.map(strings_to_smartstrings),
                 right_by: right_by.map(strings_to_smartstrings),
-                tolerance: tolerance.map(|t| t.0.into_static().unwrap()),
+                tolerance,
                 tolerance_str: tolerance_str.map(|s| s.into()),
             }))
             .suffix(suffix)
             .finish()
-            .into()
+            .into())
     }
+    /// `coalesce` merges the key columns of both sides; by default only left and inner joins do.
     #[allow(clippy::too_many_arguments)]
     #[napi(catch_unwind)]
     pub fn join(
@@ -336,11 +421,19 @@ impl JsLazyFrame {
         force_parallel: bool,
         how: Wrap<JoinType>,
         suffix: String,
+        validate: Option<Wrap<JoinValidation>>,
+        join_nulls: Option<bool>,
+        coalesce: Option<bool>,
     ) -> JsLazyFrame {
         let ldf = self.ldf.clone();
         let other = other.ldf.clone();
         let left_on = left_on.to_exprs();
         let right_on = right_on.to_exprs();
+        let coalesce = match coalesce {
+            None => JoinCoalesce::JoinSpecific,
+            Some(true) => JoinCoalesce::CoalesceColumns,
+            Some(false) => JoinCoalesce::KeepColumns,
+        };
 
         ldf.join_builder()
             .with(other)
@@ -349,6 +442,9 @@ impl JsLazyFrame {
             .allow_parallel(allow_parallel)
             .force_parallel(force_parallel)
             .how(how.0)
+            .validate(validate.map(|v| v.0).unwrap_or_default())
+            .join_nulls(join_nulls.unwrap_or(false))
+            .coalesce(coalesce)
             .suffix(suffix)
             .finish()
             .into()
@@ -531,7 +627,7 @@ impl JsLazyFrame {
     }
 
     #[napi(getter, js_name = "columns", catch_unwind)]
-    pub fn columns(&self) -> napi::Result<Vec<String>> {
+    pub fn columns(&self) -> js::Result<Vec<String>> {
         Ok(self
             .ldf
             .schema()
@@ -541,81 +637,115 @@ impl JsLazyFrame {
             .collect())
     }
 
+    /// The output schema of the logical plan, resolved without running the query.
+    #[napi(getter, catch_unwind)]
+    pub fn schema(&self) -> js::Result<Wrap<Schema>> {
+        let schema = self.ldf.schema().map_err(JsPolarsErr::from)?;
+        Ok(Wrap((*schema).clone()))
+    }
+
+    #[napi(getter, catch_unwind)]
+    pub fn width(&self) -> js::Result<i64> {
+        let schema = self.ldf.schema().map_err(JsPolarsErr::from)?;
+        Ok(schema.len() as i64)
+    }
+
     #[napi(catch_unwind)]
     pub fn unnest(&self, colss: Vec<String>) -> JsLazyFrame {
         self.ldf.clone().unnest(colss).into()
     }
 
     #[napi(catch_unwind)]
-    pub fn sink_csv(&self, path: String, options: SinkCsvOptions) -> napi::Result<()> {
-        let quote_style = QuoteStyle::default();
-        let null_value = options
-            .null_value
-            .unwrap_or(SerializeOptions::default().null);
-        let float_precision: Option<usize> = options.float_precision.map(|fp| fp as usize);
-        let separator = options.separator.unwrap_or(",".to_owned()).as_bytes()[0];
-        let line_terminator = options.line_terminator.unwrap_or("\n".to_string());
-        let quote_char = options.quote_char.unwrap_or("\"".to_owned()).as_bytes()[0];
-        let date_format = options.date_format;
-        let time_format = options.time_format;
-        let datetime_format = options.datetime_format;
-
-        let serialize_options = SerializeOptions {
-            date_format,
-            time_format,
-            datetime_format,
-            float_precision,
-            separator,
-            quote_char,
-            null: null_value,
-            line_terminator,
-            quote_style,
-        };
+    pub fn sink_csv(&self, path: String, options: SinkCsvOptions) -> js::Result<()> {
+        let format = SinkFormat::Csv(csv_writer_options(options)?);
+        sink(self.ldf.clone(), PathBuf::from(path), format, None).map_err(JsPolarsErr::from)?;
+        Ok(())
+    }
 
-        let batch_size = options.batch_size.map(|bs| bs).unwrap_or(1024) as usize;
-        let batch_size = NonZeroUsize::new(batch_size).unwrap();
-        let include_bom = options.include_bom.unwrap_or(false);
-        let include_header = options.include_header.unwrap_or(true);
-        let maintain_order = options.maintain_order;
-
-        let options = CsvWriterOptions {
-            include_bom,
-            include_header,
-            maintain_order,
-            batch_size,
-            serialize_options,
-        };
+    #[napi(ts_return_type = "Promise<void>", catch_unwind)]
+    pub fn sink_csv_async(
+        &self,
+        path: String,
+        options: SinkCsvOptions,
+        token: Option<&JsCancelToken>,
+    ) -> js::Result<AsyncTask<AsyncSink>> {
+        let format = SinkFormat::Csv(csv_writer_options(options)?);
+        Ok(AsyncTask::new(AsyncSink((
+            self.ldf.clone(),
+            PathBuf::from(path),
+            format,
+            token.cloned(),
+        ))))
+    }
 
-        let path_buf: PathBuf = PathBuf::from(path);
-        let ldf = self.ldf.clone().with_comm_subplan_elim(false);
-        let _ = ldf.sink_csv(path_buf, options).map_err(JsPolarsErr::from);
+    #[napi(catch_unwind)]
+    pub fn sink_parquet(&self, path: String, options: SinkParquetOptions) -> js::Result<()> {
+        let format = SinkFormat::Parquet(parquet_write_options(options)?);
+        sink(self.ldf.clone(), PathBuf::from(path), format, None).map_err(JsPolarsErr::from)?;
         Ok(())
     }
 
+    #[napi(ts_return_type = "Promise<void>", catch_unwind)]
+    pub fn sink_parquet_async(
+        &self,
+        path: String,
+        options: SinkParquetOptions,
+        token: Option<&JsCancelToken>,
+    ) -> js::Result<AsyncTask<AsyncSink>> {
+        let format = SinkFormat::Parquet(parquet_write_options(options)?);
+        Ok(AsyncTask::new(AsyncSink((
+            self.ldf.clone(),
+            PathBuf::from(path),
+            format,
+            token.cloned(),
+        ))))
+    }
+
     #[napi(catch_unwind)]
-    pub fn sink_parquet(&self, path: String, options: SinkParquetOptions) -> napi::Result<()> {
-        let compression_str = options.compression.unwrap_or("zstd".to_string());
-        let compression = parse_parquet_compression(compression_str, options.compression_level)?;
-        let statistics = options.statistics.unwrap_or(false);
-        let row_group_size = options.row_group_size.map(|i| i as usize);
-        let data_pagesize_limit = options.data_pagesize_limit.map(|i| i as usize);
-        let maintain_order = options.maintain_order.unwrap_or(true);
+    pub fn sink_ipc(&self, path: String, options: SinkIpcOptions) -> js::Result<()> {
+        let format = SinkFormat::Ipc(ipc_writer_options(options)?);
+        sink(self.ldf.clone(), PathBuf::from(path), format, None).map_err(JsPolarsErr::from)?;
+        Ok(())
+    }
 
-        let options = ParquetWriteOptions {
-            compression,
-            statistics,
-            row_group_size,
-            data_pagesize_limit,
-            maintain_order,
-        };
+    #[napi(ts_return_type = "Promise<void>", catch_unwind)]
+    pub fn sink_ipc_async(
+        &self,
+        path: String,
+        options: SinkIpcOptions,
+        token: Option<&JsCancelToken>,
+    ) -> js::Result<AsyncTask<AsyncSink>> {
+        let format = SinkFormat::Ipc(ipc_writer_options(options)?);
+        Ok(AsyncTask::new(AsyncSink((
+            self.ldf.clone(),
+            PathBuf::from(path),
+            format,
+            token.cloned(),
+        ))))
+    }
 
-        let path_buf: PathBuf = PathBuf::from(path);
-        let ldf = self.ldf.clone().with_comm_subplan_elim(false);
-        let _ = ldf
-            .sink_parquet(path_buf, options)
-            .map_err(JsPolarsErr::from);
+    #[napi(catch_unwind)]
+    pub fn sink_ndjson(&self, path: String, options: SinkJsonOptions) -> js::Result<()> {
+        let format = SinkFormat::Json(json_writer_options(options));
+        sink(self.ldf.clone(), PathBuf::from(path), format, None).map_err(JsPolarsErr::from)?;
         Ok(())
     }
+
+    #[napi(ts_return_type = "Promise<void>", catch_unwind)]
+    pub fn sink_ndjson_async(
+        &self,
+        path: String,
+        options: SinkJsonOptions,
+        token: Option<&JsCancelToken>,
+    ) -> napi::Result<AsyncTask<AsyncSink>> {
+        let format = SinkFormat::Json(json_writer_options(options));
+        Ok(AsyncTask::new(AsyncSink((
+            self.ldf.clone(),
+            PathBuf::from(path),
+            format,
+            token.cloned(),
+        ))))
+    }
 }
 
 #[napi(object)]
@@ -644,9 +774,11 @@ pub struct ScanCsvOptions {
     pub raise_if_empty: Option<bool>,
     pub truncate_ragged_lines: Option<bool>,
     pub schema: Option<Wrap<Schema>>,
+    pub cloud_options: Option<HashMap<String, String>>,
+    pub retries: Option<i64>,
 }
 #[napi(catch_unwind)]
-pub fn scan_csv(path: String, options: ScanCsvOptions) -> napi::Result<JsLazyFrame> {
+pub fn scan_csv(path: String, options: ScanCsvOptions) -> js::Result<JsLazyFrame> {
     let n_rows = options.n_rows.map(|i| i as usize);
     let row_count = options.row_count.map(RowIndex::from);
     let missing_utf8_is_empty_string: bool = options.missing_utf8_is_empty_string.unwrap_or(false);
@@ -669,36 +801,75 @@ pub fn scan_csv(path: String, options: ScanCsvOptions) -> napi::Result<JsLazyFra
             })
             .collect::<Schema>()
     });
+    let overwrite_dtype = overwrite_dtype.map(Arc::new);
+    let schema = options.schema.map(|schema| Arc::new(schema.0));
 
     let encoding = match options.encoding.as_ref() {
         "utf8" => CsvEncoding::Utf8,
         "utf8-lossy" => CsvEncoding::LossyUtf8,
-        e => return Err(JsPolarsErr::Other(format!("encoding not {} not implemented.", e)).into()),
+        e => {
+            return Err(JsPolarsErr::Other(format!(
+                "encoding not {} not implemented.",
+                e
+            )))
+        }
     };
+    let separator = single_byte(options.sep.as_deref().unwrap_or(","), "sep")?;
+    let infer_schema_length = options.infer_schema_length.unwrap_or(100) as usize;
+    let has_header = options.has_header.unwrap_or(true);
+    let skip_rows = options.skip_rows.unwrap_or(0) as usize;
+    let skip_rows_after_header = options.skip_rows_after_header as usize;
+    let comment_prefix = options.comment_prefix;
+    let null_values = options.null_values.map(|s| s.0);
+    let remote_schema = schema.clone();
 
-    let r = LazyCsvReader::new(path)
-        .with_infer_schema_length(Some(options.infer_schema_length.unwrap_or(100) as usize))
-        .with_separator(options.sep.unwrap_or(",".to_owned()).as_bytes()[0])
-        .with_has_header(options.has_header.unwrap_or(true))
-        .with_ignore_errors(options.ignore_errors)
-        .with_skip_rows(options.skip_rows.unwrap_or(0) as usize)
+    let configure = move |reader: LazyCsvReader| {
+        reader
+            .with_infer_schema_length(Some(infer_schema_length))
+            .with_separator(separator)
+            .with_has_header(has_header)
+            .with_ignore_errors(options.ignore_errors)
+            .with_skip_rows(skip_rows)
+            .with_cache(options.cache.unwrap_or(true))
+            .with_dtype_overwrite(overwrite_dtype.clone())
+            .with_schema(schema.clone())
+            .with_low_memory(options.low_memory.unwrap_or(false))
+            .with_comment_prefix(comment_prefix.as_deref())
+            .with_quote_char(quote_char)
+            .with_eol_char(options.eol_char.unwrap_or(b'\n'))
+            .with_rechunk(options.rechunk.unwrap_or(false))
+            .with_skip_rows_after_header(skip_rows_after_header)
+            .with_encoding(encoding)
+            .with_try_parse_dates(options.parse_dates.unwrap_or(false))
+            .with_null_values(null_values.clone())
+            .with_missing_is_null(!missing_utf8_is_empty_string)
+            .with_truncate_ragged_lines(options.truncate_ragged_lines.unwrap_or(false))
+            .with_raise_if_empty(options.raise_if_empty.unwrap_or(true))
+    };
+
+    if is_cloud_url(&path) {
+        let cloud_options = parse_cloud_options(&path, options.cloud_options, options.retries)?;
+        let infer_lines =
+            skip_rows + has_header as usize + skip_rows_after_header + infer_schema_length;
+        let scan = RemoteScan::new(
+            path,
+            cloud_options,
+            Some(infer_lines),
+            Box::new(move |path, schema| {
+                let reader = configure(LazyCsvReader::new(path));
+                match schema {
+                    Some(schema) => reader.with_schema(Some(schema)),
+                    None => reader,
+                }
+                .finish()
+            }),
+        );
+        return scan_remote(scan, remote_schema, n_rows, row_count, "REMOTE CSV");
+    }
+
+    let r = configure(LazyCsvReader::new(path))
         .with_n_rows(n_rows)
-        .with_cache(options.cache.unwrap_or(true))
-        .with_dtype_overwrite(overwrite_dtype.map(Arc::new))
-        .with_schema(options.schema.map(|schema| Arc::new(schema.0)))
-        .with_low_memory(options.low_memory.unwrap_or(false))
-        .with_comment_prefix(options.comment_prefix.as_deref())
-        .with_quote_char(quote_char)
-        .with_eol_char(options.eol_char.unwrap_or(b'\n'))
-        .with_rechunk(options.rechunk.unwrap_or(false))
-        .with_skip_rows_after_header(options.skip_rows_after_header as usize)
-        .with_encoding(encoding)
         .with_row_index(row_count)
-        .with_try_parse_dates(options.parse_dates.unwrap_or(false))
-        .with_null_values(options.null_values.map(|s| s.0))
-        .with_missing_is_null(!missing_utf8_is_empty_string)
-        .with_truncate_ragged_lines(options.truncate_ragged_lines.unwrap_or(false))
-        .with_raise_if_empty(options.raise_if_empty.unwrap_or(true))
         .finish()
         .map_err(JsPolarsErr::from)?;
     Ok(r.into())
@@ -715,10 +886,12 @@ pub struct ScanParquetOptions {
     pub use_statistics: Option<bool>,
     pub cloud_options: Option<HashMap<String, String>>,
     pub retries: Option<i64>,
+    pub hive_partitioning: Option<bool>,
+    pub hive_schema: Option<Wrap<Schema>>,
 }
 
 #[napi(catch_unwind)]
-pub fn scan_parquet(path: String, options: ScanParquetOptions) -> napi::Result<JsLazyFrame> {
+pub fn scan_parquet(path: String, options: ScanParquetOptions) -> js::Result<JsLazyFrame> {
     let n_rows = options.n_rows.map(|i| i as usize);
     let cache = options.cache.unwrap_or(true);
     let parallel = options.parallel;
@@ -726,24 +899,11 @@ pub fn scan_parquet(path: String, options: ScanParquetOptions) -> napi::Result<J
     let rechunk = options.rechunk.unwrap_or(false);
     let low_memory = options.low_memory.unwrap_or(false);
     let use_statistics = options.use_statistics.unwrap_or(false);
+    let hive_schema = options.hive_schema.map(|s| Arc::new(s.0));
+    // an explicit partition schema implies hive partitioning
+    let hive_enabled = options.hive_partitioning.unwrap_or(hive_schema.is_some());
 
-    let mut cloud_options: Option<CloudOptions> = if let Some(o) = options.cloud_options {
-        let co: Vec<(String, String)> = o.into_iter().map(|kv: (String, String)| kv).collect();
-        Some(CloudOptions::from_untyped_config(&path, co).map_err(JsPolarsErr::from)?)
-    } else {
-        None
-    };
-
-    let retries = options.retries.unwrap_or_else(|| 2) as usize;
-    if retries > 0 {
-        cloud_options =
-            cloud_options
-                .or_else(|| Some(CloudOptions::default()))
-                .map(|mut options| {
-                    options.max_retries = retries;
-                    options
-                });
-    }
+    let cloud_options = parse_cloud_options(&path, options.cloud_options, options.retries)?;
 
     let args = ScanArgsParquet {
         n_rows,
@@ -754,10 +914,9 @@ pub fn scan_parquet(path: String, options: ScanParquetOptions) -> napi::Result<J
         low_memory,
         cloud_options,
         use_statistics,
-        // TODO: Support Hive partitioning.
         hive_options: HiveOptions {
-            enabled: false,
-            ..Default::default()
+            enabled: hive_enabled,
+            schema: hive_schema,
         },
         glob: true,
     };
@@ -772,22 +931,25 @@ pub struct ScanIPCOptions {
     pub rechunk: Option<bool>,
     pub row_count: Option<JsRowCount>,
     pub memmap: Option<bool>,
+    pub cloud_options: Option<HashMap<String, String>>,
+    pub retries: Option<i64>,
 }
 
 #[napi(catch_unwind)]
-pub fn scan_ipc(path: String, options: ScanIPCOptions) -> napi::Result<JsLazyFrame> {
+pub fn scan_ipc(path: String, options: ScanIPCOptions) -> js::Result<JsLazyFrame> {
     let n_rows = options.n_rows.map(|i| i as usize);
     let cache = options.cache.unwrap_or(true);
     let rechunk = options.rechunk.unwrap_or(false);
     let memory_map = options.memmap.unwrap_or(true);
     let row_index: Option<RowIndex> = options.row_count.map(|rc| rc.into());
+    let cloud_options = parse_cloud_options(&path, options.cloud_options, options.retries)?;
     let args = ScanArgsIpc {
         n_rows,
         cache,
         rechunk,
         row_index,
         memory_map,
-        cloud_options: Default::default(),
+        cloud_options,
     };
     let lf = LazyFrame::scan_ipc(path, args).map_err(JsPolarsErr::from)?;
     Ok(lf.into())
@@ -802,52 +964,407 @@ pub struct JsonScanOptions {
     pub skip_rows: Option<i64>,
     pub low_memory: Option<bool>,
     pub row_count: Option<JsRowCount>,
+    pub schema: Option<Wrap<Schema>>,
+    pub cloud_options: Option<HashMap<String, String>>,
+    pub retries: Option<i64>,
 }
 
 #[napi(catch_unwind)]
-pub fn scan_json(path: String, options: JsonScanOptions) -> napi::Result<JsLazyFrame> {
+pub fn scan_json(path: String, options: JsonScanOptions) -> js::Result<JsLazyFrame> {
     let batch_size = options.batch_size as usize;
     let batch_size = NonZeroUsize::new(batch_size);
-    LazyJsonLineReader::new(path)
-        .with_batch_size(batch_size)
-        .low_memory(options.low_memory.unwrap_or(false))
-        .with_row_index(options.row_count.map(|rc| rc.into()))
-        .with_n_rows(options.num_rows.map(|i| i as usize))
+    let infer_schema_length = options.infer_schema_length.map(|i| i as usize);
+    let n_rows = options.num_rows.map(|i| i as usize);
+    let row_index: Option<RowIndex> = options.row_count.map(|rc| rc.into());
+    let low_memory = options.low_memory.unwrap_or(false);
+    let schema = options.schema.map(|schema| Arc::new(schema.0));
+    let configure = move |reader: LazyJsonLineReader| {
+        reader
+            .with_batch_size(batch_size)
+            .with_infer_schema_length(infer_schema_length)
+            .low_memory(low_memory)
+    };
+
+    if is_cloud_url(&path) {
+        let cloud_options = parse_cloud_options(&path, options.cloud_options, options.retries)?;
+        let scan = RemoteScan::new(
+            path,
+            cloud_options,
+            infer_schema_length,
+            Box::new(move |path, schema| {
+                configure(LazyJsonLineReader::new(path))
+                    .with_schema(schema)
+                    .finish()
+            }),
+        );
+        return scan_remote(scan, schema, n_rows, row_index, "REMOTE NDJSON");
+    }
+
+    configure(LazyJsonLineReader::new(path))
+        .with_schema(schema)
+        .with_row_index(row_index)
+        .with_n_rows(n_rows)
         .finish()
-        .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))
+        .map_err(JsPolarsErr::from)
         .map(|lf| lf.into())
 }
 
-pub struct AsyncFetch((LazyFrame, usize));
+#[napi]
+pub struct JsBatchIterator {
+    receiver: BatchReceiver,
+    closed: Arc<AtomicBool>,
+}
+
+#[napi]
+impl JsBatchIterator {
+    /// Resolves with the next batch, or `null` once the query is exhausted.
+    #[napi(ts_return_type = "Promise<JsDataFrame | null>", catch_unwind)]
+    pub fn next(&self) -> AsyncTask<AsyncNextBatch> {
+        AsyncTask::new(AsyncNextBatch((self.receiver.clone(), self.closed.clone())))
+    }
+
+    /// Stop consuming batches. The running query is aborted the next time it produces a chunk.
+    /// Never blocks: a pending `next` drops the receiver once it returns.
+    #[napi(catch_unwind)]
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Relaxed);
+        if let Ok(mut receiver) = self.receiver.try_lock() {
+            receiver.take();
+        }
+    }
+}
+
+/// Interrupts the queries it was passed to. The JS side cancels it when an `AbortSignal` fires.
+#[napi]
+#[derive(Clone, Default)]
+pub struct JsCancelToken {
+    cancelled: Arc<AtomicBool>,
+    queries: Arc<Mutex<HashMap<usize, InProcessQuery>>>,
+}
+
+#[napi]
+impl JsCancelToken {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop every running query at its next safe point. Queries started afterwards fail right away.
+    #[napi(catch_unwind)]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        for query in self.queries.lock().unwrap().values() {
+            query.cancel();
+        }
+    }
+
+    #[napi(getter)]
+    pub fn cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+impl JsCancelToken {
+    fn check(&self) -> PolarsResult<()> {
+        polars_ensure!(!self.cancelled(), ComputeError: "query interrupted");
+        Ok(())
+    }
+
+    /// Run the query on the thread pool, so that `cancel` can interrupt it.
+    fn collect(&self, ldf: LazyFrame) -> PolarsResult<DataFrame> {
+        static QUERY_ID: AtomicUsize = AtomicUsize::new(0);
+
+        self.check()?;
+        let query = ldf.collect_concurrently()?;
+        let id = QUERY_ID.fetch_add(1, Ordering::Relaxed);
+        self.queries.lock().unwrap().insert(id, query.clone());
+        // `cancel` may have run before the query was registered
+        if self.cancelled() {
+            query.cancel();
+        }
+        let out = query.fetch_blocking();
+        self.queries.lock().unwrap().remove(&id);
+        out
+    }
+
+    /// `LazyFrame::fetch` sets `FETCH_ROWS` on the thread that runs the query, so it cannot run
+    /// on the thread pool like `collect`. The token is checked in a map node instead, which
+    /// runs between the chunks of a streaming query and once the query has finished otherwise.
+    fn fetch(&self, ldf: LazyFrame, n_rows: usize) -> PolarsResult<DataFrame> {
+        self.check()?;
+        self.map_checked(ldf).fetch(n_rows)
+    }
+
+    /// Add a map node that fails once the token is cancelled.
+    fn map_checked(&self, ldf: LazyFrame) -> LazyFrame {
+        let token = self.clone();
+        ldf.map(
+            move |df: DataFrame| {
+                token.check()?;
+                Ok(df)
+            },
+            AllowedOptimizations {
+                streaming: true,
+                ..Default::default()
+            },
+            None,
+            Some("CANCEL TOKEN"),
+        )
+    }
+}
+
+type BatchReceiver = Arc<Mutex<Option<Receiver<PolarsResult<DataFrame>>>>>;
+
+pub struct AsyncNextBatch((BatchReceiver, Arc<AtomicBool>));
+
+impl Task for AsyncNextBatch {
+    type Output = PolarsResult<Option<DataFrame>>;
+    type JsValue = Option<JsDataFrame>;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let (receiver, closed) = &self.0;
+        let mut receiver = receiver.lock().unwrap();
+        let next = receiver.as_ref().map(|r| r.recv());
+        if closed.load(Ordering::Relaxed) {
+            // unblock the producer, it errors out on its next send
+            receiver.take();
+            return Ok(Ok(None));
+        }
+        match next {
+            Some(Ok(batch)) => Ok(batch.map(Some)),
+            // the query has finished without error and dropped its sender
+            Some(Err(_)) | None => Ok(Ok(None)),
+        }
+    }
+
+    fn resolve(&mut self, env: Env, df: Self::Output) -> napi::Result<Self::JsValue> {
+        let df = df.map_err(|e| JsPolarsErr::from(e).into_js(env))?;
+        Ok(df.map(|df| df.into()))
+    }
+}
+
+fn parse_cloud_options(
+    path: &str,
+    cloud_options: Option<HashMap<String, String>>,
+    retries: Option<i64>,
+) -> JsPolarsResult<Option<CloudOptions>> {
+    let mut cloud_options: Option<CloudOptions> = if let Some(o) = cloud_options {
+        let co: Vec<(String, String)> = o.into_iter().map(|kv: (String, String)| kv).collect();
+        Some(CloudOptions::from_untyped_config(path, co).map_err(JsPolarsErr::from)?)
+    } else {
+        None
+    };
+
+    let retries = retries.unwrap_or_else(|| 2) as usize;
+    if retries > 0 {
+        cloud_options =
+            cloud_options
+                .or_else(|| Some(CloudOptions::default()))
+                .map(|mut options| {
+                    options.max_retries = retries;
+                    options
+                });
+    }
+    Ok(cloud_options)
+}
+
+/// The row limit and index apply to all matched objects together, so they are set on the scan.
+/// Without a `schema`, the head of the first object is downloaded here to infer it.
+fn scan_remote(
+    scan: RemoteScan,
+    schema: Option<SchemaRef>,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+    name: &'static str,
+) -> JsPolarsResult<JsLazyFrame> {
+    let args = ScanArgsAnonymous {
+        schema,
+        n_rows,
+        row_index,
+        name,
+        ..Default::default()
+    };
+    let lf = LazyFrame::anonymous_scan(Arc::new(scan), args).map_err(JsPolarsErr::from)?;
+    Ok(lf.into())
+}
+
+#[derive(Clone)]
+pub enum SinkFormat {
+    Csv(CsvWriterOptions),
+    Parquet(ParquetWriteOptions),
+    Ipc(IpcWriterOptions),
+    Json(JsonWriterOptions),
+}
+
+fn csv_writer_options(options: SinkCsvOptions) -> JsPolarsResult<CsvWriterOptions> {
+    let quote_style = QuoteStyle::default();
+    let null_value = options
+        .null_value
+        .unwrap_or(SerializeOptions::default().null);
+    let float_precision: Option<usize> = options.float_precision.map(|fp| fp as usize);
+    let separator = single_byte(options.separator.as_deref().unwrap_or(","), "separator")?;
+    let line_terminator = options.line_terminator.unwrap_or("\n".to_string());
+    let quote_char = single_byte(options.quote_char.as_deref().unwrap_or("\""), "quoteChar")?;
+    let date_format = options.date_format;
+    let time_format = options.time_format;
+    let datetime_format = options.datetime_format;
+
+    let serialize_options = SerializeOptions {
+        date_format,
+        time_format,
+        datetime_format,
+        float_precision,
+        separator,
+        quote_char,
+        null: null_value,
+        line_terminator,
+        quote_style,
+    };
+
+    let batch_size = options.batch_size.map(|bs| bs).unwrap_or(1024) as usize;
+    let batch_size = NonZeroUsize::new(batch_size)
+        .ok_or_else(|| JsPolarsErr::Other("batchSize must be positive".to_owned()))?;
+    let include_bom = options.include_bom.unwrap_or(false);
+    let include_header = options.include_header.unwrap_or(true);
+    let maintain_order = options.maintain_order;
+
+    Ok(CsvWriterOptions {
+        include_bom,
+        include_header,
+        maintain_order,
+        batch_size,
+        serialize_options,
+    })
+}
+
+fn parquet_write_options(options: SinkParquetOptions) -> JsPolarsResult<ParquetWriteOptions> {
+    let compression_str = options.compression.unwrap_or("zstd".to_string());
+    let compression = parse_parquet_compression(compression_str, options.compression_level)?;
+    let statistics = options.statistics.unwrap_or(false);
+    let row_group_size = options.row_group_size.map(|i| i as usize);
+    let data_pagesize_limit = options.data_pagesize_limit.map(|i| i as usize);
+    let maintain_order = options.maintain_order.unwrap_or(true);
+
+    Ok(ParquetWriteOptions {
+        compression,
+        statistics,
+        row_group_size,
+        data_pagesize_limit,
+        maintain_order,
+    })
+}
+
+fn ipc_writer_options(options: SinkIpcOptions) -> JsPolarsResult<IpcWriterOptions> {
+    let compression_str = options.compression.unwrap_or("uncompressed".to_string());
+    let compression = parse_ipc_compression(compression_str)?;
+    let maintain_order = options.maintain_order.unwrap_or(true);
+
+    Ok(IpcWriterOptions {
+        compression,
+        maintain_order,
+    })
+}
+
+fn json_writer_options(options: SinkJsonOptions) -> JsonWriterOptions {
+    let maintain_order = options.maintain_order.unwrap_or(true);
+
+    JsonWriterOptions { maintain_order }
+}
+
+fn sink(
+    ldf: LazyFrame,
+    path: PathBuf,
+    format: SinkFormat,
+    token: Option<&JsCancelToken>,
+) -> PolarsResult<()> {
+    let mut ldf = ldf.with_comm_subplan_elim(false);
+    if let Some(token) = token {
+        token.check()?;
+        // sinks have no handle on their execution state, so check the token between chunks
+        ldf = token.map_checked(ldf);
+    }
+    match format {
+        SinkFormat::Csv(options) => ldf.sink_csv(path, options),
+        SinkFormat::Parquet(options) => ldf.sink_parquet(path, options),
+        SinkFormat::Ipc(options) => ldf.sink_ipc(path, options),
+        SinkFormat::Json(options) => ldf.sink_json(path, options),
+    }
+}
+
+pub struct AsyncSink((LazyFrame, PathBuf, SinkFormat, Option<JsCancelToken>));
+
+impl Task for AsyncSink {
+    type Output = PolarsResult<()>;
+    type JsValue = ();
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let (ldf, path, format, token) = &self.0;
+        Ok(sink(
+            ldf.clone(),
+            path.clone(),
+            format.clone(),
+            token.as_ref(),
+        ))
+    }
+
+    fn resolve(&mut self, env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        output.map_err(|e| JsPolarsErr::from(e).into_js(env))?;
+        Ok(())
+    }
+}
+
+pub struct AsyncFetch((LazyFrame, usize, Option<JsCancelToken>));
 
 impl Task for AsyncFetch {
-    type Output = DataFrame;
+    type Output = PolarsResult<DataFrame>;
     type JsValue = JsDataFrame;
 
     fn compute(&mut self) -> napi::Result<Self::Output> {
-        let (ldf, n_rows) = &self.0;
+        let (ldf, n_rows, token) = &self.0;
         let ldf = ldf.clone();
-        let df = ldf.fetch(*n_rows).map_err(JsPolarsErr::from)?;
-        Ok(df)
+        Ok(match token {
+            Some(token) => token.fetch(ldf, *n_rows),
+            None => ldf.fetch(*n_rows),
+        })
     }
 
-    fn resolve(&mut self, _env: Env, df: DataFrame) -> napi::Result<Self::JsValue> {
-        Ok(df.into())
+    fn resolve(&mut self, env: Env, df: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(df.map_err(|e| JsPolarsErr::from(e).into_js(env))?.into())
     }
 }
-pub struct AsyncCollect(LazyFrame);
+pub struct AsyncProfile(LazyFrame);
+
+impl Task for AsyncProfile {
+    type Output = PolarsResult<(DataFrame, DataFrame)>;
+    type JsValue = Vec<JsDataFrame>;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let ldf = self.0.clone();
+        Ok(ldf.profile())
+    }
+
+    fn resolve(&mut self, env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        let (df, timings) = output.map_err(|e| JsPolarsErr::from(e).into_js(env))?;
+        Ok(vec![df.into(), timings.into()])
+    }
+}
+
+pub struct AsyncCollect((LazyFrame, Option<JsCancelToken>));
 
 impl Task for AsyncCollect {
-    type Output = DataFrame;
+    type Output = PolarsResult<DataFrame>;
     type JsValue = JsDataFrame;
 
     fn compute(&mut self) -> napi::Result<Self::Output> {
-        let ldf = self.0.clone();
-        let df = ldf.collect().map_err(JsPolarsErr::from)?;
-        Ok(df)
+        let (ldf, token) = &self.0;
+        let ldf = ldf.clone();
+        Ok(match token {
+            Some(token) => token.collect(ldf),
+            None => ldf.collect(),
+        })
     }
 
-    fn resolve(&mut self, _env: Env, df: DataFrame) -> napi::Result<Self::JsValue> {
-        Ok(df.into())
+    fn resolve(&mut self, env: Env, df: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(df.map_err(|e| JsPolarsErr::from(e).into_js(env))?.into())
     }
 }
@@ -51,14 +51,14 @@ impl JsExpr {
         env.to_js_value(&self.inner)
     }
     #[napi(catch_unwind)]
-    pub fn serialize(&self, format: String) -> napi::Result<Buffer> {
+    pub fn serialize(&self, format: String) -> js::Result<Buffer> {
         let buf = match format.as_ref() {
             "bincode" => bincode::serialize(&self.inner)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             "json" => serde_json::to_vec(&self.inner)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "unexpected format. \n supported options are 'json', 'bincode'".to_owned(),
                 ))
             }
@@ -67,7 +67,7 @@ impl JsExpr {
     }
 
     #[napi(factory, catch_unwind)]
-    pub fn deserialize(buf: Buffer, format: String) -> napi::Result<JsExpr> {
+    pub fn deserialize(buf: Buffer, format: String) -> js::Result<JsExpr> {
         // Safety
         // we skipped the serializing/deserializing of the static in lifetime in `DataType`
         // so we actually don't have a lifetime at all when serializing.
@@ -78,11 +78,11 @@ impl JsExpr {
         let bytes = unsafe { std::mem::transmute::<&'_ [u8], &'static [u8]>(bytes) };
         let expr: Expr = match format.as_ref() {
             "bincode" => bincode::deserialize(bytes)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             "json" => serde_json::from_slice(bytes)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "unexpected format. \n supported options are 'json', 'bincode'".to_owned(),
                 ))
             }
@@ -374,7 +374,7 @@ impl JsExpr {
         &self,
         strategy: String,
         limit: FillNullLimit,
-    ) -> JsResult<JsExpr> {
+    ) -> js::Result<JsExpr> {
         let strat = parse_fill_null_strategy(&strategy, limit)?;
         Ok(self
             .inner
@@ -1013,6 +1013,37 @@ impl JsExpr {
         self.clone().inner.dt().nanosecond().into()
     }
     #[napi(catch_unwind)]
+    pub fn convert_time_zone(&self, time_zone: String) -> JsExpr {
+        self.inner.clone().dt().convert_time_zone(time_zone).into()
+    }
+    #[napi(catch_unwind)]
+    pub fn replace_time_zone(
+        &self,
+        time_zone: Option<String>,
+        ambiguous: Option<Wrap<Expr>>,
+        non_existent: Option<String>,
+    ) -> js::Result<JsExpr> {
+        let ambiguous = ambiguous
+            .map(|e| e.0)
+            .unwrap_or(dsl::lit(String::from("raise")));
+        let non_existent = match non_existent.as_deref() {
+            None | Some("raise") => NonExistent::Raise,
+            Some("null") => NonExistent::Null,
+            Some(v) => {
+                return Err(JsPolarsErr::Other(format!(
+                    "non_existent must be one of {{'raise', 'null'}}, got {}",
+                    v
+                )))
+            }
+        };
+        Ok(self
+            .inner
+            .clone()
+            .dt()
+            .replace_time_zone(time_zone, ambiguous, non_existent)
+            .into())
+    }
+    #[napi(catch_unwind)]
     pub fn duration_days(&self) -> JsExpr {
         self.inner
             .clone()
@@ -1373,16 +1404,10 @@ impl JsExpr {
             .into()
     }
     #[napi(catch_unwind)]
-    pub fn cat_set_ordering(&self, ordering: String) -> JsExpr {
-        let ordering = match ordering.as_ref() {
-            "physical" => CategoricalOrdering::Physical,
-            "lexical" => CategoricalOrdering::Lexical,
-            _ => panic!("expected one of {{'physical', 'lexical'}}"),
-        };
-
+    pub fn cat_set_ordering(&self, ordering: Wrap<CategoricalOrdering>) -> JsExpr {
         self.inner
             .clone()
-            .cast(DataType::Categorical(None, ordering))
+            .cast(DataType::Categorical(None, ordering.0))
             .into()
     }
     #[napi(catch_unwind)]
@@ -1479,15 +1504,20 @@ impl JsExpr {
         self.inner.clone().ewm_var(options).into()
     }
     #[napi(catch_unwind)]
-    pub fn extend_constant(&self, value: Option<JsAnyValue>, n: i64) -> Self {
-        self.inner
+    pub fn extend_constant(&self, value: Option<JsAnyValue>, n: i64) -> napi::Result<JsExpr> {
+        let value: AnyValue<'static> = match value {
+            Some(value) => value.try_into()?,
+            None => AnyValue::Null,
+        };
+        Ok(self
+            .inner
             .clone()
             .apply(
-                move |s| Ok(Some(s.extend_constant(value.clone().into(), n as usize)?)),
+                move |s| Ok(Some(s.extend_constant(value.clone(), n as usize)?)),
                 GetOutput::same_type(),
             )
             .with_fmt("extend")
-            .into()
+            .into())
     }
     #[napi(catch_unwind)]
     pub fn any(&self, drop_nulls: bool) -> JsExpr {
@@ -1717,7 +1747,13 @@ pub fn arg_sort_by(by: Vec<&JsExpr>, descending: Vec<bool>) -> JsExpr {
 }
 
 #[napi(catch_unwind)]
-pub fn lit(value: Wrap<AnyValue>) -> JsResult<JsExpr> {
+pub fn lit(value: Wrap<AnyValue>) -> js::Result<JsExpr> {
+    if let AnyValue::Decimal(v, scale) = value.0 {
+        let s = Int128Chunked::from_slice("literal", &[v])
+            .into_decimal_unchecked(None, scale)
+            .into_series();
+        return Ok(dsl::lit(s).into());
+    }
     let lit: LiteralValue = value.0.try_into().map_err(JsPolarsErr::from)?;
     Ok(dsl::lit(lit).into())
 }
@@ -1728,7 +1764,7 @@ pub fn range(low: Wrap<Expr>, high: Wrap<Expr>, dtype: Wrap<DataType>) -> JsExpr
 }
 
 #[napi(catch_unwind)]
-pub fn concat_lst(s: Vec<&JsExpr>) -> JsResult<JsExpr> {
+pub fn concat_lst(s: Vec<&JsExpr>) -> js::Result<JsExpr> {
     let s = s.to_exprs();
     let expr = polars::lazy::dsl::concat_list(s).map_err(JsPolarsErr::from)?;
     Ok(expr.into())
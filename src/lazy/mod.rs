@@ -1,2 +1,3 @@
+mod cloud;
 pub mod dataframe;
 pub mod dsl;
@@ -3,6 +3,8 @@
 #[macro_use]
 extern crate napi_derive;
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[napi]
@@ -15,14 +17,28 @@ pub fn toggle_string_cache() {
     polars::enable_string_cache()
 }
 
+static INT64_AS_BIGINT: AtomicBool = AtomicBool::new(false);
+
+/// Return Int64 values to JS as BigInts instead of (possibly lossy) numbers.
+#[napi]
+pub fn toggle_int64_as_bigint(toggle: bool) {
+    INT64_AS_BIGINT.store(toggle, Ordering::Relaxed)
+}
+
+pub(crate) fn int64_as_bigint() -> bool {
+    INT64_AS_BIGINT.load(Ordering::Relaxed)
+}
+
 pub mod conversion;
 pub mod dataframe;
 pub mod datatypes;
 pub mod error;
 pub mod file;
 pub mod functions;
+pub mod interop;
 pub mod lazy;
 pub mod list_construction;
+pub mod object;
 pub mod prelude;
 pub mod series;
 pub mod set;
@@ -91,7 +91,7 @@ macro_rules! build_list_with_downcast {
     }};
 }
 
-pub fn js_arr_to_list(name: &str, arr: &Array, dtype: &DataType) -> napi::Result<Series> {
+pub fn js_arr_to_list(name: &str, arr: &Array, dtype: &DataType) -> JsPolarsResult<Series> {
     let len = arr.len();
 
     let s = match dtype {
@@ -170,14 +170,17 @@ pub fn js_arr_to_list(name: &str, arr: &Array, dtype: &DataType) -> napi::Result
             builder.finish().into_series()
         }
         dt => {
-            panic!("cannot create list array from {:?}", dt);
+            return Err(JsPolarsErr::Other(format!(
+                "cannot create list array from {:?}",
+                dt
+            )))
         }
     };
     Ok(s)
 }
 
-pub fn from_typed_array(arr: &JsTypedArrayValue) -> JsResult<Series> {
-    let dtype: JsDataType = arr.typedarray_type.into();
+pub fn from_typed_array(arr: &JsTypedArrayValue) -> JsPolarsResult<Series> {
+    let dtype = JsDataType::try_from(arr.typedarray_type)?;
     let series = match dtype {
         JsDataType::Int8 => typed_to_chunked!(arr, i8, Int8Type).into(),
         JsDataType::UInt8 => typed_to_chunked!(arr, u8, UInt8Type).into(),
@@ -189,7 +192,12 @@ pub fn from_typed_array(arr: &JsTypedArrayValue) -> JsResult<Series> {
         JsDataType::Float64 => typed_to_chunked!(arr, f64, Float64Type).into(),
         JsDataType::Int64 => typed_to_chunked!(arr, i64, Int64Type).into(),
         JsDataType::UInt64 => typed_to_chunked!(arr, u64, UInt64Type).into(),
-        _ => panic!("cannot create series from"),
+        _ => {
+            return Err(JsPolarsErr::Other(format!(
+                "cannot create series from {:?}",
+                arr.typedarray_type
+            )))
+        }
     };
 
     Ok(series)
@@ -0,0 +1,238 @@
+use crate::error::JsPolarsErr;
+use napi::{sys, Env, JsUnknown, NapiRaw, Result};
+use polars::export::arrow::datatypes::ArrowDataType;
+use polars::prelude::*;
+use polars_core::chunked_array::object::builder::ObjectChunkedBuilder;
+use polars_core::chunked_array::object::registry::{self, AnonymousObjectBuilder};
+use polars_utils::total_ord::{TotalEq, TotalHash};
+use std::any::Any;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, Once};
+use std::thread::{self, ThreadId};
+
+pub(crate) const OBJECT_NAME: &str = "object";
+
+/// References dropped off their JS thread, kept per env until an object is
+/// created or read on the thread of that env. Entries are added with the
+/// first object of an env and removed when it is torn down, along with
+/// every reference it holds.
+static PENDING_UNREF: Mutex<Vec<PendingRefs>> = Mutex::new(Vec::new());
+
+struct PendingRefs {
+    env: usize,
+    thread: ThreadId,
+    refs: Vec<usize>,
+}
+
+/// A persistent reference to a JS value.
+#[derive(Debug)]
+struct JsRef {
+    env: sys::napi_env,
+    raw: sys::napi_ref,
+    thread: ThreadId,
+}
+
+// The reference is only ever dereferenced or deleted on the JS thread.
+unsafe impl Send for JsRef {}
+unsafe impl Sync for JsRef {}
+
+impl Drop for JsRef {
+    fn drop(&mut self) {
+        if thread::current().id() == self.thread {
+            unsafe { sys::napi_delete_reference(self.env, self.raw) };
+            return;
+        }
+        let mut pending = PENDING_UNREF.lock().unwrap();
+        // without an entry, the env is gone and the reference with it
+        if let Some(entry) = pending
+            .iter_mut()
+            .find(|entry| entry.env == self.env as usize && entry.thread == self.thread)
+        {
+            entry.refs.push(self.raw as usize);
+        }
+    }
+}
+
+/// Deletes the references of `env` dropped elsewhere. On the first call for
+/// an env, registers it so that other threads can queue its references.
+fn delete_pending_refs(env: &Env) -> Result<()> {
+    let thread = thread::current().id();
+    let key = env.raw() as usize;
+    let mut pending = PENDING_UNREF.lock().unwrap();
+    let Some(entry) = pending
+        .iter_mut()
+        .find(|entry| entry.env == key && entry.thread == thread)
+    else {
+        pending.push(PendingRefs {
+            env: key,
+            thread,
+            refs: vec![],
+        });
+        drop(pending);
+        let mut env = *env;
+        env.add_env_cleanup_hook((), move |_| {
+            PENDING_UNREF
+                .lock()
+                .unwrap()
+                .retain(|entry| !(entry.env == key && entry.thread == thread));
+        })?;
+        return Ok(());
+    };
+    let refs = std::mem::take(&mut entry.refs);
+    drop(pending);
+    for raw in refs {
+        unsafe { sys::napi_delete_reference(env.raw(), raw as sys::napi_ref) };
+    }
+    Ok(())
+}
+
+/// An opaque JS value stored in an `Object` series.
+///
+/// Values are compared by reference: two values are only equal if they were
+/// created from the same insertion.
+#[derive(Clone, Debug, Default)]
+pub struct ObjectValue {
+    inner: Option<Arc<JsRef>>,
+}
+
+impl ObjectValue {
+    pub fn new(env: &Env, value: &JsUnknown) -> Result<Self> {
+        delete_pending_refs(env)?;
+        let mut raw = std::ptr::null_mut();
+        napi::check_status!(unsafe {
+            sys::napi_create_reference(env.raw(), value.raw(), 1, &mut raw)
+        })?;
+        Ok(ObjectValue {
+            inner: Some(Arc::new(JsRef {
+                env: env.raw(),
+                raw,
+                thread: thread::current().id(),
+            })),
+        })
+    }
+
+    fn key(&self) -> usize {
+        self.inner
+            .as_ref()
+            .map_or(0, |inner| Arc::as_ptr(inner) as usize)
+    }
+}
+
+impl napi::bindgen_prelude::ToNapiValue for &ObjectValue {
+    unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+        delete_pending_refs(&Env::from_raw(env))?;
+        let mut result = std::ptr::null_mut();
+        match &val.inner {
+            Some(inner) if inner.env != env || inner.thread != thread::current().id() => {
+                return Err(JsPolarsErr::Other(
+                    "object values can only be read on the JS thread that created them".to_owned(),
+                )
+                .into_napi());
+            }
+            Some(inner) => napi::check_status!(sys::napi_get_reference_value(
+                inner.env,
+                inner.raw,
+                &mut result
+            ))?,
+            None => napi::check_status!(sys::napi_get_undefined(env, &mut result))?,
+        };
+        Ok(result)
+    }
+}
+
+impl PartialEq for ObjectValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for ObjectValue {}
+
+impl Hash for ObjectValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+impl TotalEq for ObjectValue {
+    fn tot_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl TotalHash for ObjectValue {
+    fn tot_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.hash(state)
+    }
+}
+
+impl fmt::Display for ObjectValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[object]")
+    }
+}
+
+impl PolarsObject for ObjectValue {
+    fn type_name() -> &'static str {
+        OBJECT_NAME
+    }
+}
+
+/// Registers `ObjectValue` as the object type, so polars can build object
+/// series when it concatenates or casts them.
+pub(crate) fn register_object_type() {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        let builder = Box::new(|name: &str, capacity: usize| {
+            Box::new(ObjectChunkedBuilder::<ObjectValue>::new(name, capacity))
+                as Box<dyn AnonymousObjectBuilder>
+        });
+        let converter = Arc::new(|av: AnyValue| {
+            let value = object_value(&av).cloned().unwrap_or_default();
+            Box::new(value) as Box<dyn Any>
+        });
+        registry::register_object_builder(
+            builder,
+            converter,
+            ArrowDataType::FixedSizeBinary(std::mem::size_of::<ObjectValue>()),
+        );
+    });
+}
+
+/// Returns the `ObjectValue` held by an object `AnyValue`.
+pub(crate) fn object_value<'a>(av: &'a AnyValue) -> Option<&'a ObjectValue> {
+    match av {
+        AnyValue::Object(v) => v.as_any().downcast_ref::<ObjectValue>(),
+        AnyValue::ObjectOwned(v) => v.0.as_any().downcast_ref::<ObjectValue>(),
+        _ => None,
+    }
+}
+
+/// Builds an `Object` series, with `null` and `undefined` as missing values.
+pub(crate) fn object_series(
+    env: &Env,
+    name: &str,
+    values: &napi::bindgen_prelude::Array,
+) -> Result<Series> {
+    register_object_type();
+    let len = values.len();
+    let mut builder = ObjectChunkedBuilder::<ObjectValue>::new(name, len as usize);
+    for i in 0..len {
+        match values.get::<JsUnknown>(i)? {
+            Some(v)
+                if !matches!(
+                    v.get_type()?,
+                    napi::ValueType::Null | napi::ValueType::Undefined
+                ) =>
+            {
+                builder.append_value(ObjectValue::new(env, &v)?)
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into_series())
+}
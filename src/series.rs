@@ -1,6 +1,8 @@
 use crate::dataframe::JsDataFrame;
+use crate::object::object_series;
 use crate::prelude::*;
 use crate::utils::reinterpret;
+use napi::JsObject;
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::CustomIterTools;
 
@@ -30,14 +32,14 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn serialize(&self, format: String) -> napi::Result<Buffer> {
+    pub fn serialize(&self, format: String) -> js::Result<Buffer> {
         let buf = match format.as_ref() {
             "bincode" => bincode::serialize(&self.series)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             "json" => serde_json::to_vec(&self.series)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "unexpected format. \n supported options are 'json', 'bincode'".to_owned(),
                 ))
             }
@@ -46,20 +48,37 @@ impl JsSeries {
     }
 
     #[napi(factory, catch_unwind)]
-    pub fn deserialize(buf: Buffer, format: String) -> napi::Result<JsSeries> {
+    pub fn deserialize(buf: Buffer, format: String) -> js::Result<JsSeries> {
         let series: Series = match format.as_ref() {
             "bincode" => bincode::deserialize(&buf)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             "json" => serde_json::from_slice(&buf)
-                .map_err(|err| napi::Error::from_reason(format!("{:?}", err)))?,
+                .map_err(|err| JsPolarsErr::Other(format!("{:?}", err)))?,
             _ => {
-                return Err(napi::Error::from_reason(
+                return Err(JsPolarsErr::Other(
                     "unexpected format. \n supported options are 'json', 'bincode'".to_owned(),
                 ))
             }
         };
         Ok(series.into())
     }
+
+    /// Returns the series as an apache-arrow `Vector`-like object of the form
+    /// `{ name, type, nullable, data }`, with a `Data` object per chunk.
+    #[napi(catch_unwind)]
+    pub fn to_arrow(&self, env: Env) -> js::Result<JsObject> {
+        let (mut field, data) = crate::interop::series_to_arrow(&env, &self.series)?;
+        field.set("data", data)?;
+        Ok(field)
+    }
+
+    #[napi(factory, catch_unwind)]
+    pub fn from_arrow(name: String, vector: JsObject) -> js::Result<JsSeries> {
+        let data: Vec<JsObject> = vector.get_named_property("data")?;
+        let dtype: JsObject = vector.get_named_property("type")?;
+        let series = crate::interop::arrow_to_series(&name, &dtype, &data)?;
+        Ok(series.into())
+    }
     //
     // FACTORIES
     //
@@ -114,6 +133,68 @@ impl JsSeries {
         JsSeries::new(s)
     }
     #[napi(factory, catch_unwind)]
+    pub fn new_opt_binary(name: String, val: Wrap<BinaryChunked>, _strict: bool) -> JsSeries {
+        let mut s = val.0.into_series();
+        s.rename(&name);
+        JsSeries::new(s)
+    }
+    /// Builds a Duration series from BigInt nanoseconds, or numbers in the time unit of `dtype`.
+    #[napi(factory, catch_unwind)]
+    pub fn new_opt_duration(
+        name: String,
+        values: Array,
+        dtype: Wrap<DataType>,
+    ) -> js::Result<JsSeries> {
+        let tu = match dtype.0 {
+            DataType::Duration(tu) => tu,
+            dt => {
+                return Err(JsPolarsErr::Other(format!(
+                    "expected a Duration, got {}",
+                    dt
+                )))
+            }
+        };
+        let ca = int64_from_js(&name, &values, |val| duration_from_js(val, tu))?;
+        Ok(ca.into_duration(tu).into_series().into())
+    }
+    /// Builds a Time series from nanoseconds since midnight, as BigInts or numbers.
+    #[napi(factory, catch_unwind)]
+    pub fn new_opt_time(name: String, values: Array) -> js::Result<JsSeries> {
+        let ca = int64_from_js(&name, &values, |val| {
+            duration_from_js(val, TimeUnit::Nanoseconds)
+        })?;
+        Ok(ca.into_time().into_series().into())
+    }
+    /// Builds an Object series holding references to arbitrary JS values.
+    #[napi(factory, catch_unwind)]
+    pub fn new_object(env: Env, name: String, values: Array) -> napi::Result<JsSeries> {
+        let s = object_series(&env, &name, &values)?;
+        Ok(JsSeries::new(s))
+    }
+    /// Builds a Decimal series from decimal strings, numbers, BigInts or `{ value, scale }` objects.
+    #[napi(factory, catch_unwind)]
+    pub fn new_opt_decimal(
+        name: String,
+        values: Array,
+        dtype: Wrap<DataType>,
+    ) -> js::Result<JsSeries> {
+        let len = values.len();
+        let mut avs = Vec::with_capacity(len as usize);
+        for idx in 0..len {
+            let av = match values.get::<napi::JsUnknown>(idx)? {
+                Some(val) => match decimal_from_js(&val)? {
+                    Some((v, scale)) => AnyValue::Decimal(v, scale),
+                    None => AnyValue::Null,
+                },
+                None => AnyValue::Null,
+            };
+            avs.push(av);
+        }
+        let s = Series::from_any_values_and_dtype(&name, &avs, &dtype.0, true)
+            .map_err(JsPolarsErr::from)?;
+        Ok(s.into())
+    }
+    #[napi(factory, catch_unwind)]
     pub fn new_opt_bool(name: String, val: Wrap<BooleanChunked>, _strict: bool) -> JsSeries {
         let mut s = val.0.into_series();
         s.rename(&name);
@@ -162,7 +243,7 @@ impl JsSeries {
         name: String,
         values: Vec<napi::JsUnknown>,
         strict: Option<bool>,
-    ) -> napi::Result<JsSeries> {
+    ) -> js::Result<JsSeries> {
         let len = values.len();
         let mut builder = PrimitiveChunkedBuilder::<Int64Type>::new(&name, len);
         for item in values.into_iter() {
@@ -175,7 +256,7 @@ impl JsSeries {
                             Ok(v) => builder.append_value(v as i64),
                             Err(e) => {
                                 if strict.unwrap_or(false) {
-                                    return Err(e);
+                                    return Err(e.into());
                                 }
                                 builder.append_null()
                             }
@@ -183,9 +264,7 @@ impl JsSeries {
                     }
                 }
                 ValueType::Null | ValueType::Undefined => builder.append_null(),
-                _ => {
-                    return Err(JsPolarsErr::Other("Series must be of date type".to_owned()).into())
-                }
+                _ => return Err(JsPolarsErr::Other("Series must be of date type".to_owned())),
             }
         }
         let ca: ChunkedArray<Int64Type> = builder.finish();
@@ -201,8 +280,20 @@ impl JsSeries {
         values: Vec<Wrap<AnyValue>>,
         dtype: Wrap<DataType>,
         strict: bool,
-    ) -> napi::Result<JsSeries> {
-        let values = values.into_iter().map(|v| v.0).collect::<Vec<_>>();
+    ) -> js::Result<JsSeries> {
+        let mut values = values.into_iter().map(|v| v.0).collect::<Vec<_>>();
+        if let DataType::Decimal(..) = dtype.0 {
+            // polars only reads integers and decimals as decimals,
+            // so JS numbers and strings are parsed like `newOptDecimal` does
+            for value in values.iter_mut() {
+                let (v, scale) = match value {
+                    AnyValue::Float64(f) => decimal_from_str(&f.to_string())?,
+                    AnyValue::StringOwned(s) => decimal_from_str(s)?,
+                    _ => continue,
+                };
+                *value = AnyValue::Decimal(v, scale);
+            }
+        }
 
         let s = Series::from_any_values_and_dtype(&name, &values, &dtype.0, strict)
             .map_err(JsPolarsErr::from)?;
@@ -211,7 +302,7 @@ impl JsSeries {
     }
 
     #[napi(factory, catch_unwind)]
-    pub fn new_list(name: String, values: Array, dtype: Wrap<DataType>) -> napi::Result<JsSeries> {
+    pub fn new_list(name: String, values: Array, dtype: Wrap<DataType>) -> js::Result<JsSeries> {
         use crate::list_construction::js_arr_to_list;
         let s = js_arr_to_list(&name, &values, &dtype.0)?;
         Ok(s.into())
@@ -223,7 +314,7 @@ impl JsSeries {
         val: Wrap<AnyValue>,
         n: i64,
         dtype: Wrap<DataType>,
-    ) -> napi::Result<JsSeries> {
+    ) -> js::Result<JsSeries> {
         let s: JsSeries = match dtype.0 {
             DataType::String => {
                 if let AnyValue::StringOwned(v) = val.0 {
@@ -232,9 +323,7 @@ impl JsSeries {
                     ca.rename(&name);
                     ca.into_series().into()
                 } else {
-                    return Err(napi::Error::from_reason(
-                        "invalid primitive cast".to_owned(),
-                    ));
+                    return Err(JsPolarsErr::Other("invalid primitive cast".to_owned()));
                 }
             }
             DataType::Int64 => {
@@ -244,9 +333,7 @@ impl JsSeries {
 
                     ca.into_inner().into_series().into()
                 } else {
-                    return Err(napi::Error::from_reason(
-                        "invalid primitive cast".to_owned(),
-                    ));
+                    return Err(JsPolarsErr::Other("invalid primitive cast".to_owned()));
                 }
             }
             DataType::Float64 => {
@@ -255,13 +342,11 @@ impl JsSeries {
                     ca.rename(&name);
                     ca.into_inner().into_series().into()
                 } else {
-                    return Err(napi::Error::from_reason(
-                        "invalid primitive cast".to_owned(),
-                    ));
+                    return Err(JsPolarsErr::Other("invalid primitive cast".to_owned()));
                 }
             }
             dt => {
-                return Err(napi::Error::from_reason(format!(
+                return Err(JsPolarsErr::Other(format!(
                     "data type: {:?} is not supported as range",
                     dt
                 )));
@@ -283,8 +368,12 @@ impl JsSeries {
     }
 
     #[napi(getter, catch_unwind)]
-    pub fn inner_dtype(&self) -> Option<JsDataType> {
-        self.series.dtype().inner_dtype().map(|dt| dt.into())
+    pub fn inner_dtype(&self) -> napi::Result<Option<JsDataType>> {
+        self.series
+            .dtype()
+            .inner_dtype()
+            .map(JsDataType::try_from)
+            .transpose()
     }
     #[napi(getter, catch_unwind)]
     pub fn name(&self) -> String {
@@ -329,11 +418,12 @@ impl JsSeries {
         }
     }
     #[napi(catch_unwind)]
-    pub fn get_idx(&self, idx: i64) -> Wrap<AnyValue> {
-        Wrap(self.series.get(idx as usize).unwrap())
+    pub fn get_idx(&self, idx: i64) -> js::Result<Wrap<AnyValue<'_>>> {
+        let av = self.series.get(idx as usize).map_err(JsPolarsErr::from)?;
+        Ok(Wrap(av))
     }
     #[napi(catch_unwind)]
-    pub fn bitand(&self, other: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn bitand(&self, other: &JsSeries) -> js::Result<JsSeries> {
         let out = self
             .series
             .bitand(&other.series)
@@ -341,7 +431,7 @@ impl JsSeries {
         Ok(out.into())
     }
     #[napi(catch_unwind)]
-    pub fn bitor(&self, other: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn bitor(&self, other: &JsSeries) -> js::Result<JsSeries> {
         let out = self
             .series
             .bitor(&other.series)
@@ -349,7 +439,7 @@ impl JsSeries {
         Ok(out.into())
     }
     #[napi(catch_unwind)]
-    pub fn bitxor(&self, other: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn bitxor(&self, other: &JsSeries) -> js::Result<JsSeries> {
         let out = self
             .series
             .bitxor(&other.series)
@@ -357,28 +447,28 @@ impl JsSeries {
         Ok(out.into())
     }
     #[napi(catch_unwind)]
-    pub fn cum_sum(&self, reverse: Option<bool>) -> napi::Result<JsSeries> {
+    pub fn cum_sum(&self, reverse: Option<bool>) -> js::Result<JsSeries> {
         let reverse = reverse.unwrap_or(false);
         Ok(cum_sum(&self.series, reverse)
             .map_err(JsPolarsErr::from)?
             .into())
     }
     #[napi(catch_unwind)]
-    pub fn cum_max(&self, reverse: Option<bool>) -> napi::Result<JsSeries> {
+    pub fn cum_max(&self, reverse: Option<bool>) -> js::Result<JsSeries> {
         let reverse = reverse.unwrap_or(false);
         Ok(cum_max(&self.series, reverse)
             .map_err(JsPolarsErr::from)?
             .into())
     }
     #[napi(catch_unwind)]
-    pub fn cum_min(&self, reverse: Option<bool>) -> napi::Result<JsSeries> {
+    pub fn cum_min(&self, reverse: Option<bool>) -> js::Result<JsSeries> {
         let reverse = reverse.unwrap_or(false);
         Ok(cum_min(&self.series, reverse)
             .map_err(JsPolarsErr::from)?
             .into())
     }
     #[napi(catch_unwind)]
-    pub fn cum_prod(&self, reverse: Option<bool>) -> napi::Result<JsSeries> {
+    pub fn cum_prod(&self, reverse: Option<bool>) -> js::Result<JsSeries> {
         let reverse = reverse.unwrap_or(false);
         Ok(cum_prod(&self.series, reverse)
             .map_err(JsPolarsErr::from)?
@@ -405,8 +495,8 @@ impl JsSeries {
         }
     }
     #[napi(catch_unwind)]
-    pub fn max(&self) -> napi::Result<Either3<Option<f64>, bool, Option<i64>>> {
-        Ok(match self.series.dtype() {
+    pub fn max(&self) -> js::Result<Either3<Option<f64>, bool, Option<i64>>> {
+        match self.series.dtype() {
             DataType::Float32 | DataType::Float64 => self.series.max::<f64>().map(Either3::A),
             DataType::Boolean => self
                 .series
@@ -415,11 +505,11 @@ impl JsSeries {
                 .map(Either3::B),
             _ => self.series.max::<i64>().map(Either3::C),
         }
-        .map_err(JsPolarsErr::from)?)
+        .map_err(JsPolarsErr::from)
     }
     #[napi(catch_unwind)]
-    pub fn min(&self) -> napi::Result<Either3<Option<f64>, bool, Option<i64>>> {
-        Ok(match self.series.dtype() {
+    pub fn min(&self) -> js::Result<Either3<Option<f64>, bool, Option<i64>>> {
+        match self.series.dtype() {
             DataType::Float32 | DataType::Float64 => self.series.min::<f64>().map(Either3::A),
             DataType::Boolean => self
                 .series
@@ -428,16 +518,16 @@ impl JsSeries {
                 .map(Either3::B),
             _ => self.series.min::<i64>().map(Either3::C),
         }
-        .map_err(JsPolarsErr::from)?)
+        .map_err(JsPolarsErr::from)
     }
     #[napi(catch_unwind)]
-    pub fn sum(&self) -> napi::Result<Either3<f64, bool, i64>> {
-        Ok(match self.series.dtype() {
+    pub fn sum(&self) -> js::Result<Either3<f64, bool, i64>> {
+        match self.series.dtype() {
             DataType::Float32 | DataType::Float64 => self.series.sum::<f64>().map(Either3::A),
             DataType::Boolean => self.series.sum::<u32>().map(|v| v == 1).map(Either3::B),
             _ => self.series.sum::<i64>().map(Either3::C),
         }
-        .map_err(JsPolarsErr::from)?)
+        .map_err(JsPolarsErr::from)
     }
     #[napi(catch_unwind)]
     pub fn n_chunks(&self) -> u32 {
@@ -452,28 +542,27 @@ impl JsSeries {
         self.series.slice(offset, length as usize).into()
     }
     #[napi(catch_unwind)]
-    pub fn append(&mut self, other: &JsSeries) -> napi::Result<()> {
+    pub fn append(&mut self, other: &JsSeries) -> js::Result<()> {
         self.series
             .append(&other.series)
             .map_err(JsPolarsErr::from)?;
         Ok(())
     }
     #[napi(catch_unwind)]
-    pub fn extend(&mut self, other: &JsSeries) -> napi::Result<()> {
+    pub fn extend(&mut self, other: &JsSeries) -> js::Result<()> {
         self.series
             .extend(&other.series)
             .map_err(JsPolarsErr::from)?;
         Ok(())
     }
     #[napi(catch_unwind)]
-    pub fn filter(&self, filter: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn filter(&self, filter: &JsSeries) -> js::Result<JsSeries> {
         let filter_series = &filter.series;
         if let Ok(ca) = filter_series.bool() {
             let series = self.series.filter(ca).map_err(JsPolarsErr::from)?;
             Ok(JsSeries { series })
         } else {
-            let err = napi::Error::from_reason("Expected a boolean mask".to_owned());
-            Err(err)
+            Err(JsPolarsErr::Other("Expected a boolean mask".to_owned()))
         }
     }
     #[napi(catch_unwind)]
@@ -506,7 +595,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub unsafe fn sort(&mut self, descending: bool, nulls_last: bool) -> napi::Result<JsSeries> {
+    pub unsafe fn sort(&mut self, descending: bool, nulls_last: bool) -> js::Result<JsSeries> {
         let sorted: Series = self
             .series
             .sort(
@@ -537,17 +626,17 @@ impl JsSeries {
             .into()
     }
     #[napi(catch_unwind)]
-    pub fn unique(&self) -> napi::Result<JsSeries> {
+    pub fn unique(&self) -> js::Result<JsSeries> {
         let unique = self.series.unique().map_err(JsPolarsErr::from)?;
         Ok(unique.into())
     }
     #[napi(catch_unwind)]
-    pub fn unique_stable(&self) -> napi::Result<JsSeries> {
+    pub fn unique_stable(&self) -> js::Result<JsSeries> {
         let unique = self.series.unique_stable().map_err(JsPolarsErr::from)?;
         Ok(unique.into())
     }
     #[napi(catch_unwind)]
-    pub fn value_counts(&self, sorted: bool) -> napi::Result<JsDataFrame> {
+    pub fn value_counts(&self, sorted: bool) -> js::Result<JsDataFrame> {
         let df = self
             .series
             .value_counts(true, sorted)
@@ -556,7 +645,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn arg_unique(&self) -> napi::Result<JsSeries> {
+    pub fn arg_unique(&self) -> js::Result<JsSeries> {
         let arg_unique = self.series.arg_unique().map_err(JsPolarsErr::from)?;
         Ok(arg_unique.into_series().into())
     }
@@ -569,13 +658,13 @@ impl JsSeries {
         self.series.arg_max().map(|v| v as i64)
     }
     #[napi(catch_unwind)]
-    pub fn take(&self, indices: Vec<u32>) -> napi::Result<JsSeries> {
+    pub fn take(&self, indices: Vec<u32>) -> js::Result<JsSeries> {
         let indices = UInt32Chunked::from_vec("", indices);
         let take = self.series.take(&indices).map_err(JsPolarsErr::from)?;
         Ok(JsSeries::new(take))
     }
     #[napi(catch_unwind)]
-    pub fn take_with_series(&self, indices: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn take_with_series(&self, indices: &JsSeries) -> js::Result<JsSeries> {
         let idx = indices.series.u32().map_err(JsPolarsErr::from)?;
         let take = self.series.take(idx).map_err(JsPolarsErr::from)?;
         Ok(JsSeries::new(take))
@@ -602,31 +691,31 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn is_not_nan(&self) -> napi::Result<JsSeries> {
+    pub fn is_not_nan(&self) -> js::Result<JsSeries> {
         let ca = self.series.is_not_nan().map_err(JsPolarsErr::from)?;
         Ok(ca.into_series().into())
     }
 
     #[napi(catch_unwind)]
-    pub fn is_nan(&self) -> napi::Result<JsSeries> {
+    pub fn is_nan(&self) -> js::Result<JsSeries> {
         let ca = self.series.is_nan().map_err(JsPolarsErr::from)?;
         Ok(ca.into_series().into())
     }
 
     #[napi(catch_unwind)]
-    pub fn is_finite(&self) -> napi::Result<JsSeries> {
+    pub fn is_finite(&self) -> js::Result<JsSeries> {
         let ca = self.series.is_finite().map_err(JsPolarsErr::from)?;
         Ok(ca.into_series().into())
     }
 
     #[napi(catch_unwind)]
-    pub fn is_infinite(&self) -> napi::Result<JsSeries> {
+    pub fn is_infinite(&self) -> js::Result<JsSeries> {
         let ca = self.series.is_infinite().map_err(JsPolarsErr::from)?;
         Ok(ca.into_series().into())
     }
 
     #[napi(catch_unwind)]
-    pub fn is_unique(&self) -> napi::Result<JsSeries> {
+    pub fn is_unique(&self) -> js::Result<JsSeries> {
         let ca = is_unique(&self.series).map_err(JsPolarsErr::from)?;
         Ok(ca.into_series().into())
     }
@@ -638,7 +727,7 @@ impl JsSeries {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<Wrap<u64>>,
-    ) -> napi::Result<Self> {
+    ) -> js::Result<Self> {
         // Safety:
         // Wrap is transparent.
         let seed: Option<u64> = unsafe { std::mem::transmute(seed) };
@@ -656,7 +745,7 @@ impl JsSeries {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<Wrap<u64>>,
-    ) -> napi::Result<Self> {
+    ) -> js::Result<Self> {
         // Safety:
         // Wrap is transparent.
         let seed: Option<u64> = unsafe { std::mem::transmute(seed) };
@@ -667,12 +756,12 @@ impl JsSeries {
         Ok(s.into())
     }
     #[napi(catch_unwind)]
-    pub fn is_duplicated(&self) -> napi::Result<JsSeries> {
+    pub fn is_duplicated(&self) -> js::Result<JsSeries> {
         let ca = is_duplicated(&self.series).map_err(JsPolarsErr::from)?;
         Ok(ca.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn explode(&self) -> napi::Result<JsSeries> {
+    pub fn explode(&self) -> js::Result<JsSeries> {
         let s = self.series.explode().map_err(JsPolarsErr::from)?;
         Ok(s.into())
     }
@@ -692,7 +781,7 @@ impl JsSeries {
         }
     }
     #[napi(catch_unwind)]
-    pub fn eq(&self, rhs: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn eq(&self, rhs: &JsSeries) -> js::Result<JsSeries> {
         Ok(Self::new(
             self.series
                 .equal(&rhs.series)
@@ -702,7 +791,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn neq(&self, rhs: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn neq(&self, rhs: &JsSeries) -> js::Result<JsSeries> {
         Ok(Self::new(
             self.series
                 .not_equal(&rhs.series)
@@ -712,7 +801,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn gt(&self, rhs: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn gt(&self, rhs: &JsSeries) -> js::Result<JsSeries> {
         Ok(Self::new(
             self.series
                 .gt(&rhs.series)
@@ -722,7 +811,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn gt_eq(&self, rhs: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn gt_eq(&self, rhs: &JsSeries) -> js::Result<JsSeries> {
         Ok(Self::new(
             self.series
                 .gt_eq(&rhs.series)
@@ -732,7 +821,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn lt(&self, rhs: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn lt(&self, rhs: &JsSeries) -> js::Result<JsSeries> {
         Ok(Self::new(
             self.series
                 .lt(&rhs.series)
@@ -742,7 +831,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn lt_eq(&self, rhs: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn lt_eq(&self, rhs: &JsSeries) -> js::Result<JsSeries> {
         Ok(Self::new(
             self.series
                 .lt_eq(&rhs.series)
@@ -752,7 +841,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn _not(&self) -> napi::Result<JsSeries> {
+    pub fn _not(&self) -> js::Result<JsSeries> {
         let bool = self.series.bool().map_err(JsPolarsErr::from)?;
         Ok((!bool).into_series().into())
     }
@@ -771,9 +860,12 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn to_typed_array(&self) -> TypedArrayBuffer {
-        let series = &self.series;
-        series.into()
+    pub fn to_typed_array(&self) -> js::Result<TypedArrayBuffer> {
+        TypedArrayBuffer::try_from(&self.series)
+    }
+    #[napi(catch_unwind)]
+    pub fn to_typed_array_with_validity(&self) -> js::Result<TypedArrayWithValidity> {
+        TypedArrayWithValidity::try_from(&self.series)
     }
     #[napi(catch_unwind)]
     pub fn to_array(&self) -> Wrap<&Series> {
@@ -795,17 +887,17 @@ impl JsSeries {
         &self,
         quantile: f64,
         interpolation: Wrap<QuantileInterpolOptions>,
-    ) -> JsAnyValue {
+    ) -> js::Result<JsAnyValue> {
         let binding = self
             .series
             .quantile_reduce(quantile, interpolation.0)
-            .expect("invalid quantile");
+            .map_err(JsPolarsErr::from)?;
         let v = binding.as_any_value();
-        v.into()
+        Ok(v.try_into()?)
     }
     /// Rechunk and return a pointer to the start of the Series.
     /// Only implemented for numeric types
-    pub fn as_single_ptr(&mut self) -> napi::Result<usize> {
+    pub fn as_single_ptr(&mut self) -> JsPolarsResult<usize> {
         let ptr = self.series.as_single_ptr().map_err(JsPolarsErr::from)?;
         Ok(ptr)
     }
@@ -816,7 +908,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn fill_null(&self, strategy: Wrap<FillNullStrategy>) -> napi::Result<JsSeries> {
+    pub fn fill_null(&self, strategy: Wrap<FillNullStrategy>) -> js::Result<JsSeries> {
         let series = self
             .series
             .fill_null(strategy.0)
@@ -825,7 +917,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn is_in(&self, other: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn is_in(&self, other: &JsSeries) -> js::Result<JsSeries> {
         let series = is_in(&self.series, &other.series)
             .map(|ca| ca.into_series())
             .map_err(JsPolarsErr::from)?;
@@ -844,7 +936,7 @@ impl JsSeries {
         JsSeries::new(s)
     }
     #[napi(catch_unwind)]
-    pub fn zip_with(&self, mask: &JsSeries, other: &JsSeries) -> napi::Result<JsSeries> {
+    pub fn zip_with(&self, mask: &JsSeries, other: &JsSeries) -> js::Result<JsSeries> {
         let mask = mask.series.bool().map_err(JsPolarsErr::from)?;
         let s = self
             .series
@@ -855,28 +947,28 @@ impl JsSeries {
 
     // Struct namespace
     #[napi(catch_unwind)]
-    pub fn struct_to_frame(&self) -> napi::Result<crate::dataframe::JsDataFrame> {
+    pub fn struct_to_frame(&self) -> js::Result<crate::dataframe::JsDataFrame> {
         let ca = self.series.struct_().map_err(JsPolarsErr::from)?;
         let df: DataFrame = ca.clone().into();
         Ok(df.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn struct_fields(&self) -> napi::Result<Vec<&str>> {
+    pub fn struct_fields(&self) -> js::Result<Vec<&str>> {
         let ca = self.series.struct_().map_err(JsPolarsErr::from)?;
         Ok(ca.fields().iter().map(|s| s.name()).collect())
     }
     // String Namespace
 
     #[napi(catch_unwind)]
-    pub fn str_lengths(&self) -> napi::Result<JsSeries> {
+    pub fn str_lengths(&self) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca.str_len_chars().into_series();
         Ok(JsSeries::new(s))
     }
 
     #[napi]
-    pub fn str_contains(&self, pat: String, strict: bool) -> napi::Result<JsSeries> {
+    pub fn str_contains(&self, pat: String, strict: bool) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca
             .contains(&pat, strict)
@@ -890,7 +982,7 @@ impl JsSeries {
         &self,
         dtype: Option<Wrap<DataType>>,
         infer_schema_len: Option<i64>,
-    ) -> napi::Result<JsSeries> {
+    ) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let dt = dtype.map(|d| d.0);
         let infer_schema_len = infer_schema_len.map(|l| l as usize);
@@ -902,7 +994,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn str_json_path_match(&self, pat: Wrap<StringChunked>) -> napi::Result<JsSeries> {
+    pub fn str_json_path_match(&self, pat: Wrap<StringChunked>) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca
             .json_path_match(&pat.0)
@@ -912,7 +1004,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn str_replace(&self, pat: String, val: String) -> napi::Result<JsSeries> {
+    pub fn str_replace(&self, pat: String, val: String) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca
             .replace(&pat, &val)
@@ -922,7 +1014,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn str_replace_all(&self, pat: String, val: String) -> napi::Result<JsSeries> {
+    pub fn str_replace_all(&self, pat: String, val: String) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca
             .replace_all(&pat, &val)
@@ -932,27 +1024,27 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn str_to_uppercase(&self) -> napi::Result<JsSeries> {
+    pub fn str_to_uppercase(&self) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca.to_uppercase().into_series();
         Ok(s.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn str_to_lowercase(&self) -> napi::Result<JsSeries> {
+    pub fn str_to_lowercase(&self) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca.to_lowercase().into_series();
         Ok(s.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn str_hex_encode(&self) -> napi::Result<JsSeries> {
+    pub fn str_hex_encode(&self) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca.hex_encode().into_series();
         Ok(s.into())
     }
     #[napi(catch_unwind)]
-    pub fn str_hex_decode(&self, strict: bool) -> napi::Result<JsSeries> {
+    pub fn str_hex_decode(&self, strict: bool) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca
             .hex_decode(strict)
@@ -961,13 +1053,13 @@ impl JsSeries {
         Ok(s.into())
     }
     #[napi]
-    pub fn str_base64_encode(&self) -> napi::Result<JsSeries> {
+    pub fn str_base64_encode(&self) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca.base64_encode().into_series();
         Ok(s.into())
     }
     #[napi(catch_unwind)]
-    pub fn str_base64_decode(&self, strict: bool) -> napi::Result<JsSeries> {
+    pub fn str_base64_decode(&self, strict: bool) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca
             .base64_decode(strict)
@@ -976,7 +1068,7 @@ impl JsSeries {
         Ok(s.into())
     }
     #[napi(catch_unwind)]
-    pub fn str_pad_start(&self, length: i64, fill_char: String) -> napi::Result<JsSeries> {
+    pub fn str_pad_start(&self, length: i64, fill_char: String) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca
             .pad_start(length as usize, fill_char.chars().nth(0).unwrap())
@@ -984,7 +1076,7 @@ impl JsSeries {
         Ok(s.into())
     }
     #[napi(catch_unwind)]
-    pub fn str_pad_end(&self, length: i64, fill_char: String) -> napi::Result<JsSeries> {
+    pub fn str_pad_end(&self, length: i64, fill_char: String) -> js::Result<JsSeries> {
         let ca = self.series.str().map_err(JsPolarsErr::from)?;
         let s = ca
             .pad_end(length as usize, fill_char.chars().nth(0).unwrap())
@@ -993,12 +1085,12 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn strftime(&self, fmt: String) -> napi::Result<JsSeries> {
+    pub fn strftime(&self, fmt: String) -> js::Result<JsSeries> {
         let s = self.series.strftime(&fmt).map_err(JsPolarsErr::from)?;
         Ok(s.into())
     }
     #[napi(catch_unwind)]
-    pub fn arr_lengths(&self) -> napi::Result<JsSeries> {
+    pub fn arr_lengths(&self) -> js::Result<JsSeries> {
         let ca = self.series.list().map_err(JsPolarsErr::from)?;
         let s = ca.lst_lengths().into_series();
         Ok(JsSeries::new(s))
@@ -1009,11 +1101,7 @@ impl JsSeries {
     //   Ok(ca.into_series().into())
     // }
     #[napi(catch_unwind)]
-    pub fn to_dummies(
-        &self,
-        separator: Option<&str>,
-        drop_first: bool,
-    ) -> napi::Result<JsDataFrame> {
+    pub fn to_dummies(&self, separator: Option<&str>, drop_first: bool) -> js::Result<JsDataFrame> {
         let df = self
             .series
             .to_dummies(separator, drop_first)
@@ -1029,57 +1117,57 @@ impl JsSeries {
         }
     }
     #[napi(catch_unwind)]
-    pub fn year(&self) -> napi::Result<JsSeries> {
+    pub fn year(&self) -> js::Result<JsSeries> {
         let s = self.series.year().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn month(&self) -> napi::Result<JsSeries> {
+    pub fn month(&self) -> js::Result<JsSeries> {
         let s = self.series.month().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn weekday(&self) -> napi::Result<JsSeries> {
+    pub fn weekday(&self) -> js::Result<JsSeries> {
         let s = self.series.weekday().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn week(&self) -> napi::Result<JsSeries> {
+    pub fn week(&self) -> js::Result<JsSeries> {
         let s = self.series.week().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn day(&self) -> napi::Result<JsSeries> {
+    pub fn day(&self) -> js::Result<JsSeries> {
         let s = self.series.day().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn ordinal_day(&self) -> napi::Result<JsSeries> {
+    pub fn ordinal_day(&self) -> js::Result<JsSeries> {
         let s = self.series.ordinal_day().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn hour(&self) -> napi::Result<JsSeries> {
+    pub fn hour(&self) -> js::Result<JsSeries> {
         let s = self.series.hour().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn minute(&self) -> napi::Result<JsSeries> {
+    pub fn minute(&self) -> js::Result<JsSeries> {
         let s = self.series.minute().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn second(&self) -> napi::Result<JsSeries> {
+    pub fn second(&self) -> js::Result<JsSeries> {
         let s = self.series.second().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn nanosecond(&self) -> napi::Result<JsSeries> {
+    pub fn nanosecond(&self) -> js::Result<JsSeries> {
         let s = self.series.nanosecond().map_err(JsPolarsErr::from)?;
         Ok(s.into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn dt_epoch_seconds(&self) -> napi::Result<JsSeries> {
+    pub fn dt_epoch_seconds(&self) -> js::Result<JsSeries> {
         let ms = self
             .series
             .timestamp(TimeUnit::Milliseconds)
@@ -1087,13 +1175,13 @@ impl JsSeries {
         Ok((ms / 1000).into_series().into())
     }
     #[napi(catch_unwind)]
-    pub fn n_unique(&self) -> napi::Result<i64> {
+    pub fn n_unique(&self) -> js::Result<i64> {
         let n = self.series.n_unique().map_err(JsPolarsErr::from)?;
         Ok(n as i64)
     }
 
     #[napi(catch_unwind)]
-    pub fn is_first_distinct(&self) -> napi::Result<JsSeries> {
+    pub fn is_first_distinct(&self) -> js::Result<JsSeries> {
         let out = is_first_distinct(&self.series)
             .map_err(JsPolarsErr::from)?
             .into_series();
@@ -1101,19 +1189,19 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn round(&self, decimals: u32) -> napi::Result<JsSeries> {
+    pub fn round(&self, decimals: u32) -> js::Result<JsSeries> {
         let s = self.series.round(decimals).map_err(JsPolarsErr::from)?;
         Ok(s.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn floor(&self) -> napi::Result<JsSeries> {
+    pub fn floor(&self) -> js::Result<JsSeries> {
         let s = self.series.floor().map_err(JsPolarsErr::from)?;
         Ok(s.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn ceil(&self) -> napi::Result<JsSeries> {
+    pub fn ceil(&self) -> js::Result<JsSeries> {
         let s = self.series.ceil().map_err(JsPolarsErr::from)?;
         Ok(s.into())
     }
@@ -1123,7 +1211,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn dot(&self, other: &JsSeries) -> napi::Result<f64> {
+    pub fn dot(&self, other: &JsSeries) -> js::Result<f64> {
         let s = self.series.dot(&other.series).map_err(JsPolarsErr::from)?;
         Ok(s)
     }
@@ -1134,13 +1222,13 @@ impl JsSeries {
         self.series.hash(hb).into_series().into()
     }
     #[napi(catch_unwind)]
-    pub fn reinterpret(&self, signed: bool) -> napi::Result<JsSeries> {
+    pub fn reinterpret(&self, signed: bool) -> js::Result<JsSeries> {
         let s = reinterpret(&self.series, signed).map_err(JsPolarsErr::from)?;
         Ok(s.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn mode(&self) -> napi::Result<JsSeries> {
+    pub fn mode(&self) -> js::Result<JsSeries> {
         let s = mode::mode(&self.series).map_err(JsPolarsErr::from)?;
         Ok(s.into())
     }
@@ -1158,24 +1246,24 @@ impl JsSeries {
         let seed: Option<u64> = unsafe { std::mem::transmute(seed) };
         let options = RankOptions {
             method: method.0,
-            descending: descending,
+            descending,
         };
         Ok(self.series.rank(options, seed).into())
     }
     #[napi(catch_unwind)]
-    pub fn diff(&self, n: i64, null_behavior: Wrap<NullBehavior>) -> napi::Result<JsSeries> {
+    pub fn diff(&self, n: i64, null_behavior: Wrap<NullBehavior>) -> js::Result<JsSeries> {
         let s = diff(&self.series, n, null_behavior.0).map_err(JsPolarsErr::from)?;
         Ok(s.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn skew(&self, bias: bool) -> napi::Result<Option<f64>> {
+    pub fn skew(&self, bias: bool) -> js::Result<Option<f64>> {
         let out = self.series.skew(bias).map_err(JsPolarsErr::from)?;
         Ok(out)
     }
 
     #[napi(catch_unwind)]
-    pub fn kurtosis(&self, fisher: bool, bias: bool) -> napi::Result<Option<f64>> {
+    pub fn kurtosis(&self, fisher: bool, bias: bool) -> js::Result<Option<f64>> {
         let out = self
             .series
             .kurtosis(fisher, bias)
@@ -1184,7 +1272,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn cast(&self, dtype: Wrap<DataType>, strict: Option<bool>) -> napi::Result<JsSeries> {
+    pub fn cast(&self, dtype: Wrap<DataType>, strict: Option<bool>) -> js::Result<JsSeries> {
         let strict = strict.unwrap_or(false);
         let dtype = dtype.0;
         let out = if strict {
@@ -1197,13 +1285,13 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn abs(&self) -> napi::Result<JsSeries> {
+    pub fn abs(&self) -> js::Result<JsSeries> {
         let s = abs(&self.series).map_err(JsPolarsErr::from)?;
         Ok(s.into())
     }
 
     #[napi(catch_unwind)]
-    pub fn reshape(&self, dims: Vec<i64>) -> napi::Result<JsSeries> {
+    pub fn reshape(&self, dims: Vec<i64>) -> js::Result<JsSeries> {
         let out = self.series.reshape(&dims).map_err(JsPolarsErr::from)?;
         Ok(out.into())
     }
@@ -1214,7 +1302,7 @@ impl JsSeries {
     }
 
     #[napi(catch_unwind)]
-    pub fn extend_constant(&self, value: Wrap<AnyValue>, n: i64) -> napi::Result<JsSeries> {
+    pub fn extend_constant(&self, value: Wrap<AnyValue>, n: i64) -> js::Result<JsSeries> {
         let out = self
             .series
             .extend_constant(value.0, n as usize)
@@ -1238,7 +1326,7 @@ impl JsSeries {
         }
     }
     #[napi(catch_unwind)]
-    pub fn scatter(&mut self, idx: &JsSeries, values: &JsSeries) -> napi::Result<()> {
+    pub fn scatter(&mut self, idx: &JsSeries, values: &JsSeries) -> js::Result<()> {
         // we take the value because we want a ref count
         // of 1 so that we can have mutable access
         let s = std::mem::take(&mut self.series);
@@ -1247,9 +1335,24 @@ impl JsSeries {
                 self.series = out;
                 Ok(())
             }
-            Err(e) => Err(napi::Error::from_reason(format!("{:?}", e))),
+            Err(e) => Err(JsPolarsErr::Other(format!("{:?}", e))),
+        }
+    }
+}
+
+fn int64_from_js<F>(name: &str, values: &Array, read: F) -> JsPolarsResult<Int64Chunked>
+where
+    F: Fn(&napi::JsUnknown) -> JsPolarsResult<Option<i64>>,
+{
+    let len = values.len() as usize;
+    let mut builder = PrimitiveChunkedBuilder::<Int64Type>::new(name, len);
+    for idx in 0..len {
+        match values.get::<napi::JsUnknown>(idx as u32)? {
+            Some(val) => builder.append_option(read(&val)?),
+            None => builder.append_null(),
         }
     }
+    Ok(builder.finish())
 }
 
 macro_rules! impl_set_with_mask_wrap {
@@ -1259,7 +1362,7 @@ macro_rules! impl_set_with_mask_wrap {
             series: &JsSeries,
             mask: &JsSeries,
             value: Option<Wrap<$native>>,
-        ) -> napi::Result<JsSeries> {
+        ) -> js::Result<JsSeries> {
             let value = value.map(|v| v.0);
             let mask = mask.series.bool().map_err(JsPolarsErr::from)?;
             let ca = series.series.$cast().map_err(JsPolarsErr::from)?;
@@ -1279,7 +1382,7 @@ macro_rules! impl_set_with_mask {
             series: &JsSeries,
             mask: &JsSeries,
             value: Option<$native>,
-        ) -> napi::Result<JsSeries> {
+        ) -> js::Result<JsSeries> {
             let mask = mask.series.bool().map_err(JsPolarsErr::from)?;
             let ca = series.series.$cast().map_err(JsPolarsErr::from)?;
             let new = ca
@@ -1478,7 +1581,7 @@ impl_rhs_arithmetic!(series_rem_f64_rhs, f64, rem);
 macro_rules! impl_eq_num {
     ($name:ident, $type:ty) => {
         #[napi(catch_unwind)]
-        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> napi::Result<JsSeries> {
+        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> js::Result<JsSeries> {
             let rhs: $type = rhs.try_into()?;
             Ok(JsSeries::new(
                 s.series
@@ -1505,7 +1608,7 @@ impl_eq_num!(series_eq_str, &str);
 macro_rules! impl_neq_num {
     ($name:ident, $type:ty) => {
         #[napi(catch_unwind)]
-        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> napi::Result<JsSeries> {
+        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> js::Result<JsSeries> {
             let rhs: $type = rhs.try_into()?;
             Ok(JsSeries::new(
                 s.series
@@ -1531,7 +1634,7 @@ impl_neq_num!(series_neq_str, &str);
 macro_rules! impl_gt_num {
     ($name:ident, $type:ty) => {
         #[napi(catch_unwind)]
-        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> napi::Result<JsSeries> {
+        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> js::Result<JsSeries> {
             let rhs: $type = rhs.try_into()?;
             Ok(JsSeries::new(
                 s.series.gt(rhs).map_err(JsPolarsErr::from)?.into_series(),
@@ -1554,7 +1657,7 @@ impl_gt_num!(series_gt_str, &str);
 macro_rules! impl_gt_eq_num {
     ($name:ident, $type:ty) => {
         #[napi(catch_unwind)]
-        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> napi::Result<JsSeries> {
+        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> js::Result<JsSeries> {
             let rhs: $type = rhs.try_into()?;
             Ok(JsSeries::new(
                 s.series
@@ -1580,7 +1683,7 @@ impl_gt_eq_num!(series_gt_eq_str, &str);
 macro_rules! impl_lt_num {
     ($name:ident, $type:ty) => {
         #[napi(catch_unwind)]
-        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> napi::Result<JsSeries> {
+        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> js::Result<JsSeries> {
             let rhs: $type = rhs.try_into()?;
             Ok(JsSeries::new(
                 s.series.lt(rhs).map_err(JsPolarsErr::from)?.into_series(),
@@ -1603,7 +1706,7 @@ impl_lt_num!(series_lt_str, &str);
 macro_rules! impl_lt_eq_num {
     ($name:ident, $type:ty) => {
         #[napi(catch_unwind)]
-        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> napi::Result<JsSeries> {
+        pub fn $name(s: &JsSeries, rhs: Wrap<AnyValue>) -> js::Result<JsSeries> {
             let rhs: $type = rhs.try_into()?;
             Ok(JsSeries::new(
                 s.series
@@ -84,7 +84,9 @@ pub(crate) fn scatter(mut s: Series, idx: &Series, values: &Series) -> PolarsRes
             let values = values.str()?;
             ca.scatter(idx, values)
         }
-        _ => panic!("not yet implemented for dtype: {}", logical_dtype),
+        _ => {
+            polars_bail!(InvalidOperation: "scatter is not supported for dtype: {}", logical_dtype)
+        }
     };
 
     s?.cast(&logical_dtype)
@@ -19,7 +19,7 @@ impl JsSQLContext {
     }
 
     #[napi(catch_unwind)]
-    pub fn execute(&mut self, query: String) -> JsResult<JsLazyFrame> {
+    pub fn execute(&mut self, query: String) -> js::Result<JsLazyFrame> {
         Ok(self
             .context
             .execute(&query)